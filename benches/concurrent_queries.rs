@@ -0,0 +1,77 @@
+//! Measures how query throughput on a single shared `IndexedCrate` scales with
+//! the number of threads querying it, since all its queries share its memoized values.
+//!
+//! Run with `cargo bench --bench concurrent_queries`. It queries the rustdoc JSON file
+//! named by the `RUSTDOC_JSON` environment variable, or by default one of the test crates
+//! generated by `./scripts/regenerate_test_rustdocs.sh`.
+
+use std::{collections::BTreeMap, rc::Rc, time::Instant};
+
+use trustfall_rustdoc_adapter::{load_rustdoc, IndexedCrate, RustdocAdapter};
+
+const QUERY: &str = r#"
+{
+    Crate {
+        item {
+            ... on ImplOwner {
+                name @output
+                is_effectively_deprecated @output
+
+                importable_path {
+                    path @output
+                }
+            }
+        }
+    }
+}
+"#;
+
+const QUERIES_PER_THREAD: usize = 50;
+
+fn run_queries<'a>(indexed_crate: &'a IndexedCrate<'a>) -> usize {
+    let schema = RustdocAdapter::schema();
+    let adapter = Rc::new(RustdocAdapter::new(indexed_crate, None));
+    let mut rows = 0;
+    for _ in 0..QUERIES_PER_THREAD {
+        let variables: BTreeMap<&str, &str> = BTreeMap::new();
+        rows += trustfall::execute_query(&schema, adapter.clone(), QUERY, variables)
+            .expect("failed to run query")
+            .count();
+    }
+    rows
+}
+
+fn main() {
+    let path = std::env::var("RUSTDOC_JSON")
+        .unwrap_or_else(|_| "./localdata/test_data/public_api/rustdoc.json".to_string());
+    let crate_ = load_rustdoc(&path).unwrap_or_else(|error| {
+        panic!(
+            "failed to load {path}: {error}; \
+            did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"
+        )
+    });
+
+    for threads in [1, 2, 4, 8] {
+        // Each thread count starts with empty memos, as a freshly-indexed crate would.
+        let indexed_crate = IndexedCrate::new(&crate_);
+
+        let start = Instant::now();
+        let rows: usize = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|_| scope.spawn(|| run_queries(&indexed_crate)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("query thread panicked"))
+                .sum()
+        });
+        let elapsed = start.elapsed();
+
+        let queries = threads * QUERIES_PER_THREAD;
+        println!(
+            "{threads} thread(s): {queries} queries, {rows} rows in {elapsed:?} \
+            ({:.1} queries/s)",
+            queries as f64 / elapsed.as_secs_f64(),
+        );
+    }
+}
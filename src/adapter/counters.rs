@@ -0,0 +1,76 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use trustfall::provider::{ContextOutcomeIterator, VertexIterator};
+
+use super::vertex::Vertex;
+
+/// The amount of work done by the adapter's entry points and edges while executing queries,
+/// as recorded by [`RustdocAdapter::with_execution_counters()`](super::RustdocAdapter::with_execution_counters).
+///
+/// Edges whose vertex counts are much larger than their expansion counts,
+/// or that are expanded many more times than expected, point to expensive traversals.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionCounters {
+    /// entry point name -> number of vertices it produced
+    pub entry_points: BTreeMap<String, u64>,
+
+    /// (type name, edge name) -> number of vertices the edge was expanded from
+    pub edge_expansions: BTreeMap<(String, String), u64>,
+
+    /// (type name, edge name) -> number of vertices produced by expanding the edge
+    pub edge_vertices: BTreeMap<(String, String), u64>,
+}
+
+fn increment<K: Ord + Clone>(counts: &mut BTreeMap<K, u64>, key: &K) {
+    match counts.get_mut(key) {
+        Some(count) => *count += 1,
+        None => {
+            counts.insert(key.clone(), 1);
+        }
+    }
+}
+
+pub(super) fn count_entry_point<'a>(
+    counters: &Rc<RefCell<ExecutionCounters>>,
+    edge_name: &str,
+    vertices: VertexIterator<'a, Vertex<'a>>,
+) -> VertexIterator<'a, Vertex<'a>> {
+    let counters = counters.clone();
+    let key = edge_name.to_string();
+    counters
+        .borrow_mut()
+        .entry_points
+        .entry(key.clone())
+        .or_default();
+
+    Box::new(vertices.inspect(move |_| {
+        increment(&mut counters.borrow_mut().entry_points, &key);
+    }))
+}
+
+pub(super) fn count_edge<'a>(
+    counters: &Rc<RefCell<ExecutionCounters>>,
+    type_name: &str,
+    edge_name: &str,
+    outcomes: ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    let counters = counters.clone();
+    let key = (type_name.to_string(), edge_name.to_string());
+
+    Box::new(outcomes.map(move |(context, neighbors)| {
+        if context.active_vertex().is_none() {
+            // The edge isn't actually expanded for contexts
+            // that are part of an `@optional` that didn't match.
+            return (context, neighbors);
+        }
+
+        increment(&mut counters.borrow_mut().edge_expansions, &key);
+        let counters = counters.clone();
+        let key = key.clone();
+        let neighbors: VertexIterator<'a, Vertex<'a>> = Box::new(neighbors.inspect(move |_| {
+            increment(&mut counters.borrow_mut().edge_vertices, &key);
+        }));
+        (context, neighbors)
+    }))
+}
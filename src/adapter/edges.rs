@@ -1,12 +1,26 @@
-use rustdoc_types::{Id, VariantKind};
+use std::collections::{HashMap, HashSet};
+
+use rustdoc_types::{
+    Abi, GenericArg, GenericArgs, GenericBound, GenericParamDefKind, Id, Item, ItemEnum, Path,
+    Term, TraitBoundModifier, Type, TypeBindingKind, VariantKind,
+};
 use trustfall::provider::{
-    resolve_neighbors_with, ContextIterator, ContextOutcomeIterator, ResolveEdgeInfo,
-    VertexIterator,
+    resolve_neighbors_with, ContextIterator, ContextOutcomeIterator, EdgeParameters,
+    ResolveEdgeInfo, VertexIterator,
 };
 
-use crate::{attributes::Attribute, IndexedCrate};
+use crate::{
+    attributes::{parse_attributes, Attribute},
+    indexed_crate::ItemId,
+    IndexedCrate,
+};
 
-use super::{optimizations, origin::Origin, vertex::Vertex, RustdocAdapter};
+use super::{
+    optimizations,
+    origin::Origin,
+    vertex::{FunctionLikeItem, Vertex},
+    RustdocAdapter,
+};
 
 pub(super) fn resolve_crate_diff_edge<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
@@ -31,14 +45,291 @@ pub(super) fn resolve_crate_edge<'a>(
     adapter: &RustdocAdapter<'a>,
     contexts: ContextIterator<'a, Vertex<'a>>,
     edge_name: &str,
+    parameters: &EdgeParameters,
     resolve_info: &ResolveEdgeInfo,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
     match edge_name {
-        "item" => optimizations::item_lookup::resolve_crate_items(adapter, contexts, resolve_info),
+        "item" => optimizations::item_lookup::resolve_crate_items(
+            adapter,
+            contexts,
+            parameters,
+            resolve_info,
+        ),
+        "attribute" => {
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let crate_ = vertex.as_crate().expect("vertex was not a Crate");
+                match crate_.index.get(&crate_.root) {
+                    Some(root_module) => Box::new(root_module.attrs.iter().map(move |attr| {
+                        origin.make_attribute_vertex(Attribute::new(attr.as_str()))
+                    })),
+                    None => Box::new(std::iter::empty()),
+                }
+            })
+        }
+        "file" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let crate_ = vertex.as_indexed_crate().expect("vertex was not a Crate");
+            Box::new(
+                crate_
+                    .source_files()
+                    .into_iter()
+                    .map(move |(path, items)| origin.make_file_vertex(path, items)),
+            )
+        }),
+        "feature" => {
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let crate_ = vertex.as_indexed_crate().expect("vertex was not a Crate");
+                let metadata = &crate_.package_metadata;
+                Box::new(metadata.features.iter().map(move |(name, enables)| {
+                    origin.make_feature_vertex(metadata, name, enables)
+                }))
+            })
+        }
+        "optional_dependency" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let crate_ = vertex.as_indexed_crate().expect("vertex was not a Crate");
+            let metadata = &crate_.package_metadata;
+            Box::new(
+                metadata
+                    .optional_dependencies
+                    .iter()
+                    .map(move |name| origin.make_optional_dependency_vertex(metadata, name)),
+            )
+        }),
+        "impl" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+            // The kind index is sorted by id, so the order is consistent from run to run.
+            optimizations::item_lookup::resolve_item_vertices(
+                origin,
+                crate_vertex.items_of_kind("Impl").iter().copied(),
+            )
+        }),
+        "trait_impl" => {
+            let trait_ = parameters
+                .get("trait")
+                .expect("no trait parameter provided")
+                .as_str()
+                .expect("trait parameter was not a string")
+                .to_string();
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+                Box::new(
+                    crate_vertex
+                        .impls_of_trait(&trait_)
+                        .iter()
+                        .map(move |item| origin.make_item_vertex(item)),
+                )
+            })
+        }
+        "item_name_search" => {
+            let prefix = parameters
+                .get("prefix")
+                .expect("no prefix parameter provided")
+                .as_str()
+                .expect("prefix parameter was not a string")
+                .to_string();
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+                optimizations::item_lookup::resolve_item_vertices(
+                    origin,
+                    crate_vertex
+                        .items_with_name_prefix(&prefix)
+                        .iter()
+                        .map(|(_, item)| *item),
+                )
+            })
+        }
+        "item_substring_search" => {
+            let substring = parameters
+                .get("substring")
+                .expect("no substring parameter provided")
+                .as_str()
+                .expect("substring parameter was not a string")
+                .to_string();
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+                optimizations::item_lookup::resolve_item_vertices(
+                    origin,
+                    crate_vertex
+                        .items_with_name_containing(&substring)
+                        .into_iter(),
+                )
+            })
+        }
+        "item_case_insensitive_search" => {
+            let name = parameters
+                .get("name")
+                .expect("no name parameter provided")
+                .as_str()
+                .expect("name parameter was not a string")
+                .to_string();
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+                optimizations::item_lookup::resolve_item_vertices(
+                    origin,
+                    crate_vertex
+                        .items_with_name_ignoring_case(&name)
+                        .iter()
+                        .copied(),
+                )
+            })
+        }
+        "item_fuzzy_search" => resolve_crate_fuzzy_search_edge(contexts, parameters),
+        "ffi_symbol" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let crate_ = vertex.as_crate().expect("vertex was not a Crate");
+            let mut symbols: Vec<&Item> = crate_
+                .index
+                .values()
+                .filter(|item| is_ffi_symbol(item))
+                .collect();
+            // Ensure a consistent order, since queries can observe this order directly.
+            symbols.sort_unstable_by(|a, b| a.id.stable_cmp(&b.id));
+            optimizations::item_lookup::resolve_item_vertices(origin, symbols.into_iter())
+        }),
+        "unreachable_pub_item" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+            let mut items: Vec<&Item> = crate_vertex
+                .inner
+                .index
+                .values()
+                .filter(|item| crate_vertex.effective_visibility(item) == "unreachable")
+                .collect();
+            // Ensure a consistent order, since queries can observe this order directly.
+            items.sort_unstable_by(|a, b| a.id.stable_cmp(&b.id));
+            optimizations::item_lookup::resolve_item_vertices(origin, items.into_iter())
+        }),
+        "deprecated_item" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+            optimizations::item_lookup::resolve_item_vertices(
+                origin,
+                crate_vertex.deprecated_items().iter().copied(),
+            )
+        }),
+        "macro" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+            optimizations::item_lookup::resolve_item_vertices(
+                origin,
+                ["DeclarativeMacro", "ProcMacro"]
+                    .into_iter()
+                    .flat_map(|kind| crate_vertex.items_of_kind(kind).iter().copied()),
+            )
+        }),
+        "function_like" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+            Box::new(crate_vertex.function_like_items().into_iter().map(
+                move |(kind, function, owner)| {
+                    origin.make_function_like_item_vertex(FunctionLikeItem {
+                        kind,
+                        function,
+                        owner,
+                    })
+                },
+            ))
+        }),
+        "item_at_location" => {
+            let file = parameters
+                .get("file")
+                .expect("no file parameter provided")
+                .as_str()
+                .expect("file parameter was not a string")
+                .to_string();
+            let line = match parameters.get("line").expect("no line parameter provided") {
+                trustfall::FieldValue::Int64(value) => usize::try_from(*value).ok(),
+                trustfall::FieldValue::Uint64(value) => usize::try_from(*value).ok(),
+                other => unreachable!("line parameter was not an integer: {other:?}"),
+            };
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+                match line {
+                    Some(line) => optimizations::item_lookup::resolve_item_vertices(
+                        origin,
+                        crate_vertex.items_at_location(&file, line).into_iter(),
+                    ),
+                    // Negative lines can't be part of any span.
+                    None => Box::new(std::iter::empty()),
+                }
+            })
+        }
         _ => unreachable!("resolve_crate_edge {edge_name}"),
     }
 }
 
+/// Whether the item is part of the crate's FFI surface:
+/// a function or static exported under a stable symbol name with `#[no_mangle]` or similar,
+/// or a function defined with a non-Rust ABI, like `pub extern "C" fn`.
+///
+/// Functions declared in `extern` blocks are imported rather than exported,
+/// so they aren't part of the crate's FFI surface.
+fn is_ffi_symbol(item: &Item) -> bool {
+    let exports_symbol = || parse_attributes(&item.attrs).any(|attr| attr.exports_symbol());
+    match &item.inner {
+        ItemEnum::Function(function) => {
+            function.has_body && (function.header.abi != Abi::Rust || exports_symbol())
+        }
+        ItemEnum::Static(..) => exports_symbol(),
+        _ => false,
+    }
+}
+
+#[cfg(feature = "fuzzy")]
+fn resolve_crate_fuzzy_search_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    parameters: &EdgeParameters,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    let name = parameters
+        .get("name")
+        .expect("no name parameter provided")
+        .as_str()
+        .expect("name parameter was not a string")
+        .to_string();
+    let max_distance = match parameters
+        .get("max_distance")
+        .expect("no max_distance parameter provided")
+    {
+        trustfall::FieldValue::Int64(value) => usize::try_from(*value).ok(),
+        trustfall::FieldValue::Uint64(value) => usize::try_from(*value).ok(),
+        other => unreachable!("max_distance parameter was not an integer: {other:?}"),
+    };
+
+    resolve_neighbors_with(contexts, move |vertex| {
+        let origin = vertex.origin;
+        let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+        match max_distance {
+            Some(max_distance) => optimizations::item_lookup::resolve_item_vertices(
+                origin,
+                crate_vertex
+                    .items_with_similar_name(&name, max_distance)
+                    .into_iter(),
+            ),
+            // No name is a negative number of edits away.
+            None => Box::new(std::iter::empty()),
+        }
+    })
+}
+
+/// Without the `fuzzy` feature there is no edit distance implementation,
+/// so fuzzy searches never find any items.
+#[cfg(not(feature = "fuzzy"))]
+fn resolve_crate_fuzzy_search_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    _parameters: &EdgeParameters,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    resolve_neighbors_with(contexts, |_| Box::new(std::iter::empty()))
+}
+
 pub(super) fn resolve_importable_edge<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     edge_name: &str,
@@ -77,11 +368,43 @@ pub(super) fn resolve_importable_edge<'a>(
 
             Box::new(
                 parent_crate
-                    .publicly_importable_names(item_id)
+                    .memoized_publicly_importable_paths(item_id)
                     .into_iter()
                     .map(move |x| origin.make_importable_path_vertex(x)),
             )
         }),
+        "counterpart" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let item = vertex.as_item().expect("vertex was not an Item");
+
+            let (parent_crate, other_crate, other_origin) = match (origin, previous_crate) {
+                (Origin::CurrentCrate, Some(previous_crate)) => {
+                    (current_crate, previous_crate, Origin::PreviousCrate)
+                }
+                (Origin::PreviousCrate, Some(previous_crate)) => {
+                    (previous_crate, current_crate, Origin::CurrentCrate)
+                }
+                (_, None) => return Box::new(std::iter::empty()),
+            };
+            // Items of other kinds sharing a path, like a function named the same as a struct,
+            // live in different namespaces and aren't counterparts.
+            let mut seen_ids = HashSet::new();
+            let counterparts: Vec<_> = parent_crate
+                .memoized_publicly_importable_paths(&item.id)
+                .into_iter()
+                .flat_map(|path| {
+                    other_crate
+                        .importable_items_in_namespaces(&path.components, path.namespaces)
+                        .collect::<Vec<_>>()
+                })
+                .filter(|counterpart| seen_ids.insert(&counterpart.id))
+                .collect();
+            Box::new(
+                counterparts
+                    .into_iter()
+                    .map(move |counterpart| other_origin.make_item_vertex(counterpart)),
+            )
+        }),
         _ => unreachable!("resolve_importable_edge {edge_name}"),
     }
 }
@@ -89,6 +412,8 @@ pub(super) fn resolve_importable_edge<'a>(
 pub(super) fn resolve_item_edge<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     edge_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
     match edge_name {
         "span" => resolve_neighbors_with(contexts, move |vertex| {
@@ -109,6 +434,43 @@ pub(super) fn resolve_item_edge<'a>(
                     .map(move |attr| origin.make_attribute_vertex(Attribute::new(attr.as_str()))),
             )
         }),
+        "lint_attribute" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let item = vertex.as_item().expect("vertex was not an Item");
+            Box::new(
+                parse_attributes(&item.attrs)
+                    .flat_map(|attr| attr.lint_attributes())
+                    .map(move |lint_attribute| origin.make_lint_attribute_vertex(lint_attribute)),
+            )
+        }),
+        "stability" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let item = vertex.as_item().expect("vertex was not an Item");
+            Box::new(
+                parse_attributes(&item.attrs)
+                    .find_map(|attr| attr.stability())
+                    .map(move |stability| origin.make_stability_vertex(stability))
+                    .into_iter(),
+            )
+        }),
+        "requires_feature" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let item = vertex.as_item().expect("vertex was not an Item");
+            let indexed_crate = match origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+            let metadata = &indexed_crate.package_metadata;
+            Box::new(
+                indexed_crate
+                    .required_features(item)
+                    .into_iter()
+                    .filter_map(move |feature| {
+                        let (name, enables) = metadata.features.get_key_value(feature)?;
+                        Some(origin.make_feature_vertex(metadata, name, enables))
+                    }),
+            )
+        }),
         _ => unreachable!("resolve_item_edge {edge_name}"),
     }
 }
@@ -117,6 +479,7 @@ pub(super) fn resolve_impl_owner_edge<'a>(
     adapter: &RustdocAdapter<'a>,
     contexts: ContextIterator<'a, Vertex<'a>>,
     edge_name: &str,
+    parameters: &EdgeParameters,
     resolve_info: &ResolveEdgeInfo,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
     match edge_name {
@@ -126,6 +489,78 @@ pub(super) fn resolve_impl_owner_edge<'a>(
             edge_name,
             resolve_info,
         ),
+        "trait_impl" => {
+            let current_crate = adapter.current_crate;
+            let previous_crate = adapter.previous_crate;
+            let trait_ = parameters
+                .get("trait")
+                .expect("no trait parameter provided")
+                .as_str()
+                .expect("trait parameter was not a string")
+                .to_string();
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let item = vertex.as_item().expect("vertex was not an ImplOwner");
+                let indexed_crate = match origin {
+                    Origin::CurrentCrate => current_crate,
+                    Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+                };
+                Box::new(
+                    indexed_crate
+                        .owner_impls_of_trait(&item.id, &trait_)
+                        .iter()
+                        .map(move |impl_item| origin.make_item_vertex(impl_item)),
+                )
+            })
+        }
+        "available_method" => {
+            let current_crate = adapter.current_crate;
+            let previous_crate = adapter.previous_crate;
+            let follow_deref = parameters
+                .get("follow_deref")
+                .expect("no follow_deref parameter provided")
+                .as_bool()
+                .expect("follow_deref parameter was not a boolean");
+            let include_supertraits = parameters
+                .get("include_supertraits")
+                .expect("no include_supertraits parameter provided")
+                .as_bool()
+                .expect("include_supertraits parameter was not a boolean");
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let item = vertex.as_item().expect("vertex was not an ImplOwner");
+                let indexed_crate = match origin {
+                    Origin::CurrentCrate => current_crate,
+                    Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+                };
+                Box::new(
+                    indexed_crate
+                        .available_methods(&item.id, follow_deref, include_supertraits)
+                        .into_iter()
+                        .map(move |(_, method)| origin.make_item_vertex(method)),
+                )
+            })
+        }
+        "method_name_conflict" => {
+            let current_crate = adapter.current_crate;
+            let previous_crate = adapter.previous_crate;
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let item = vertex.as_item().expect("vertex was not an ImplOwner");
+                let indexed_crate = match origin {
+                    Origin::CurrentCrate => current_crate,
+                    Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+                };
+                Box::new(
+                    indexed_crate
+                        .method_name_conflicts(&item.id)
+                        .into_iter()
+                        .map(move |(name, providers)| {
+                            origin.make_method_name_conflict_vertex(name, providers)
+                        }),
+                )
+            })
+        }
         _ => unreachable!("resolve_impl_owner_edge {edge_name}"),
     }
 }
@@ -133,10 +568,18 @@ pub(super) fn resolve_impl_owner_edge<'a>(
 pub(super) fn resolve_function_like_edge<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     edge_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
     match edge_name {
         "parameter" => resolve_neighbors_with(contexts, move |vertex| {
             let origin = vertex.origin;
+            let indexed_crate = match origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+            let self_type =
+                indexed_crate.impl_self_type(&vertex.as_item().expect("vertex was not an Item").id);
 
             Box::new(
                 vertex
@@ -145,13 +588,54 @@ pub(super) fn resolve_function_like_edge<'a>(
                     .decl
                     .inputs
                     .iter()
-                    .map(move |(name, _type_)| origin.make_function_parameter_vertex(name)),
+                    .map(move |(name, type_)| {
+                        origin.make_function_parameter_vertex(name, type_, self_type)
+                    }),
             )
         }),
         _ => unreachable!("resolve_function_like_edge {edge_name}"),
     }
 }
 
+fn struct_fields(struct_item: &rustdoc_types::Struct) -> Box<dyn Iterator<Item = &Id> + '_> {
+    match &struct_item.kind {
+        rustdoc_types::StructKind::Unit => Box::new(std::iter::empty()),
+        rustdoc_types::StructKind::Tuple(field_ids) => {
+            Box::new(field_ids.iter().filter_map(|x| x.as_ref()))
+        }
+        rustdoc_types::StructKind::Plain { fields, .. } => Box::new(fields.iter()),
+    }
+}
+
+/// Whether the type is certainly zero-sized, like `()`, `[T; 0]`, `PhantomData<T>`,
+/// or a struct in this crate without any fields.
+///
+/// Rustdoc doesn't record type layouts, so other zero-sized types aren't recognized.
+fn is_known_zero_sized(item_index: &HashMap<Id, Item>, ty: &Type) -> bool {
+    match ty {
+        Type::Tuple(types) => types.is_empty(),
+        Type::Array { len, .. } => len == "0",
+        Type::ResolvedPath(path) => {
+            let name = path.name.rsplit("::").next().unwrap_or_default();
+            if matches!(name, "PhantomData" | "PhantomPinned") {
+                return true;
+            }
+            match item_index.get(&path.id).map(|item| &item.inner) {
+                Some(ItemEnum::Struct(struct_item)) => match &struct_item.kind {
+                    rustdoc_types::StructKind::Unit => true,
+                    rustdoc_types::StructKind::Tuple(field_ids) => field_ids.is_empty(),
+                    rustdoc_types::StructKind::Plain {
+                        fields,
+                        fields_stripped,
+                    } => fields.is_empty() && !fields_stripped,
+                },
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
 pub(super) fn resolve_struct_edge<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     edge_name: &str,
@@ -173,17 +657,52 @@ pub(super) fn resolve_struct_edge<'a>(
                 }
             };
 
-            let field_ids_iter: Box<dyn Iterator<Item = &Id>> = match &struct_item.kind {
-                rustdoc_types::StructKind::Unit => Box::new(std::iter::empty()),
-                rustdoc_types::StructKind::Tuple(field_ids) => {
-                    Box::new(field_ids.iter().filter_map(|x| x.as_ref()))
+            Box::new(struct_fields(struct_item).map(move |field_id| {
+                origin.make_item_vertex(item_index.get(field_id).expect("missing item"))
+            }))
+        }),
+        "transparent_inner_type" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let item = vertex.as_item().expect("vertex was not an Item");
+            let struct_item = vertex.as_struct().expect("vertex was not a Struct");
+
+            let item_index = match origin {
+                Origin::CurrentCrate => &current_crate.inner.index,
+                Origin::PreviousCrate => {
+                    &previous_crate
+                        .expect("no previous crate provided")
+                        .inner
+                        .index
                 }
-                rustdoc_types::StructKind::Plain { fields, .. } => Box::new(fields.iter()),
             };
 
-            Box::new(field_ids_iter.map(move |field_id| {
-                origin.make_item_vertex(item_index.get(field_id).expect("missing item"))
-            }))
+            if !parse_attributes(&item.attrs).any(|attr| attr.is_repr_transparent()) {
+                return Box::new(std::iter::empty());
+            }
+
+            let field_types: Vec<&Type> = struct_fields(struct_item)
+                .filter_map(|field_id| match &item_index.get(field_id)?.inner {
+                    ItemEnum::StructField(field_type) => Some(field_type),
+                    _ => None,
+                })
+                .collect();
+            let inner_type = match field_types.as_slice() {
+                [single] => Some(*single),
+                _ => {
+                    let mut non_zst_types = field_types
+                        .iter()
+                        .filter(|field_type| !is_known_zero_sized(item_index, field_type));
+                    match (non_zst_types.next(), non_zst_types.next()) {
+                        (Some(inner_type), None) => Some(*inner_type),
+                        _ => None,
+                    }
+                }
+            };
+            Box::new(
+                inner_type
+                    .into_iter()
+                    .map(move |inner_type| origin.make_raw_type_vertex(inner_type)),
+            )
         }),
         _ => unreachable!("resolve_struct_edge {edge_name}"),
     }
@@ -274,6 +793,214 @@ pub(super) fn resolve_struct_field_edge<'a>(
     }
 }
 
+pub(super) fn resolve_constant_like_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "raw_type" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let (type_, _) = vertex
+                .as_constant_like()
+                .expect("not a ConstantLike vertex");
+            Box::new(std::iter::once(origin.make_raw_type_vertex(type_)))
+        }),
+        _ => unreachable!("resolve_constant_like_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_union_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "field" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let union_item = vertex.as_union().expect("vertex was not a Union");
+
+            let item_index = match origin {
+                Origin::CurrentCrate => &current_crate.inner.index,
+                Origin::PreviousCrate => {
+                    &previous_crate
+                        .expect("no previous crate provided")
+                        .inner
+                        .index
+                }
+            };
+
+            Box::new(union_item.fields.iter().map(move |field_id| {
+                origin.make_item_vertex(item_index.get(field_id).expect("missing item"))
+            }))
+        }),
+        _ => unreachable!("resolve_union_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_static_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "raw_type" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let static_ = vertex.as_static().expect("not a Static vertex");
+            Box::new(std::iter::once(origin.make_raw_type_vertex(&static_.type_)))
+        }),
+        _ => unreachable!("resolve_static_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_raw_type_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "trait_object" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let raw_type = vertex.as_raw_type().expect("not a RawType vertex");
+
+            let mut trait_objects = vec![];
+            collect_trait_objects(raw_type, &mut trait_objects);
+            Box::new(
+                trait_objects
+                    .into_iter()
+                    .map(move |ty| origin.make_raw_type_vertex(ty)),
+            )
+        }),
+        _ => unreachable!("resolve_raw_type_edge {edge_name}"),
+    }
+}
+
+/// Collects the `dyn Trait` types that appear anywhere within the given type,
+/// including the type itself, in the order in which they are written.
+fn collect_trait_objects<'a>(ty: &'a Type, output: &mut Vec<&'a Type>) {
+    match ty {
+        Type::DynTrait(dyn_trait) => {
+            output.push(ty);
+            for poly_trait in &dyn_trait.traits {
+                collect_trait_objects_in_path(&poly_trait.trait_, output);
+            }
+        }
+        Type::ResolvedPath(path) => collect_trait_objects_in_path(path, output),
+        Type::FunctionPointer(fn_pointer) => {
+            for (_, input) in &fn_pointer.decl.inputs {
+                collect_trait_objects(input, output);
+            }
+            if let Some(fn_output) = &fn_pointer.decl.output {
+                collect_trait_objects(fn_output, output);
+            }
+        }
+        Type::Tuple(types) => {
+            for ty in types {
+                collect_trait_objects(ty, output);
+            }
+        }
+        Type::Slice(ty)
+        | Type::Array { type_: ty, .. }
+        | Type::RawPointer { type_: ty, .. }
+        | Type::BorrowedRef { type_: ty, .. } => collect_trait_objects(ty, output),
+        Type::ImplTrait(bounds) => {
+            for bound in bounds {
+                if let GenericBound::TraitBound { trait_, .. } = bound {
+                    collect_trait_objects_in_path(trait_, output);
+                }
+            }
+        }
+        Type::QualifiedPath {
+            args,
+            self_type,
+            trait_,
+            ..
+        } => {
+            collect_trait_objects(self_type, output);
+            collect_trait_objects_in_path(trait_, output);
+            collect_trait_objects_in_generic_args(args, output);
+        }
+        Type::Generic(..) | Type::Primitive(..) | Type::Infer => {}
+    }
+}
+
+fn collect_trait_objects_in_path<'a>(path: &'a Path, output: &mut Vec<&'a Type>) {
+    if let Some(args) = &path.args {
+        collect_trait_objects_in_generic_args(args, output);
+    }
+}
+
+fn collect_trait_objects_in_generic_args<'a>(args: &'a GenericArgs, output: &mut Vec<&'a Type>) {
+    match args {
+        GenericArgs::AngleBracketed { args, bindings } => {
+            for arg in args {
+                if let GenericArg::Type(ty) = arg {
+                    collect_trait_objects(ty, output);
+                }
+            }
+            for binding in bindings {
+                collect_trait_objects_in_generic_args(&binding.args, output);
+                if let TypeBindingKind::Equality(Term::Type(ty)) = &binding.binding {
+                    collect_trait_objects(ty, output);
+                }
+            }
+        }
+        GenericArgs::Parenthesized {
+            inputs,
+            output: fn_output,
+        } => {
+            for input in inputs {
+                collect_trait_objects(input, output);
+            }
+            if let Some(fn_output) = fn_output {
+                collect_trait_objects(fn_output, output);
+            }
+        }
+    }
+}
+
+pub(super) fn resolve_dyn_trait_type_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "trait_bound" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let dyn_trait = vertex.as_dyn_trait().expect("not a DynTraitType vertex");
+
+            // Trait objects can't have `?Trait` or `~const Trait` bounds.
+            Box::new(dyn_trait.traits.iter().map(move |poly_trait| {
+                origin.make_trait_bound_vertex(&poly_trait.trait_, &TraitBoundModifier::None)
+            }))
+        }),
+        _ => unreachable!("resolve_dyn_trait_type_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_generics_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "generic_type_parameter" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let generics = vertex.as_generics().expect("vertex was not a generic item");
+
+            Box::new(
+                generics
+                    .params
+                    .iter()
+                    .filter_map(move |param| match &param.kind {
+                        GenericParamDefKind::Type { .. } => {
+                            Some(origin.make_generic_type_parameter_vertex(generics, param))
+                        }
+                        GenericParamDefKind::Lifetime { .. }
+                        | GenericParamDefKind::Const { .. } => None,
+                    }),
+            )
+        }),
+        _ => unreachable!("resolve_generics_edge {edge_name}"),
+    }
+}
+
 pub(super) fn resolve_impl_edge<'a>(
     adapter: &RustdocAdapter<'a>,
     contexts: ContextIterator<'a, Vertex<'a>>,
@@ -331,6 +1058,36 @@ pub(super) fn resolve_impl_edge<'a>(
                 Box::new(std::iter::empty())
             }
         }),
+        "associated_constant" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let item_index = match origin {
+                Origin::CurrentCrate => &current_crate.inner.index,
+                Origin::PreviousCrate => {
+                    &previous_crate
+                        .expect("no previous crate provided")
+                        .inner
+                        .index
+                }
+            };
+
+            let impl_vertex = vertex.as_impl().expect("not an Impl vertex");
+            Box::new(impl_vertex.items.iter().filter_map(move |item_id| {
+                let next_item = item_index.get(item_id)?;
+                match &next_item.inner {
+                    rustdoc_types::ItemEnum::AssocConst { .. } => {
+                        Some(origin.make_item_vertex(next_item))
+                    }
+                    _ => None,
+                }
+            }))
+        }),
+        "self_type" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let impl_vertex = vertex.as_impl().expect("not an Impl vertex");
+            Box::new(std::iter::once(
+                origin.make_raw_type_vertex(&impl_vertex.for_),
+            ))
+        }),
         _ => unreachable!("resolve_impl_edge {edge_name}"),
     }
 }
@@ -369,10 +1126,242 @@ pub(super) fn resolve_trait_edge<'a>(
                 }
             }))
         }),
+        "associated_type" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let item_index = match origin {
+                Origin::CurrentCrate => &current_crate.inner.index,
+                Origin::PreviousCrate => {
+                    &previous_crate
+                        .expect("no previous crate provided")
+                        .inner
+                        .index
+                }
+            };
+
+            let trait_vertex = vertex.as_trait().expect("not a Trait vertex");
+            Box::new(trait_vertex.items.iter().filter_map(move |item_id| {
+                let next_item = item_index.get(item_id)?;
+                match &next_item.inner {
+                    rustdoc_types::ItemEnum::AssocType { .. } => {
+                        Some(origin.make_item_vertex(next_item))
+                    }
+                    _ => None,
+                }
+            }))
+        }),
+        "associated_constant" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let item_index = match origin {
+                Origin::CurrentCrate => &current_crate.inner.index,
+                Origin::PreviousCrate => {
+                    &previous_crate
+                        .expect("no previous crate provided")
+                        .inner
+                        .index
+                }
+            };
+
+            let trait_vertex = vertex.as_trait().expect("not a Trait vertex");
+            Box::new(trait_vertex.items.iter().filter_map(move |item_id| {
+                let next_item = item_index.get(item_id)?;
+                match &next_item.inner {
+                    rustdoc_types::ItemEnum::AssocConst { .. } => {
+                        Some(origin.make_item_vertex(next_item))
+                    }
+                    _ => None,
+                }
+            }))
+        }),
+        "bounded_function" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let indexed_crate = match origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+
+            let trait_item = vertex.as_item().expect("not an Item vertex");
+            Box::new(
+                indexed_crate
+                    .functions_bounded_by(&trait_item.id)
+                    .iter()
+                    .map(move |function| origin.make_item_vertex(function)),
+            )
+        }),
+        "foreign_impl" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let indexed_crate = match origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+
+            let trait_item = vertex.as_item().expect("not an Item vertex");
+            Box::new(
+                indexed_crate
+                    .foreign_impls_of(&trait_item.id)
+                    .iter()
+                    .map(move |impl_item| origin.make_item_vertex(impl_item)),
+            )
+        }),
         _ => unreachable!("resolve_trait_edge {edge_name}"),
     }
 }
 
+pub(super) fn resolve_associated_type_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "trait_bound" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let (bounds, _) = vertex
+                .as_associated_type()
+                .expect("vertex was not an AssociatedType");
+
+            Box::new(bounds.iter().filter_map(move |bound| match bound {
+                GenericBound::TraitBound {
+                    trait_, modifier, ..
+                } => Some(origin.make_trait_bound_vertex(trait_, modifier)),
+                GenericBound::Outlives(..) => None,
+            }))
+        }),
+        _ => unreachable!("resolve_associated_type_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_trait_alias_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "trait_bound" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let trait_alias = vertex
+                .as_trait_alias()
+                .expect("vertex was not a TraitAlias");
+
+            Box::new(
+                trait_alias
+                    .params
+                    .iter()
+                    .filter_map(move |bound| match bound {
+                        GenericBound::TraitBound {
+                            trait_, modifier, ..
+                        } => Some(origin.make_trait_bound_vertex(trait_, modifier)),
+                        GenericBound::Outlives(..) => None,
+                    }),
+            )
+        }),
+        _ => unreachable!("resolve_trait_alias_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_trait_bound_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "trait" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let parent_crate = match origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+
+            let (path, _) = vertex
+                .as_trait_bound()
+                .expect("vertex was not a TraitBound");
+
+            // Same lookup as `Impl.implemented_trait`: traits from other crates
+            // are only available if they are among the manually-inlined built-in traits.
+            let found_item = parent_crate
+                .inner
+                .index
+                .get(&path.id)
+                .or_else(|| parent_crate.manually_inlined_builtin_traits.get(&path.id));
+            match found_item {
+                Some(item) => Box::new(std::iter::once(origin.make_item_vertex(item))),
+                None => Box::new(std::iter::empty()),
+            }
+        }),
+        _ => unreachable!("resolve_trait_bound_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_import_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "resolves_through" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let import = vertex.as_import().expect("vertex was not an Import");
+
+            let parent_crate = match origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+
+            optimizations::item_lookup::resolve_item_vertices(
+                origin,
+                parent_crate.import_resolution_chain(import).into_iter(),
+            )
+        }),
+        _ => unreachable!("resolve_import_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_module_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+    parameters: &EdgeParameters,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "contains" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let module = vertex.as_module().expect("vertex was not a Module");
+            let parent_crate = match origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+            Box::new(
+                module
+                    .items
+                    .iter()
+                    .filter_map(move |id| parent_crate.inner.index.get(id))
+                    .map(move |item| origin.make_item_vertex(item)),
+            )
+        }),
+        "module_item" => {
+            let include_reexports = parameters
+                .get("include_reexports")
+                .expect("no include_reexports parameter provided")
+                .as_bool()
+                .expect("include_reexports parameter was not a boolean");
+            resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                let item = vertex.as_item().expect("vertex was not a Module");
+                let parent_crate = match origin {
+                    Origin::CurrentCrate => current_crate,
+                    Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+                };
+                Box::new(
+                    parent_crate
+                        .transitive_module_items(item, include_reexports)
+                        .into_iter()
+                        .map(move |item| origin.make_item_vertex(item)),
+                )
+            })
+        }
+        _ => unreachable!("resolve_module_edge {edge_name}"),
+    }
+}
+
 pub(super) fn resolve_implemented_trait_edge<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     edge_name: &str,
@@ -390,6 +1379,137 @@ pub(super) fn resolve_implemented_trait_edge<'a>(
     }
 }
 
+pub(super) fn resolve_method_name_conflict_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    let providers_of = |vertex: &Vertex<'a>| {
+        let (_, providers) = vertex
+            .as_method_name_conflict()
+            .expect("vertex was not a MethodNameConflict");
+        (vertex.origin, providers.to_vec())
+    };
+    match edge_name {
+        "impl" => resolve_neighbors_with(contexts, move |vertex| {
+            let (origin, providers) = providers_of(vertex);
+            Box::new(
+                providers
+                    .into_iter()
+                    .map(move |(impl_item, _)| origin.make_item_vertex(impl_item)),
+            )
+        }),
+        "method" => resolve_neighbors_with(contexts, move |vertex| {
+            let (origin, providers) = providers_of(vertex);
+            Box::new(
+                providers
+                    .into_iter()
+                    .map(move |(_, method)| origin.make_item_vertex(method)),
+            )
+        }),
+        _ => unreachable!("resolve_method_name_conflict_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_file_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "item" => resolve_neighbors_with(contexts, move |vertex| {
+            let (_, items) = vertex.as_file().expect("vertex was not a File");
+            optimizations::item_lookup::resolve_item_vertices(vertex.origin, items.iter().copied())
+        }),
+        _ => unreachable!("resolve_file_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_feature_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "enabled_feature" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let (metadata, _, enables) = vertex.as_feature().expect("vertex was not a Feature");
+            Box::new(metadata.enabled_features(enables).map(move |name| {
+                let (name, enables) = metadata
+                    .features
+                    .get_key_value(name)
+                    .expect("enabled feature was not declared");
+                origin.make_feature_vertex(metadata, name, enables)
+            }))
+        }),
+        "optional_dependency" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let (metadata, _, enables) = vertex.as_feature().expect("vertex was not a Feature");
+            Box::new(
+                metadata
+                    .activated_dependencies(enables)
+                    .map(move |name| origin.make_optional_dependency_vertex(metadata, name)),
+            )
+        }),
+        _ => unreachable!("resolve_feature_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_optional_dependency_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "enabling_feature" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let (metadata, dependency) = vertex
+                .as_optional_dependency()
+                .expect("vertex was not an OptionalDependency");
+            Box::new(
+                metadata
+                    .features
+                    .iter()
+                    .filter(move |(_, enables)| {
+                        metadata
+                            .activated_dependencies(enables)
+                            .any(|activated| activated == dependency)
+                    })
+                    .map(move |(name, enables)| {
+                        origin.make_feature_vertex(metadata, name, enables)
+                    }),
+            )
+        }),
+        _ => unreachable!("resolve_optional_dependency_edge {edge_name}"),
+    }
+}
+
+pub(super) fn resolve_function_like_item_edge<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    edge_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    match edge_name {
+        "function" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let function_like = vertex
+                .as_function_like_item()
+                .expect("vertex was not a FunctionLikeItem");
+            Box::new(std::iter::once(
+                origin.make_item_vertex(function_like.function),
+            ))
+        }),
+        "owner" => resolve_neighbors_with(contexts, move |vertex| {
+            let origin = vertex.origin;
+            let function_like = vertex
+                .as_function_like_item()
+                .expect("vertex was not a FunctionLikeItem");
+            Box::new(
+                function_like
+                    .owner
+                    .into_iter()
+                    .map(move |owner| origin.make_item_vertex(owner)),
+            )
+        }),
+        _ => unreachable!("resolve_function_like_item_edge {edge_name}"),
+    }
+}
+
 pub(super) fn resolve_attribute_edge<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     edge_name: &str,
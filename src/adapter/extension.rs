@@ -0,0 +1,111 @@
+use rustdoc_types::Item;
+use trustfall::{provider::EdgeParameters, FieldValue};
+
+use super::vertex::Vertex;
+
+/// Additional properties and edges that downstream crates can attach
+/// to the adapter's existing vertex types, without forking the schema.
+///
+/// Register extensions with [`RustdocAdapter::with_extension()`](super::RustdocAdapter::with_extension),
+/// then run queries against [`RustdocAdapter::extended_schema()`](super::RustdocAdapter::extended_schema)
+/// so that the extension's fields are part of the schema.
+pub trait AdapterExtension<'a> {
+    /// The fields this extension adds to the schema, as `(type name, field definition)` pairs.
+    ///
+    /// For example, `("Struct", "lint_level: String")` adds a `lint_level` property to `Struct`,
+    /// and `("Struct", "related_item(kind: String!): [Item!]")` adds an edge to it.
+    ///
+    /// Fields added to an interface are also added to all types that implement it.
+    fn schema_fields(&self) -> Vec<(&'static str, &'static str)>;
+
+    /// Resolve the value of one of this extension's properties for the given vertex.
+    ///
+    /// `type_name` is the type on which the property was queried, which may be
+    /// an interface implemented by the vertex's actual type.
+    fn resolve_property(
+        &self,
+        vertex: &Vertex<'a>,
+        type_name: &str,
+        property_name: &str,
+    ) -> FieldValue;
+
+    /// Resolve the neighbors along one of this extension's edges for the given vertex.
+    ///
+    /// Extension edges point to items in the same crate as the vertex they start from,
+    /// so the edge's type in [`schema_fields()`](Self::schema_fields) should be
+    /// `Item` or one of its subtypes.
+    fn resolve_neighbors(
+        &self,
+        vertex: &Vertex<'a>,
+        type_name: &str,
+        edge_name: &str,
+        parameters: &EdgeParameters,
+    ) -> Box<dyn Iterator<Item = &'a Item> + 'a> {
+        let _ = (vertex, parameters);
+        unreachable!(
+            "extension does not define any edges, but was asked for {type_name}.{edge_name}"
+        )
+    }
+}
+
+/// Splits the field name off a field definition like `name(arg: Int!): [Item!]`.
+pub(super) fn field_name(field_definition: &str) -> &str {
+    let end = field_definition
+        .find(['(', ':'])
+        .unwrap_or(field_definition.len());
+    field_definition[..end].trim()
+}
+
+/// Returns the names of all schema types that are, or implement, the given type.
+pub(super) fn types_including(schema_text: &str, type_name: &str) -> Vec<String> {
+    schema_text
+        .lines()
+        .filter_map(parse_type_header)
+        .filter(|(name, implements)| *name == type_name || implements.contains(&type_name))
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Adds the given `(type name, field definition)` pairs to the schema text.
+pub(super) fn extend_schema_text(schema_text: &str, fields: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(schema_text.len());
+    let mut fields_to_add: Vec<&str> = vec![];
+
+    for line in schema_text.lines() {
+        if let Some((name, implements)) = parse_type_header(line) {
+            fields_to_add = fields
+                .iter()
+                .filter(|(type_name, _)| *type_name == name || implements.contains(type_name))
+                .map(|(_, definition)| *definition)
+                .collect();
+        } else if line == "}" && !fields_to_add.is_empty() {
+            result.push_str("\n  # fields from adapter extensions\n");
+            for definition in fields_to_add.drain(..) {
+                result.push_str("  ");
+                result.push_str(definition);
+                result.push('\n');
+            }
+        }
+
+        result.push_str(line);
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Parses lines like `type Struct implements Item & Importable {`
+/// into the declared type's name and the names of the interfaces it implements.
+fn parse_type_header(line: &str) -> Option<(&str, Vec<&str>)> {
+    let declaration = line
+        .strip_prefix("type ")
+        .or_else(|| line.strip_prefix("interface "))?
+        .strip_suffix('{')?
+        .trim();
+
+    let (name, implements) = match declaration.split_once(" implements ") {
+        Some((name, implements)) => (name, implements.split('&').map(str::trim).collect()),
+        None => (declaration, vec![]),
+    };
+    Some((name.trim(), implements))
+}
@@ -1,21 +1,21 @@
-use std::sync::Arc;
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::Arc};
 
 use trustfall::{
     provider::{
-        resolve_coercion_with, Adapter, ContextIterator, ContextOutcomeIterator, EdgeParameters,
-        ResolveEdgeInfo, ResolveInfo, Typename, VertexIterator,
+        resolve_coercion_with, resolve_neighbors_with, resolve_property_with, Adapter,
+        ContextIterator, ContextOutcomeIterator, EdgeParameters, ResolveEdgeInfo, ResolveInfo,
+        Typename, VertexIterator,
     },
     FieldValue, Schema,
 };
 
-use crate::indexed_crate::IndexedCrate;
+use crate::{indexed_crate::IndexedCrate, PathNormalization};
 
-use self::{
-    origin::Origin,
-    vertex::{Vertex, VertexKind},
-};
+use self::{origin::Origin, vertex::VertexKind};
 
+mod counters;
 mod edges;
+mod extension;
 mod optimizations;
 mod origin;
 mod properties;
@@ -24,10 +24,29 @@ mod vertex;
 #[cfg(test)]
 mod tests;
 
+pub use counters::ExecutionCounters;
+pub use extension::AdapterExtension;
+pub use vertex::Vertex;
+
+pub(crate) use vertex::{item_typename, ITEM_TYPENAMES};
+
+const SCHEMA_TEXT: &str = include_str!("../rustdoc_schema.graphql");
+
 #[non_exhaustive]
 pub struct RustdocAdapter<'a> {
     current_crate: &'a IndexedCrate<'a>,
     previous_crate: Option<&'a IndexedCrate<'a>>,
+
+    extensions: Vec<Rc<dyn AdapterExtension<'a> + 'a>>,
+
+    /// (type name, field name) -> index of the extension in `extensions` that resolves it
+    extension_fields: HashMap<(String, String), usize>,
+
+    /// Work done while executing queries, if counting was enabled.
+    counters: Option<Rc<RefCell<ExecutionCounters>>>,
+
+    /// How to normalize paths when rendering type names.
+    path_normalization: PathNormalization,
 }
 
 impl<'a> RustdocAdapter<'a> {
@@ -38,11 +57,246 @@ impl<'a> RustdocAdapter<'a> {
         Self {
             current_crate,
             previous_crate,
+            extensions: vec![],
+            extension_fields: HashMap::new(),
+            counters: None,
+            path_normalization: PathNormalization::default(),
         }
     }
 
     pub fn schema() -> Schema {
-        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema not valid")
+        Schema::parse(SCHEMA_TEXT).expect("schema not valid")
+    }
+
+    /// Register an extension that adds properties and edges to existing schema types.
+    ///
+    /// Queries using the extension's fields must be run against [`Self::extended_schema()`].
+    pub fn with_extension(mut self, extension: impl AdapterExtension<'a> + 'a) -> Self {
+        let index = self.extensions.len();
+        for (type_name, definition) in extension.schema_fields() {
+            let field_name = extension::field_name(definition);
+            for name in extension::types_including(SCHEMA_TEXT, type_name) {
+                let previous = self
+                    .extension_fields
+                    .insert((name, field_name.to_string()), index);
+                assert!(
+                    previous.is_none(),
+                    "multiple extensions define field {field_name} on type {type_name}"
+                );
+            }
+        }
+        self.extensions.push(Rc::new(extension));
+        self
+    }
+
+    /// The adapter's schema, including the fields added by all registered extensions.
+    pub fn extended_schema(&self) -> Schema {
+        let fields: Vec<_> = self
+            .extensions
+            .iter()
+            .flat_map(|extension| extension.schema_fields())
+            .collect();
+        Schema::parse(extension::extend_schema_text(SCHEMA_TEXT, &fields))
+            .expect("extended schema not valid")
+    }
+
+    /// Count the vertices produced by each entry point and edge while executing queries.
+    ///
+    /// The counts accumulate across all queries run with this adapter,
+    /// and are retrieved with [`Self::execution_counters()`].
+    pub fn with_execution_counters(mut self) -> Self {
+        self.counters = Some(Default::default());
+        self
+    }
+
+    /// The work done so far while executing queries,
+    /// or `None` if counting wasn't enabled with [`Self::with_execution_counters()`].
+    pub fn execution_counters(&self) -> Option<ExecutionCounters> {
+        self.counters
+            .as_ref()
+            .map(|counters| counters.borrow().clone())
+    }
+
+    /// Normalize the paths in rendered type names, like `RawType.display_name`, as configured.
+    pub fn with_path_normalization(mut self, path_normalization: PathNormalization) -> Self {
+        self.path_normalization = path_normalization;
+        self
+    }
+
+    fn extension_for(
+        &self,
+        type_name: &str,
+        field_name: &str,
+    ) -> Option<Rc<dyn AdapterExtension<'a> + 'a>> {
+        if self.extension_fields.is_empty() {
+            return None;
+        }
+        self.extension_fields
+            .get(&(type_name.to_string(), field_name.to_string()))
+            .map(|index| self.extensions[*index].clone())
+    }
+
+    fn resolve_neighbors_uncounted(
+        &self,
+        contexts: ContextIterator<'a, Vertex<'a>>,
+        type_name: &Arc<str>,
+        edge_name: &Arc<str>,
+        parameters: &EdgeParameters,
+        resolve_info: &ResolveEdgeInfo,
+    ) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+        if let Some(extension) = self.extension_for(type_name, edge_name) {
+            let type_name = type_name.clone();
+            let edge_name = edge_name.clone();
+            let parameters = parameters.clone();
+            return resolve_neighbors_with(contexts, move |vertex| {
+                let origin = vertex.origin;
+                optimizations::item_lookup::resolve_item_vertices(
+                    origin,
+                    extension.resolve_neighbors(vertex, &type_name, &edge_name, &parameters),
+                )
+            });
+        }
+
+        match type_name.as_ref() {
+            "CrateDiff" => edges::resolve_crate_diff_edge(contexts, edge_name),
+            "Crate" => {
+                edges::resolve_crate_edge(self, contexts, edge_name, parameters, resolve_info)
+            }
+            "Importable" | "ImplOwner" | "Struct" | "Enum" | "Union" | "Trait" | "TraitAlias"
+            | "Function" | "Macro" | "ProcMacro" | "DeclarativeMacro" | "Typedef" | "Constant"
+            | "Static" | "Module" | "OtherItem"
+                if matches!(
+                    edge_name.as_ref(),
+                    "importable_path" | "canonical_path" | "counterpart"
+                ) =>
+            {
+                edges::resolve_importable_edge(
+                    contexts,
+                    edge_name,
+                    self.current_crate,
+                    self.previous_crate,
+                )
+            }
+            "Item" | "ImplOwner" | "Struct" | "StructField" | "Enum" | "Union" | "Variant"
+            | "PlainVariant" | "TupleVariant" | "StructVariant" | "Trait" | "TraitAlias"
+            | "Function" | "Method" | "Impl" | "Macro" | "ProcMacro" | "DeclarativeMacro"
+            | "Import" | "Typedef" | "Primitive" | "AssociatedType" | "Constant" | "Static"
+            | "AssociatedConstant" | "Module" | "OtherItem" | "GenericItem" | "HasAttributes"
+                if matches!(
+                    edge_name.as_ref(),
+                    "span" | "attribute" | "lint_attribute" | "stability" | "requires_feature"
+                ) =>
+            {
+                edges::resolve_item_edge(
+                    contexts,
+                    edge_name,
+                    self.current_crate,
+                    self.previous_crate,
+                )
+            }
+            "ImplOwner" | "Struct" | "Enum" | "Union" | "Primitive"
+                if matches!(
+                    edge_name.as_ref(),
+                    "impl"
+                        | "inherent_impl"
+                        | "trait_impl"
+                        | "available_method"
+                        | "method_name_conflict"
+                ) =>
+            {
+                edges::resolve_impl_owner_edge(self, contexts, edge_name, parameters, resolve_info)
+            }
+            "GenericItem" | "Struct" | "Enum" | "Union" | "Trait" | "TraitAlias" | "Function"
+            | "Method" | "Impl" | "Typedef"
+                if matches!(edge_name.as_ref(), "generic_type_parameter") =>
+            {
+                edges::resolve_generics_edge(contexts, edge_name)
+            }
+            "Function" | "Method" | "FunctionLike" if matches!(edge_name.as_ref(), "parameter") => {
+                edges::resolve_function_like_edge(
+                    contexts,
+                    edge_name,
+                    self.current_crate,
+                    self.previous_crate,
+                )
+            }
+            "Struct" => edges::resolve_struct_edge(
+                contexts,
+                edge_name,
+                self.current_crate,
+                self.previous_crate,
+            ),
+            "Union" => edges::resolve_union_edge(
+                contexts,
+                edge_name,
+                self.current_crate,
+                self.previous_crate,
+            ),
+            "Variant" | "PlainVariant" | "TupleVariant" | "StructVariant" => {
+                edges::resolve_variant_edge(
+                    contexts,
+                    edge_name,
+                    self.current_crate,
+                    self.previous_crate,
+                )
+            }
+            "Enum" => edges::resolve_enum_edge(
+                contexts,
+                edge_name,
+                self.current_crate,
+                self.previous_crate,
+            ),
+            "ConstantLike" | "Constant" | "AssociatedConstant"
+                if matches!(edge_name.as_ref(), "raw_type") =>
+            {
+                edges::resolve_constant_like_edge(contexts, edge_name)
+            }
+            "StructField" => edges::resolve_struct_field_edge(contexts, edge_name),
+            "Static" => edges::resolve_static_edge(contexts, edge_name),
+            "Impl" => edges::resolve_impl_edge(self, contexts, edge_name, resolve_info),
+            "Trait" => edges::resolve_trait_edge(
+                contexts,
+                edge_name,
+                self.current_crate,
+                self.previous_crate,
+            ),
+            "Import" => edges::resolve_import_edge(
+                contexts,
+                edge_name,
+                self.current_crate,
+                self.previous_crate,
+            ),
+            "Module" => edges::resolve_module_edge(
+                contexts,
+                edge_name,
+                parameters,
+                self.current_crate,
+                self.previous_crate,
+            ),
+            "AssociatedType" => edges::resolve_associated_type_edge(contexts, edge_name),
+            "TraitAlias" => edges::resolve_trait_alias_edge(contexts, edge_name),
+            "TraitBound" => edges::resolve_trait_bound_edge(
+                contexts,
+                edge_name,
+                self.current_crate,
+                self.previous_crate,
+            ),
+            "ImplementedTrait" => edges::resolve_implemented_trait_edge(contexts, edge_name),
+            "FunctionLikeItem" => edges::resolve_function_like_item_edge(contexts, edge_name),
+            "MethodNameConflict" => edges::resolve_method_name_conflict_edge(contexts, edge_name),
+            "File" => edges::resolve_file_edge(contexts, edge_name),
+            "Feature" => edges::resolve_feature_edge(contexts, edge_name),
+            "OptionalDependency" => edges::resolve_optional_dependency_edge(contexts, edge_name),
+            "RawType" | "ResolvedPathType" | "PrimitiveType" | "DynTraitType" | "OtherType"
+                if matches!(edge_name.as_ref(), "trait_object") =>
+            {
+                edges::resolve_raw_type_edge(contexts, edge_name)
+            }
+            "DynTraitType" => edges::resolve_dyn_trait_type_edge(contexts, edge_name),
+            "Attribute" => edges::resolve_attribute_edge(contexts, edge_name),
+            "AttributeMetaItem" => edges::resolve_attribute_meta_item_edge(contexts, edge_name),
+            _ => unreachable!("resolve_neighbors {type_name} {edge_name} {parameters:?}"),
+        }
     }
 }
 
@@ -55,7 +309,7 @@ impl<'a> Adapter<'a> for RustdocAdapter<'a> {
         _parameters: &EdgeParameters,
         _resolve_info: &ResolveInfo,
     ) -> VertexIterator<'a, Self::Vertex> {
-        match edge_name.as_ref() {
+        let vertices: VertexIterator<'a, Self::Vertex> = match edge_name.as_ref() {
             "Crate" => Box::new(std::iter::once(Vertex::new_crate(
                 Origin::CurrentCrate,
                 self.current_crate,
@@ -67,7 +321,17 @@ impl<'a> Adapter<'a> for RustdocAdapter<'a> {
                     kind: VertexKind::CrateDiff((self.current_crate, previous_crate)),
                 }))
             }
+            "PublicReexport" => Box::new(
+                self.current_crate
+                    .public_reexports()
+                    .map(|item| Origin::CurrentCrate.make_item_vertex(item)),
+            ),
             _ => unreachable!("resolve_starting_vertices {edge_name}"),
+        };
+
+        match &self.counters {
+            Some(counters) => counters::count_entry_point(counters, edge_name, vertices),
+            None => vertices,
         }
     }
 
@@ -86,49 +350,210 @@ impl<'a> Adapter<'a> for RustdocAdapter<'a> {
                 }
                 None => (ctx, FieldValue::Null),
             }))
+        } else if let Some(extension) = self.extension_for(type_name, property_name) {
+            let type_name = type_name.clone();
+            let property_name = property_name.clone();
+            resolve_property_with(contexts, move |vertex| {
+                extension.resolve_property(vertex, &type_name, &property_name)
+            })
         } else {
             match type_name.as_ref() {
                 "Crate" => properties::resolve_crate_property(contexts, property_name),
-                "Item" => properties::resolve_item_property(contexts, property_name),
-                "ImplOwner" | "Struct" | "StructField" | "Enum" | "Variant" | "PlainVariant"
-                | "TupleVariant" | "StructVariant" | "Trait" | "Function" | "Method" | "Impl"
+                "Item" => properties::resolve_item_property(
+                    contexts,
+                    property_name,
+                    self.current_crate,
+                    self.previous_crate,
+                ),
+                "ImplOwner" | "Struct" | "StructField" | "Enum" | "Union" | "Variant"
+                | "PlainVariant" | "TupleVariant" | "StructVariant" | "Trait" | "TraitAlias"
+                | "Function" | "Method" | "Impl" | "Macro" | "ProcMacro" | "DeclarativeMacro"
+                | "Import" | "Typedef" | "Primitive" | "AssociatedType" | "Constant" | "Static"
+                | "AssociatedConstant" | "Module" | "OtherItem" | "GenericItem" | "Documented"
+                | "HasAttributes"
                     if matches!(
                         property_name.as_ref(),
-                        "id" | "crate_id" | "name" | "docs" | "attrs" | "visibility_limit"
+                        "id" | "crate_id"
+                            | "name"
+                            | "docs"
+                            | "attrs"
+                            | "visibility_limit"
+                            | "doc_hidden"
+                            | "effective_visibility"
+                            | "is_effectively_deprecated"
                     ) =>
                 {
                     // properties inherited from Item, accesssed on Item subtypes
-                    properties::resolve_item_property(contexts, property_name)
+                    properties::resolve_item_property(
+                        contexts,
+                        property_name,
+                        self.current_crate,
+                        self.previous_crate,
+                    )
+                }
+                "GenericItem" | "Struct" | "Enum" | "Union" | "Trait" | "TraitAlias"
+                | "Function" | "Method" | "Impl" | "Typedef"
+                    if matches!(
+                        property_name.as_ref(),
+                        "generic_type_param_count" | "lifetime_param_count"
+                    ) =>
+                {
+                    properties::resolve_generic_item_property(contexts, property_name)
                 }
                 "Struct" => properties::resolve_struct_property(contexts, property_name),
-                "Enum" => properties::resolve_enum_property(contexts, property_name),
+                "StructField" => properties::resolve_struct_field_property(contexts, property_name),
+                "Union" => properties::resolve_union_property(contexts, property_name),
+                "Enum" => properties::resolve_enum_property(
+                    contexts,
+                    property_name,
+                    self.current_crate,
+                    self.previous_crate,
+                ),
+                "Variant" | "PlainVariant" | "TupleVariant" | "StructVariant" => {
+                    properties::resolve_variant_property(contexts, property_name)
+                }
+                "Span" if property_name.as_ref() == "source" => {
+                    properties::resolve_span_source_property(
+                        contexts,
+                        property_name,
+                        self.current_crate,
+                        self.previous_crate,
+                    )
+                }
+                "Span"
+                    if matches!(
+                        property_name.as_ref(),
+                        "begin_byte_offset" | "end_byte_offset"
+                    ) =>
+                {
+                    properties::resolve_span_byte_offset_property(
+                        contexts,
+                        property_name,
+                        self.current_crate,
+                        self.previous_crate,
+                    )
+                }
                 "Span" => properties::resolve_span_property(contexts, property_name),
                 "Path" => properties::resolve_path_property(contexts, property_name),
                 "ImportablePath" => {
                     properties::resolve_importable_path_property(contexts, property_name)
                 }
                 "FunctionLike" | "Function" | "Method"
-                    if matches!(property_name.as_ref(), "const" | "unsafe" | "async") =>
+                    if matches!(
+                        property_name.as_ref(),
+                        "const"
+                            | "unsafe"
+                            | "async"
+                            | "c_variadic"
+                            | "abi"
+                            | "abi_unwind"
+                            | "return_type_name"
+                            | "param_count"
+                    ) =>
                 {
-                    properties::resolve_function_like_property(contexts, property_name)
+                    properties::resolve_function_like_property(
+                        contexts,
+                        property_name,
+                        self.current_crate,
+                        self.previous_crate,
+                    )
                 }
                 "FunctionParameter" => {
                     properties::resolve_function_parameter_property(contexts, property_name)
                 }
-                "Impl" => properties::resolve_impl_property(contexts, property_name),
+                "Impl" => properties::resolve_impl_property(
+                    contexts,
+                    property_name,
+                    self.current_crate,
+                    self.previous_crate,
+                ),
                 "Attribute" => properties::resolve_attribute_property(contexts, property_name),
                 "AttributeMetaItem" => {
                     properties::resolve_attribute_meta_item_property(contexts, property_name)
                 }
+                "LintAttribute" => {
+                    properties::resolve_lint_attribute_property(contexts, property_name)
+                }
+                "Stability" => properties::resolve_stability_property(contexts, property_name),
                 "Trait" => properties::resolve_trait_property(contexts, property_name),
+                "Macro" | "ProcMacro" | "DeclarativeMacro" if property_name.as_ref() == "kind" => {
+                    properties::resolve_macro_property(contexts, property_name)
+                }
+                "DeclarativeMacro" => {
+                    properties::resolve_declarative_macro_property(contexts, property_name)
+                }
+                "ProcMacro" => properties::resolve_proc_macro_property(contexts, property_name),
+                "Import" => properties::resolve_import_property(
+                    contexts,
+                    property_name,
+                    self.current_crate,
+                    self.previous_crate,
+                ),
+                "Primitive" => properties::resolve_primitive_property(contexts, property_name),
+                "Module" => properties::resolve_module_property(contexts, property_name),
+                "OtherItem" => properties::resolve_other_item_property(contexts, property_name),
+                "AssociatedType" => {
+                    properties::resolve_associated_type_property(contexts, property_name)
+                }
+                "TraitAlias" => properties::resolve_trait_alias_property(contexts, property_name),
+                "ConstantLike" | "Constant" | "AssociatedConstant"
+                    if matches!(
+                        property_name.as_ref(),
+                        "type_name"
+                            | "is_associated"
+                            | "int_value"
+                            | "float_value"
+                            | "bool_value"
+                            | "str_value"
+                    ) =>
+                {
+                    properties::resolve_constant_like_property(
+                        contexts,
+                        property_name,
+                        self.current_crate,
+                        self.previous_crate,
+                    )
+                }
+                "Constant" => properties::resolve_constant_property(contexts, property_name),
+                "Static" => properties::resolve_static_property(contexts, property_name),
+                "AssociatedConstant" => {
+                    properties::resolve_associated_constant_property(contexts, property_name)
+                }
+                "TraitBound" => properties::resolve_trait_bound_property(contexts, property_name),
+                "FunctionLikeItem" => {
+                    properties::resolve_function_like_item_property(contexts, property_name)
+                }
+                "MethodNameConflict" => {
+                    properties::resolve_method_name_conflict_property(contexts, property_name)
+                }
+                "GenericTypeParameter" => {
+                    properties::resolve_generic_type_parameter_property(contexts, property_name)
+                }
+                "File" => properties::resolve_file_property(contexts, property_name),
+                "Feature" => properties::resolve_feature_property(contexts, property_name),
+                "OptionalDependency" => {
+                    properties::resolve_optional_dependency_property(contexts, property_name)
+                }
                 "ImplementedTrait" => {
                     properties::resolve_implemented_trait_property(contexts, property_name)
                 }
-                "RawType" | "ResolvedPathType" | "PrimitiveType"
-                    if matches!(property_name.as_ref(), "name") =>
+                "RawType" | "ResolvedPathType" | "PrimitiveType" | "DynTraitType" | "OtherType"
+                    if matches!(
+                        property_name.as_ref(),
+                        "name" | "display_name" | "qualified_display_name"
+                    ) =>
                 {
                     // fields from "RawType"
-                    properties::resolve_raw_type_property(contexts, property_name)
+                    properties::resolve_raw_type_property(
+                        contexts,
+                        property_name,
+                        self.current_crate,
+                        self.previous_crate,
+                        self.path_normalization,
+                    )
+                }
+                "DynTraitType" => {
+                    properties::resolve_dyn_trait_type_property(contexts, property_name)
                 }
                 _ => unreachable!("resolve_property {type_name} {property_name}"),
             }
@@ -143,69 +568,19 @@ impl<'a> Adapter<'a> for RustdocAdapter<'a> {
         parameters: &EdgeParameters,
         resolve_info: &ResolveEdgeInfo,
     ) -> ContextOutcomeIterator<'a, Self::Vertex, VertexIterator<'a, Self::Vertex>> {
-        match type_name.as_ref() {
-            "CrateDiff" => edges::resolve_crate_diff_edge(contexts, edge_name),
-            "Crate" => edges::resolve_crate_edge(self, contexts, edge_name, resolve_info),
-            "Importable" | "ImplOwner" | "Struct" | "Enum" | "Trait" | "Function"
-                if matches!(edge_name.as_ref(), "importable_path" | "canonical_path") =>
-            {
-                edges::resolve_importable_edge(
-                    contexts,
-                    edge_name,
-                    self.current_crate,
-                    self.previous_crate,
-                )
-            }
-            "Item" | "ImplOwner" | "Struct" | "StructField" | "Enum" | "Variant"
-            | "PlainVariant" | "TupleVariant" | "StructVariant" | "Trait" | "Function"
-            | "Method" | "Impl"
-                if matches!(edge_name.as_ref(), "span" | "attribute") =>
-            {
-                edges::resolve_item_edge(contexts, edge_name)
-            }
-            "ImplOwner" | "Struct" | "Enum"
-                if matches!(edge_name.as_ref(), "impl" | "inherent_impl") =>
-            {
-                edges::resolve_impl_owner_edge(self, contexts, edge_name, resolve_info)
-            }
-            "Function" | "Method" | "FunctionLike" if matches!(edge_name.as_ref(), "parameter") => {
-                edges::resolve_function_like_edge(contexts, edge_name)
-            }
-            "Struct" => edges::resolve_struct_edge(
-                contexts,
-                edge_name,
-                self.current_crate,
-                self.previous_crate,
-            ),
-            "Variant" | "PlainVariant" | "TupleVariant" | "StructVariant" => {
-                edges::resolve_variant_edge(
-                    contexts,
-                    edge_name,
-                    self.current_crate,
-                    self.previous_crate,
-                )
-            }
-            "Enum" => edges::resolve_enum_edge(
-                contexts,
-                edge_name,
-                self.current_crate,
-                self.previous_crate,
-            ),
-            "StructField" => edges::resolve_struct_field_edge(contexts, edge_name),
-            "Impl" => edges::resolve_impl_edge(self, contexts, edge_name, resolve_info),
-            "Trait" => edges::resolve_trait_edge(
-                contexts,
-                edge_name,
-                self.current_crate,
-                self.previous_crate,
-            ),
-            "ImplementedTrait" => edges::resolve_implemented_trait_edge(contexts, edge_name),
-            "Attribute" => edges::resolve_attribute_edge(contexts, edge_name),
-            "AttributeMetaItem" => edges::resolve_attribute_meta_item_edge(contexts, edge_name),
-            _ => unreachable!("resolve_neighbors {type_name} {edge_name} {parameters:?}"),
+        let outcomes = self.resolve_neighbors_uncounted(
+            contexts,
+            type_name,
+            edge_name,
+            parameters,
+            resolve_info,
+        );
+
+        match &self.counters {
+            Some(counters) => counters::count_edge(counters, type_name, edge_name, outcomes),
+            None => outcomes,
         }
     }
-
     fn resolve_coercion(
         &self,
         contexts: ContextIterator<'a, Self::Vertex>,
@@ -215,30 +590,64 @@ impl<'a> Adapter<'a> for RustdocAdapter<'a> {
     ) -> ContextOutcomeIterator<'a, Self::Vertex, bool> {
         let coerce_to_type = coerce_to_type.clone();
         match type_name.as_ref() {
-            "Item" | "Variant" | "FunctionLike" | "Importable" | "ImplOwner" | "RawType"
-            | "ResolvedPathType" => {
-                resolve_coercion_with(contexts, move |vertex| {
-                    let actual_type_name = vertex.typename();
-
-                    match coerce_to_type.as_ref() {
-                        "Variant" => matches!(
-                            actual_type_name,
-                            "PlainVariant" | "TupleVariant" | "StructVariant"
-                        ),
-                        "ImplOwner" => matches!(actual_type_name, "Struct" | "Enum"),
-                        "ResolvedPathType" => {
-                            matches!(actual_type_name, "ResolvedPathType" | "ImplementedTrait")
-                        }
-                        _ => {
-                            // The remaining types are final (don't have any subtypes)
-                            // so we can just compare the actual type name to
-                            // the type we are attempting to coerce to.
-                            actual_type_name == coerce_to_type.as_ref()
-                        }
-                    }
-                })
-            }
+            "Item" | "Variant" | "FunctionLike" | "ConstantLike" | "Importable" | "ImplOwner"
+            | "Macro" | "RawType" | "ResolvedPathType" | "GenericItem" | "Documented"
+            | "HasAttributes" => resolve_coercion_with(contexts, move |vertex| {
+                is_subtype(vertex.typename(), coerce_to_type.as_ref())
+            }),
             _ => unreachable!("resolve_coercion {type_name} {coerce_to_type}"),
         }
     }
 }
+
+/// Whether a vertex whose runtime type is `actual_type_name` can be coerced to `coerce_to_type`.
+fn is_subtype(actual_type_name: &str, coerce_to_type: &str) -> bool {
+    match coerce_to_type {
+        "Variant" => matches!(
+            actual_type_name,
+            "PlainVariant" | "TupleVariant" | "StructVariant"
+        ),
+        "ImplOwner" => matches!(actual_type_name, "Struct" | "Enum" | "Union"),
+        "Importable" => matches!(
+            actual_type_name,
+            "Struct"
+                | "Enum"
+                | "Union"
+                | "Trait"
+                | "TraitAlias"
+                | "Function"
+                | "ProcMacro"
+                | "DeclarativeMacro"
+                | "Typedef"
+                | "Constant"
+                | "Static"
+                | "Module"
+                | "OtherItem"
+        ),
+        "ConstantLike" => matches!(actual_type_name, "Constant" | "AssociatedConstant"),
+        "Macro" => matches!(actual_type_name, "ProcMacro" | "DeclarativeMacro"),
+        "GenericItem" => matches!(
+            actual_type_name,
+            "Struct"
+                | "Enum"
+                | "Union"
+                | "Trait"
+                | "TraitAlias"
+                | "Function"
+                | "Method"
+                | "Impl"
+                | "Typedef"
+        ),
+        // All items are documented and can have attributes.
+        "Item" | "Documented" | "HasAttributes" => ITEM_TYPENAMES.contains(&actual_type_name),
+        "ResolvedPathType" => {
+            matches!(actual_type_name, "ResolvedPathType" | "ImplementedTrait")
+        }
+        _ => {
+            // The remaining types are final (don't have any subtypes)
+            // so we can just compare the actual type name to
+            // the type we are attempting to coerce to.
+            actual_type_name == coerce_to_type
+        }
+    }
+}
@@ -104,15 +104,10 @@ fn resolve_impl_based_on_method_name_candidate<'a>(
 ) -> VertexIterator<'a, Vertex<'a>> {
     let origin = vertex.origin;
     let impl_index = match origin {
-        Origin::CurrentCrate => current_crate
-            .impl_index
-            .as_ref()
-            .expect("no impl index present"),
+        Origin::CurrentCrate => current_crate.impl_index(),
         Origin::PreviousCrate => previous_crate
             .expect("no previous crate provided")
-            .impl_index
-            .as_ref()
-            .expect("no impl index provided"),
+            .impl_index(),
     };
 
     let item_id = &vertex.as_item().expect("not an item").id;
@@ -190,13 +185,15 @@ fn resolve_owner_impl_slow_path<'a>(
     };
 
     // Get the IDs of all the impl blocks.
-    // Relies on the fact that only structs and enums can have impls,
-    // so we know that the vertex must represent either a struct or an enum.
+    // Relies on the fact that only structs, enums, unions, and primitives can have impls,
+    // so we know that the vertex must represent one of them.
     let impl_ids = vertex
         .as_struct()
         .map(|s| &s.impls)
         .or_else(|| vertex.as_enum().map(|e| &e.impls))
-        .expect("vertex was neither a struct nor an enum");
+        .or_else(|| vertex.as_union().map(|u| &u.impls))
+        .or_else(|| vertex.as_primitive().map(|p| &p.impls))
+        .expect("vertex was neither a struct, an enum, a union, nor a primitive");
 
     Box::new(impl_ids.iter().filter_map(move |item_id| {
         let next_item = item_index.get(item_id);
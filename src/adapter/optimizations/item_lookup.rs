@@ -1,21 +1,41 @@
+use std::rc::Rc;
+
 use rustdoc_types::Item;
 use trustfall::{
     provider::{
         resolve_neighbors_with, CandidateValue, ContextIterator, ContextOutcomeIterator,
-        ResolveEdgeInfo, VertexInfo, VertexIterator,
+        EdgeParameters, ResolveEdgeInfo, Typename, VertexInfo, VertexIterator,
     },
     FieldValue,
 };
 
-use super::super::{origin::Origin, vertex::Vertex, RustdocAdapter};
+use super::super::{is_subtype, origin::Origin, vertex::Vertex, RustdocAdapter, ITEM_TYPENAMES};
 
 use crate::IndexedCrate;
 
 pub(crate) fn resolve_crate_items<'a>(
     adapter: &RustdocAdapter<'a>,
     contexts: ContextIterator<'a, Vertex<'a>>,
+    parameters: &EdgeParameters,
     resolve_info: &ResolveEdgeInfo,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, VertexIterator<'a, Vertex<'a>>> {
+    // Which item kinds to include, if the `kind` parameter restricts them.
+    // Kinds that aren't the type of any item can't match anything, so they are skipped.
+    let kinds: Option<Rc<[&'static str]>> = parameters
+        .get("kind")
+        .and_then(|value| value.as_slice())
+        .map(|values| {
+            ITEM_TYPENAMES
+                .iter()
+                .copied()
+                .filter(|known| {
+                    values.iter().any(|value| {
+                        value.as_str().expect("kind parameter was not a string") == *known
+                    })
+                })
+                .collect()
+        });
+
     // Is the `importable_path` edge being resolved in a subsequent step?
     if let Some(neighbor_info) = resolve_info
         .destination()
@@ -30,28 +50,146 @@ pub(crate) fn resolve_crate_items<'a>(
         // statically vs dynamically, so we check the dynamic case first since
         // it might be more specific.
         if let Some(dynamic_value) = neighbor_info.dynamically_required_property("path") {
-            return dynamic_value.resolve_with(adapter, contexts, |vertex, candidate| {
+            return dynamic_value.resolve_with(adapter, contexts, move |vertex, candidate| {
                 let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
                 let origin = vertex.origin;
-                resolve_items_by_importable_path(crate_vertex, origin, candidate)
+                filter_by_kinds(
+                    resolve_items_by_importable_path(crate_vertex, origin, candidate),
+                    kinds.clone(),
+                )
             });
         } else if let Some(path_value) = neighbor_info.statically_required_property("path") {
             let path_value = path_value.cloned();
             return resolve_neighbors_with(contexts, move |vertex| {
                 let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
                 let origin = vertex.origin;
-                resolve_items_by_importable_path(crate_vertex, origin, path_value.clone())
+                filter_by_kinds(
+                    resolve_items_by_importable_path(crate_vertex, origin, path_value.clone()),
+                    kinds.clone(),
+                )
+            });
+        }
+    }
+
+    // Is the item coerced to a specific item type, like `... on Struct`?
+    // If its name is also known, we can use an index to look up matching items directly.
+    let destination = resolve_info.destination();
+    if let Some(kind) = destination.coerced_to_type().and_then(|coerced_to| {
+        ITEM_TYPENAMES
+            .iter()
+            .copied()
+            .find(|kind| *kind == coerced_to.as_ref())
+    }) {
+        if kinds
+            .as_ref()
+            .map(|kinds| !kinds.contains(&kind))
+            .unwrap_or_default()
+        {
+            // The item is coerced to a kind that the `kind` parameter excludes.
+            return resolve_neighbors_with(contexts, |_| Box::new(std::iter::empty()));
+        }
+
+        if let Some(dynamic_value) = destination.dynamically_required_property("name") {
+            return dynamic_value.resolve_with(adapter, contexts, move |vertex, candidate| {
+                let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+                let origin = vertex.origin;
+                resolve_items_by_name_and_kind(crate_vertex, origin, candidate, kind)
+            });
+        } else if let Some(name_value) = destination.statically_required_property("name") {
+            let name_value = name_value.cloned();
+            return resolve_neighbors_with(contexts, move |vertex| {
+                let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
+                let origin = vertex.origin;
+                resolve_items_by_name_and_kind(crate_vertex, origin, name_value.clone(), kind)
             });
         }
     }
 
-    resolve_neighbors_with(contexts, |vertex| {
+    // If the item is coerced to a type, like `... on Struct` or `... on Importable`,
+    // only the kinds of items of that type can match. Taking them from the per-kind index
+    // saves checking the type of every other item in the crate during the coercion.
+    let kinds = match destination.coerced_to_type() {
+        Some(coerced_to) => Some(
+            ITEM_TYPENAMES
+                .iter()
+                .copied()
+                .filter(|kind| is_subtype(kind, coerced_to.as_ref()))
+                .filter(|kind| {
+                    kinds
+                        .as_ref()
+                        .map(|kinds| kinds.contains(kind))
+                        .unwrap_or(true)
+                })
+                .collect(),
+        ),
+        None => kinds,
+    };
+
+    resolve_neighbors_with(contexts, move |vertex| {
         let crate_vertex = vertex.as_indexed_crate().expect("vertex was not a Crate");
         let origin = vertex.origin;
-        resolve_items_slow_path(crate_vertex, origin)
+        match kinds.clone() {
+            Some(kinds) => resolve_item_vertices(
+                origin,
+                ITEM_TYPENAMES
+                    .iter()
+                    .filter(move |kind| kinds.contains(kind))
+                    .flat_map(move |kind| crate_vertex.items_of_kind(kind).iter().copied()),
+            ),
+            None => resolve_items_slow_path(crate_vertex, origin),
+        }
     })
 }
 
+fn filter_by_kinds<'a>(
+    vertices: VertexIterator<'a, Vertex<'a>>,
+    kinds: Option<Rc<[&'static str]>>,
+) -> VertexIterator<'a, Vertex<'a>> {
+    match kinds {
+        Some(kinds) => Box::new(vertices.filter(move |vertex| kinds.contains(&vertex.typename()))),
+        None => vertices,
+    }
+}
+
+fn resolve_items_by_name_and_kind<'a>(
+    crate_vertex: &'a IndexedCrate,
+    origin: Origin,
+    name: CandidateValue<FieldValue>,
+    kind: &'static str,
+) -> VertexIterator<'a, Vertex<'a>> {
+    match name {
+        CandidateValue::Impossible => Box::new(std::iter::empty()),
+        CandidateValue::Single(value) => {
+            resolve_items_by_name_and_kind_field_value(crate_vertex, origin, &value, kind)
+        }
+        CandidateValue::Multiple(values) => Box::new(values.into_iter().flat_map(move |value| {
+            resolve_items_by_name_and_kind_field_value(crate_vertex, origin, &value, kind)
+        })),
+        _ => {
+            // fall through to all items of that kind
+            resolve_item_vertices(origin, crate_vertex.items_of_kind(kind).iter().copied())
+        }
+    }
+}
+
+fn resolve_items_by_name_and_kind_field_value<'a>(
+    crate_vertex: &'a IndexedCrate,
+    origin: Origin,
+    value: &FieldValue,
+    kind: &'static str,
+) -> VertexIterator<'a, Vertex<'a>> {
+    match value.as_str() {
+        Some(name) => resolve_item_vertices(
+            origin,
+            crate_vertex
+                .items_with_name_and_kind(name, kind)
+                .into_iter(),
+        ),
+        // Items without a name, like impls, can't be looked up by name.
+        None => Box::new(std::iter::empty()),
+    }
+}
+
 fn resolve_items_by_importable_path<'a>(
     crate_vertex: &'a IndexedCrate,
     origin: Origin,
@@ -85,7 +223,7 @@ fn resolve_items_by_importable_path_field_value<'a>(
         .collect();
     if let Some(items) = crate_vertex
         .imports_index
-        .as_ref()
+        .as_deref()
         .expect("crate's imports_index was never constructed")
         .get(path_components.as_slice())
     {
@@ -103,25 +241,9 @@ fn resolve_items_slow_path<'a>(
     resolve_item_vertices(origin, crate_vertex.inner.index.values())
 }
 
-fn resolve_item_vertices<'a>(
+pub(crate) fn resolve_item_vertices<'a>(
     origin: Origin,
     items: impl Iterator<Item = &'a Item> + 'a,
 ) -> VertexIterator<'a, Vertex<'a>> {
-    Box::new(
-        items
-            .filter(|item| {
-                // Filter out item types that are not currently supported.
-                matches!(
-                    item.inner,
-                    rustdoc_types::ItemEnum::Struct(..)
-                        | rustdoc_types::ItemEnum::StructField(..)
-                        | rustdoc_types::ItemEnum::Enum(..)
-                        | rustdoc_types::ItemEnum::Variant(..)
-                        | rustdoc_types::ItemEnum::Function(..)
-                        | rustdoc_types::ItemEnum::Impl(..)
-                        | rustdoc_types::ItemEnum::Trait(..)
-                )
-            })
-            .map(move |value| origin.make_item_vertex(value)),
-    )
+    Box::new(items.map(move |value| origin.make_item_vertex(value)))
 }
@@ -37,14 +37,14 @@ pub(crate) fn resolve_impl_methods<'a>(
         })
     } else if let Some(candidate) = neighbor_info.statically_required_property("name") {
         let candidate = candidate.cloned();
-        return resolve_neighbors_with(contexts, move |vertex| {
+        resolve_neighbors_with(contexts, move |vertex| {
             resolve_method_from_candidate_value(
                 current_crate,
                 previous_crate,
                 vertex,
                 candidate.clone(),
             )
-        });
+        })
     } else {
         resolve_neighbors_with(contexts, move |vertex| {
             let origin = vertex.origin;
@@ -97,22 +97,10 @@ fn resolve_method_from_candidate_value<'a>(
 ) -> VertexIterator<'a, Vertex<'a>> {
     let origin = vertex.origin;
     let (item_index, impl_index) = match origin {
-        Origin::CurrentCrate => (
-            &current_crate.inner.index,
-            current_crate
-                .impl_index
-                .as_ref()
-                .expect("no impl index present"),
-        ),
+        Origin::CurrentCrate => (&current_crate.inner.index, current_crate.impl_index()),
         Origin::PreviousCrate => {
             let previous_crate = previous_crate.expect("no previous crate provided");
-            (
-                &previous_crate.inner.index,
-                previous_crate
-                    .impl_index
-                    .as_ref()
-                    .expect("no impl index provided"),
-            )
+            (&previous_crate.inner.index, previous_crate.impl_index())
         }
     };
 
@@ -1,10 +1,14 @@
 use std::rc::Rc;
 
-use rustdoc_types::{Item, Span};
+use rustdoc_types::{Item, Span, Type};
 
-use crate::attributes::{Attribute, AttributeMetaItem};
+use crate::{
+    attributes::{Attribute, AttributeMetaItem, LintAttribute, StabilityAttribute},
+    indexed_crate::ImportableName,
+    PackageMetadata,
+};
 
-use super::vertex::{Vertex, VertexKind};
+use super::vertex::{FunctionLikeItem, Vertex, VertexKind};
 
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
@@ -37,7 +41,7 @@ impl Origin {
 
     pub(super) fn make_importable_path_vertex<'a>(
         &self,
-        importable_path: Vec<&'a str>,
+        importable_path: ImportableName<'a>,
     ) -> Vertex<'a> {
         Vertex {
             origin: *self,
@@ -59,6 +63,69 @@ impl Origin {
         }
     }
 
+    pub(super) fn make_lint_attribute_vertex<'a>(
+        &self,
+        lint_attribute: LintAttribute<'a>,
+    ) -> Vertex<'a> {
+        Vertex {
+            origin: *self,
+            kind: VertexKind::LintAttribute(lint_attribute),
+        }
+    }
+
+    pub(super) fn make_stability_vertex<'a>(
+        &self,
+        stability: StabilityAttribute<'a>,
+    ) -> Vertex<'a> {
+        Vertex {
+            origin: *self,
+            kind: VertexKind::Stability(stability),
+        }
+    }
+
+    pub(super) fn make_trait_bound_vertex<'a>(
+        &self,
+        path: &'a rustdoc_types::Path,
+        modifier: &'a rustdoc_types::TraitBoundModifier,
+    ) -> Vertex<'a> {
+        Vertex {
+            origin: *self,
+            kind: VertexKind::TraitBound(path, modifier),
+        }
+    }
+
+    pub(super) fn make_generic_type_parameter_vertex<'a>(
+        &self,
+        generics: &'a rustdoc_types::Generics,
+        param: &'a rustdoc_types::GenericParamDef,
+    ) -> Vertex<'a> {
+        Vertex {
+            origin: *self,
+            kind: VertexKind::GenericTypeParameter(generics, param),
+        }
+    }
+
+    pub(super) fn make_function_like_item_vertex<'a>(
+        &self,
+        function_like: FunctionLikeItem<'a>,
+    ) -> Vertex<'a> {
+        Vertex {
+            origin: *self,
+            kind: VertexKind::FunctionLikeItem(function_like),
+        }
+    }
+
+    pub(super) fn make_method_name_conflict_vertex<'a>(
+        &self,
+        name: &'a str,
+        providers: Vec<(&'a Item, &'a Item)>,
+    ) -> Vertex<'a> {
+        Vertex {
+            origin: *self,
+            kind: VertexKind::MethodNameConflict(name, providers.into()),
+        }
+    }
+
     pub(super) fn make_attribute_meta_item_vertex<'a>(
         &self,
         meta_item: Rc<AttributeMetaItem<'a>>,
@@ -80,10 +147,49 @@ impl Origin {
         }
     }
 
-    pub(super) fn make_function_parameter_vertex<'a>(&self, name: &'a str) -> Vertex<'a> {
+    pub(super) fn make_function_parameter_vertex<'a>(
+        &self,
+        name: &'a str,
+        type_: &'a Type,
+        self_type: Option<&'a Type>,
+    ) -> Vertex<'a> {
+        Vertex {
+            origin: *self,
+            kind: VertexKind::FunctionParameter(name, type_, self_type),
+        }
+    }
+
+    pub(super) fn make_feature_vertex<'a>(
+        &self,
+        metadata: &'a PackageMetadata,
+        name: &'a str,
+        enables: &'a [String],
+    ) -> Vertex<'a> {
+        Vertex {
+            origin: *self,
+            kind: VertexKind::Feature(metadata, name, enables),
+        }
+    }
+
+    pub(super) fn make_optional_dependency_vertex<'a>(
+        &self,
+        metadata: &'a PackageMetadata,
+        name: &'a str,
+    ) -> Vertex<'a> {
+        Vertex {
+            origin: *self,
+            kind: VertexKind::OptionalDependency(metadata, name),
+        }
+    }
+
+    pub(super) fn make_file_vertex<'a>(
+        &self,
+        path: &'a std::path::Path,
+        items: &'a [&'a Item],
+    ) -> Vertex<'a> {
         Vertex {
             origin: *self,
-            kind: VertexKind::FunctionParameter(name),
+            kind: VertexKind::File(path, items),
         }
     }
 }
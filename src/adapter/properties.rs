@@ -1,3 +1,7 @@
+use rustdoc_types::{
+    Crate, GenericBound, GenericParamDef, GenericParamDefKind, Generics, Item, ItemEnum,
+    TraitBoundModifier, Type, WherePredicate,
+};
 use trustfall::{
     provider::{
         accessor_property, field_property, resolve_property_with, ContextIterator,
@@ -6,7 +10,18 @@ use trustfall::{
     FieldValue,
 };
 
-use super::vertex::Vertex;
+use crate::{
+    attributes::{parse_attributes, OnUnimplementedAttribute},
+    const_value::ConstValue,
+    indexed_crate::ItemId,
+    type_display::{abi_parts, TypeRenderer},
+    IndexedCrate, PathNormalization,
+};
+
+use super::{
+    origin::Origin,
+    vertex::{is_keyword_page, Vertex},
+};
 
 pub(super) fn resolve_crate_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
@@ -15,7 +30,7 @@ pub(super) fn resolve_crate_property<'a>(
     match property_name {
         "root" => resolve_property_with(
             contexts,
-            field_property!(as_crate, root, { root.0.clone().into() }),
+            field_property!(as_crate, root, { root.to_id_string().into() }),
         ),
         "crate_version" => {
             resolve_property_with(contexts, field_property!(as_crate, crate_version))
@@ -26,18 +41,72 @@ pub(super) fn resolve_crate_property<'a>(
         "format_version" => {
             resolve_property_with(contexts, field_property!(as_crate, format_version))
         }
+        "html_root_url"
+        | "html_logo_url"
+        | "html_favicon_url"
+        | "html_playground_url"
+        | "issue_tracker_base_url" => {
+            let property_name = property_name.to_string();
+            resolve_property_with(contexts, move |vertex| {
+                let crate_ = vertex.as_crate().expect("not a crate");
+                crate_doc_attribute_value(crate_, &property_name).into()
+            })
+        }
+        "package_name"
+        | "package_version"
+        | "package_repository"
+        | "package_license"
+        | "package_rust_version"
+        | "package_description" => {
+            let property_name = property_name.to_string();
+            resolve_property_with(contexts, move |vertex| {
+                let crate_ = vertex.as_indexed_crate().expect("not a crate");
+                let metadata = &crate_.package_metadata;
+                match property_name.as_str() {
+                    "package_name" => metadata.name.clone(),
+                    "package_version" => metadata.version.clone(),
+                    "package_repository" => metadata.repository.clone(),
+                    "package_license" => metadata.license.clone(),
+                    "package_rust_version" => metadata.rust_version.clone(),
+                    "package_description" => metadata.description.clone(),
+                    _ => unreachable!("Crate property {property_name}"),
+                }
+                .into()
+            })
+        }
         _ => unreachable!("Crate property {property_name}"),
     }
 }
 
+/// Find the value assigned to the given key in the crate's `#![doc(...)]` attributes,
+/// like `"https://docs.rs/foo"` in `#![doc(html_root_url = "https://docs.rs/foo")]`.
+fn crate_doc_attribute_value(crate_: &Crate, key: &str) -> Option<String> {
+    let root_module = crate_.index.get(&crate_.root)?;
+    parse_attributes(&root_module.attrs).find_map(|attribute| {
+        if attribute.content.base != "doc" {
+            return None;
+        }
+        attribute
+            .content
+            .arguments
+            .iter()
+            .flatten()
+            .find(|argument| argument.base == key)
+            .and_then(|argument| argument.assigned_item)
+            .map(|value| value.trim_matches('"').to_string())
+    })
+}
+
 pub(super) fn resolve_item_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     property_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
     match property_name {
         "id" => resolve_property_with(
             contexts,
-            field_property!(as_item, id, { id.0.clone().into() }),
+            field_property!(as_item, id, { id.to_id_string().into() }),
         ),
         "crate_id" => resolve_property_with(contexts, field_property!(as_item, crate_id)),
         "name" => resolve_property_with(contexts, field_property!(as_item, name)),
@@ -54,6 +123,28 @@ pub(super) fn resolve_item_property<'a>(
                 }
             }
         }),
+        "doc_hidden" => resolve_property_with(contexts, |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            parse_attributes(&item.attrs)
+                .any(|attr| attr.is_doc_hidden())
+                .into()
+        }),
+        "effective_visibility" => resolve_property_with(contexts, move |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            let indexed_crate = match vertex.origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+            indexed_crate.effective_visibility(item).into()
+        }),
+        "is_effectively_deprecated" => resolve_property_with(contexts, move |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            let indexed_crate = match vertex.origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+            indexed_crate.is_effectively_deprecated(item).into()
+        }),
         _ => unreachable!("Item property {property_name}"),
     }
 }
@@ -85,6 +176,36 @@ pub(super) fn resolve_struct_property<'a>(
     }
 }
 
+pub(super) fn resolve_union_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "fields_stripped" => {
+            resolve_property_with(contexts, field_property!(as_union, fields_stripped))
+        }
+        _ => unreachable!("Union property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_struct_field_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "is_positional" => resolve_property_with(contexts, |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            // Rust identifiers can't start with a digit, so only positional fields
+            // have names made up of digits.
+            item.name
+                .as_deref()
+                .map(|name| name.chars().all(|c| c.is_ascii_digit()))
+                .into()
+        }),
+        _ => unreachable!("StructField property {property_name}"),
+    }
+}
+
 pub(super) fn resolve_span_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     property_name: &str,
@@ -112,22 +233,246 @@ pub(super) fn resolve_span_property<'a>(
             contexts,
             field_property!(as_span, end, { (end.1 as u64).into() }),
         ),
+        "editor_begin_column" => resolve_property_with(
+            contexts,
+            field_property!(as_span, begin, { (begin.1 as u64 + 1).into() }),
+        ),
+        "editor_end_column" => resolve_property_with(
+            contexts,
+            field_property!(as_span, end, { (end.1 as u64 + 1).into() }),
+        ),
+        _ => unreachable!("Span property {property_name}"),
+    }
+}
+
+/// The source files of the crate the span belongs to, if the caller provided their directory.
+#[cfg(feature = "source-snippets")]
+fn span_source_files<'a>(
+    vertex: &Vertex<'a>,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> Option<&'a crate::source_snippet::SourceFiles> {
+    let indexed_crate = match vertex.origin {
+        Origin::CurrentCrate => current_crate,
+        Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+    };
+    indexed_crate.source_files.as_deref()
+}
+
+/// Resolve the `Span.source` property, read from the crate's source files.
+#[cfg(feature = "source-snippets")]
+pub(super) fn resolve_span_source_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "source" => resolve_property_with(contexts, move |vertex| {
+            let span = vertex.as_span().expect("not a span");
+            span_source_files(vertex, current_crate, previous_crate)
+                .and_then(|source_files| source_files.span_source(span))
+                .into()
+        }),
+        _ => unreachable!("Span property {property_name}"),
+    }
+}
+
+/// Without the `source-snippets` feature there's no source directory to read from,
+/// so `Span.source` is always null.
+#[cfg(not(feature = "source-snippets"))]
+pub(super) fn resolve_span_source_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    _property_name: &str,
+    _current_crate: &'a IndexedCrate<'a>,
+    _previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    resolve_property_with(contexts, |_| FieldValue::Null)
+}
+
+/// Resolve the `Span.begin_byte_offset` and `Span.end_byte_offset` properties,
+/// computed from the crate's source files.
+#[cfg(feature = "source-snippets")]
+pub(super) fn resolve_span_byte_offset_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    let byte_offsets = move |vertex: &Vertex<'a>| {
+        let span = vertex.as_span().expect("not a span");
+        span_source_files(vertex, current_crate, previous_crate)
+            .and_then(|source_files| source_files.span_byte_offsets(span))
+    };
+    match property_name {
+        "begin_byte_offset" => resolve_property_with(contexts, move |vertex| {
+            byte_offsets(vertex).map(|(begin, _)| begin as u64).into()
+        }),
+        "end_byte_offset" => resolve_property_with(contexts, move |vertex| {
+            byte_offsets(vertex).map(|(_, end)| end as u64).into()
+        }),
         _ => unreachable!("Span property {property_name}"),
     }
 }
 
+/// Without the `source-snippets` feature there's no source directory to read from,
+/// so the byte offsets are always null.
+#[cfg(not(feature = "source-snippets"))]
+pub(super) fn resolve_span_byte_offset_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    _property_name: &str,
+    _current_crate: &'a IndexedCrate<'a>,
+    _previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    resolve_property_with(contexts, |_| FieldValue::Null)
+}
+
+pub(super) fn resolve_file_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "path" => resolve_property_with(contexts, |vertex| {
+            let (path, _) = vertex.as_file().expect("not a File");
+            path.to_str().expect("non-representable path").into()
+        }),
+        "item_count" => resolve_property_with(contexts, |vertex| {
+            let (_, items) = vertex.as_file().expect("not a File");
+            (items.len() as u64).into()
+        }),
+        _ => unreachable!("File property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_feature_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "name" => resolve_property_with(contexts, |vertex| {
+            let (_, name, _) = vertex.as_feature().expect("not a Feature");
+            name.into()
+        }),
+        "enables" => resolve_property_with(contexts, |vertex| {
+            let (_, _, enables) = vertex.as_feature().expect("not a Feature");
+            enables.to_vec().into()
+        }),
+        _ => unreachable!("Feature property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_optional_dependency_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "name" => resolve_property_with(contexts, |vertex| {
+            let (_, name) = vertex
+                .as_optional_dependency()
+                .expect("not an OptionalDependency");
+            name.into()
+        }),
+        _ => unreachable!("OptionalDependency property {property_name}"),
+    }
+}
+
 pub(super) fn resolve_enum_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     property_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
     match property_name {
         "variants_stripped" => {
             resolve_property_with(contexts, field_property!(as_enum, variants_stripped))
         }
+        "matchable_variant_names" => resolve_property_with(contexts, move |vertex| {
+            matchable_variants(vertex, current_crate, previous_crate)
+                .into_iter()
+                .filter_map(|variant| variant.name.clone())
+                .collect::<Vec<_>>()
+                .into()
+        }),
+        "exhaustively_matchable" => resolve_property_with(contexts, move |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            let enum_ = vertex.as_enum().expect("not an enum");
+            let non_exhaustive = parse_attributes(&item.attrs).any(|attr| attr.is_non_exhaustive());
+
+            (!non_exhaustive
+                && !enum_.variants_stripped
+                && matchable_variants(vertex, current_crate, previous_crate).len()
+                    == enum_.variants.len())
+            .into()
+        }),
         _ => unreachable!("Enum property {property_name}"),
     }
 }
 
+/// The enum's variants that downstream users can name in a `match`,
+/// i.e. the ones that aren't `#[doc(hidden)]`, in declaration order.
+fn matchable_variants<'a>(
+    vertex: &Vertex<'a>,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> Vec<&'a Item> {
+    let enum_ = vertex.as_enum().expect("not an enum");
+    let index = match vertex.origin {
+        Origin::CurrentCrate => &current_crate.inner.index,
+        Origin::PreviousCrate => {
+            &previous_crate
+                .expect("no previous crate provided")
+                .inner
+                .index
+        }
+    };
+    enum_
+        .variants
+        .iter()
+        .filter_map(|id| index.get(id))
+        .filter(|variant| !parse_attributes(&variant.attrs).any(|attr| attr.is_doc_hidden()))
+        .collect()
+}
+
+pub(super) fn resolve_variant_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "discriminant_expr" => resolve_property_with(contexts, |vertex| {
+            let variant = vertex.as_variant().expect("not a variant");
+            variant
+                .discriminant
+                .as_ref()
+                .map(|discriminant| discriminant.expr.clone())
+                .into()
+        }),
+        "discriminant_value" => resolve_property_with(contexts, |vertex| {
+            let variant = vertex.as_variant().expect("not a variant");
+            match &variant.discriminant {
+                // The value is a decimal integer computed by rustc, but it may be
+                // out of range of `i64` since discriminants can be up to 128 bits wide.
+                Some(discriminant) => {
+                    if let Ok(value) = discriminant.value.parse::<i64>() {
+                        value.into()
+                    } else if let Ok(value) = discriminant.value.parse::<u64>() {
+                        value.into()
+                    } else {
+                        FieldValue::Null
+                    }
+                }
+                None => FieldValue::Null,
+            }
+        }),
+        "non_exhaustive" => resolve_property_with(contexts, |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            parse_attributes(&item.attrs)
+                .any(|attr| attr.is_non_exhaustive())
+                .into()
+        }),
+        _ => unreachable!("Variant property {property_name}"),
+    }
+}
+
 pub(super) fn resolve_path_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     property_name: &str,
@@ -149,19 +494,81 @@ pub(super) fn resolve_importable_path_property<'a>(
             vertex
                 .as_importable_path()
                 .expect("not an importable path")
+                .components
                 .iter()
                 .map(ToString::to_string)
                 .collect::<Vec<_>>()
                 .into()
         }),
         "visibility_limit" => resolve_property_with(contexts, |_| "public".into()),
+        "reexport_doc_hidden" => resolve_property_with(contexts, |vertex| {
+            vertex
+                .as_importable_path()
+                .expect("not an importable path")
+                .hidden_reexport
+                .into()
+        }),
+        "target_doc_hidden" => resolve_property_with(contexts, |vertex| {
+            vertex
+                .as_importable_path()
+                .expect("not an importable path")
+                .hidden_target
+                .into()
+        }),
+        "namespaces" => resolve_property_with(contexts, |vertex| {
+            vertex
+                .as_importable_path()
+                .expect("not an importable path")
+                .namespaces
+                .iter()
+                .map(|namespace| namespace.as_str())
+                .collect::<Vec<_>>()
+                .into()
+        }),
         _ => unreachable!("ImportablePath property {property_name}"),
     }
 }
 
+pub(super) fn resolve_generic_item_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "generic_type_param_count" => resolve_property_with(contexts, |vertex| {
+            let generics = vertex.as_generics().expect("not a GenericItem");
+            let count = generics
+                .params
+                .iter()
+                .filter(|param| {
+                    matches!(
+                        param.kind,
+                        GenericParamDefKind::Type {
+                            synthetic: false,
+                            ..
+                        }
+                    )
+                })
+                .count();
+            (count as u64).into()
+        }),
+        "lifetime_param_count" => resolve_property_with(contexts, |vertex| {
+            let generics = vertex.as_generics().expect("not a GenericItem");
+            let count = generics
+                .params
+                .iter()
+                .filter(|param| matches!(param.kind, GenericParamDefKind::Lifetime { .. }))
+                .count();
+            (count as u64).into()
+        }),
+        _ => unreachable!("GenericItem property {property_name}"),
+    }
+}
+
 pub(super) fn resolve_function_like_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     property_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
     match property_name {
         "const" => resolve_property_with(
@@ -176,6 +583,38 @@ pub(super) fn resolve_function_like_property<'a>(
             contexts,
             field_property!(as_function, header, { header.unsafe_.into() }),
         ),
+        "c_variadic" => resolve_property_with(
+            contexts,
+            field_property!(as_function, decl, { decl.c_variadic.into() }),
+        ),
+        "abi" => resolve_property_with(
+            contexts,
+            field_property!(as_function, header, { abi_parts(&header.abi).0.into() }),
+        ),
+        "abi_unwind" => resolve_property_with(
+            contexts,
+            field_property!(as_function, header, { abi_parts(&header.abi).1.into() }),
+        ),
+        "param_count" => resolve_property_with(
+            contexts,
+            field_property!(as_function, decl, { (decl.inputs.len() as u64).into() }),
+        ),
+        "return_type_name" => resolve_property_with(contexts, move |vertex| {
+            let indexed_crate = match vertex.origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+            let item = vertex.as_item().expect("not an item");
+            let function = vertex.as_function().expect("not a function");
+            let renderer =
+                TypeRenderer::default().with_self_type(indexed_crate.impl_self_type(&item.id));
+            function
+                .decl
+                .output
+                .as_ref()
+                .map(|output| renderer.render(output))
+                .into()
+        }),
         _ => unreachable!("FunctionLike property {property_name}"),
     }
 }
@@ -186,9 +625,18 @@ pub(super) fn resolve_function_parameter_property<'a>(
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
     match property_name {
         "name" => resolve_property_with(contexts, |vertex| {
-            vertex
+            let (name, _, _) = vertex
+                .as_function_parameter()
+                .expect("not a function parameter");
+            name.into()
+        }),
+        "type_name" => resolve_property_with(contexts, |vertex| {
+            let (_, type_, self_type) = vertex
                 .as_function_parameter()
-                .expect("not a function parameter")
+                .expect("not a function parameter");
+            TypeRenderer::default()
+                .with_self_type(self_type)
+                .render(type_)
                 .into()
         }),
         _ => unreachable!("FunctionParameter property {property_name}"),
@@ -198,11 +646,21 @@ pub(super) fn resolve_function_parameter_property<'a>(
 pub(super) fn resolve_impl_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     property_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
     match property_name {
         "unsafe" => resolve_property_with(contexts, field_property!(as_impl, is_unsafe)),
         "negative" => resolve_property_with(contexts, field_property!(as_impl, negative)),
         "synthetic" => resolve_property_with(contexts, field_property!(as_impl, synthetic)),
+        "module_path" => resolve_property_with(contexts, move |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            let indexed_crate = match vertex.origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+            indexed_crate.module_path(&item.id).into()
+        }),
         _ => unreachable!("Impl property {property_name}"),
     }
 }
@@ -215,6 +673,10 @@ pub(super) fn resolve_attribute_property<'a>(
         "raw_attribute" => {
             resolve_property_with(contexts, accessor_property!(as_attribute, raw_attribute))
         }
+        "normalized_attribute" => resolve_property_with(
+            contexts,
+            accessor_property!(as_attribute, normalized_attribute),
+        ),
         "is_inner" => resolve_property_with(contexts, field_property!(as_attribute, is_inner)),
         _ => unreachable!("Attribute property {property_name}"),
     }
@@ -228,6 +690,10 @@ pub(super) fn resolve_attribute_meta_item_property<'a>(
         "raw_item" => {
             resolve_property_with(contexts, field_property!(as_attribute_meta_item, raw_item))
         }
+        "normalized_item" => resolve_property_with(
+            contexts,
+            accessor_property!(as_attribute_meta_item, normalized_item),
+        ),
         "base" => resolve_property_with(contexts, field_property!(as_attribute_meta_item, base)),
         "assigned_item" => resolve_property_with(
             contexts,
@@ -237,16 +703,78 @@ pub(super) fn resolve_attribute_meta_item_property<'a>(
     }
 }
 
+pub(super) fn resolve_lint_attribute_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "level" => resolve_property_with(contexts, field_property!(as_lint_attribute, level)),
+        "lint" => resolve_property_with(contexts, field_property!(as_lint_attribute, lint)),
+        "reason" => resolve_property_with(contexts, field_property!(as_lint_attribute, reason)),
+        _ => unreachable!("LintAttribute property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_stability_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "level" => resolve_property_with(contexts, field_property!(as_stability, level)),
+        "feature" => resolve_property_with(contexts, field_property!(as_stability, feature)),
+        "since" => resolve_property_with(contexts, field_property!(as_stability, since)),
+        "issue" => resolve_property_with(contexts, field_property!(as_stability, issue)),
+        "reason" => resolve_property_with(contexts, field_property!(as_stability, reason)),
+        _ => unreachable!("Stability property {property_name}"),
+    }
+}
+
 pub(super) fn resolve_raw_type_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     property_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+    path_normalization: PathNormalization,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
     match property_name {
+        "display_name" | "qualified_display_name" => {
+            let path_normalization = if property_name == "qualified_display_name" {
+                path_normalization.with_resolved_paths(true)
+            } else {
+                path_normalization
+            };
+            resolve_property_with(contexts, move |vertex| {
+                let type_vertex = vertex.as_raw_type().expect("not a RawType");
+                let indexed_crate = match vertex.origin {
+                    Origin::CurrentCrate => current_crate,
+                    Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+                };
+                TypeRenderer::default()
+                    .with_path_normalization(path_normalization, &indexed_crate.inner.paths)
+                    .render(type_vertex)
+                    .into()
+            })
+        }
         "name" => resolve_property_with(contexts, |vertex| {
             let type_vertex = vertex.as_raw_type().expect("not a RawType");
             match type_vertex {
                 rustdoc_types::Type::ResolvedPath(path) => path.name.clone().into(),
                 rustdoc_types::Type::Primitive(name) => name.clone().into(),
+                rustdoc_types::Type::DynTrait(dyn_trait) => {
+                    let mut name = String::from("dyn ");
+                    let components = dyn_trait
+                        .traits
+                        .iter()
+                        .map(|poly_trait| poly_trait.trait_.name.as_str())
+                        .chain(dyn_trait.lifetime.as_deref());
+                    for (index, component) in components.enumerate() {
+                        if index > 0 {
+                            name.push_str(" + ");
+                        }
+                        name.push_str(component);
+                    }
+                    name.into()
+                }
                 _ => unreachable!("unexpected RawType vertex content: {type_vertex:?}"),
             }
         }),
@@ -254,16 +782,507 @@ pub(super) fn resolve_raw_type_property<'a>(
     }
 }
 
+fn on_unimplemented_attributes<'a>(
+    vertex: &Vertex<'a>,
+) -> impl Iterator<Item = OnUnimplementedAttribute<'a>> {
+    let item = vertex.as_item().expect("not an item");
+    parse_attributes(&item.attrs).filter_map(|attr| attr.on_unimplemented())
+}
+
 pub(super) fn resolve_trait_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     property_name: &str,
 ) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
     match property_name {
         "unsafe" => resolve_property_with(contexts, field_property!(as_trait, is_unsafe)),
+        "notable_trait" => resolve_property_with(contexts, |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            parse_attributes(&item.attrs)
+                .any(|attr| attr.is_doc_notable_trait())
+                .into()
+        }),
+        "on_unimplemented_message" => resolve_property_with(contexts, |vertex| {
+            on_unimplemented_attributes(vertex)
+                .find_map(|attribute| attribute.message)
+                .into()
+        }),
+        "on_unimplemented_label" => resolve_property_with(contexts, |vertex| {
+            on_unimplemented_attributes(vertex)
+                .find_map(|attribute| attribute.label)
+                .into()
+        }),
+        "on_unimplemented_notes" => resolve_property_with(contexts, |vertex| {
+            on_unimplemented_attributes(vertex)
+                .flat_map(|attribute| attribute.notes)
+                .collect::<Vec<_>>()
+                .into()
+        }),
         _ => unreachable!("Trait property {property_name}"),
     }
 }
 
+pub(super) fn resolve_macro_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "kind" => resolve_property_with(contexts, |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            match &item.inner {
+                ItemEnum::Macro(..) => "declarative",
+                ItemEnum::ProcMacro(proc_macro) => match proc_macro.kind {
+                    rustdoc_types::MacroKind::Bang => "function-like",
+                    rustdoc_types::MacroKind::Attr => "attribute",
+                    rustdoc_types::MacroKind::Derive => "derive",
+                },
+                _ => unreachable!("not a macro: {item:?}"),
+            }
+            .into()
+        }),
+        _ => unreachable!("Macro property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_declarative_macro_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "exported" => resolve_property_with(contexts, |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            parse_attributes(&item.attrs)
+                .any(|attr| attr.is_macro_export())
+                .into()
+        }),
+        "local_inner_macros" => resolve_property_with(contexts, |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            parse_attributes(&item.attrs)
+                .any(|attr| attr.is_macro_export_local_inner_macros())
+                .into()
+        }),
+        _ => unreachable!("DeclarativeMacro property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_proc_macro_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "helper_attributes" => {
+            resolve_property_with(contexts, field_property!(as_proc_macro, helpers))
+        }
+        _ => unreachable!("ProcMacro property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_import_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "source" => resolve_property_with(contexts, field_property!(as_import, source)),
+        "imported_as" => resolve_property_with(contexts, field_property!(as_import, name)),
+        "glob" => resolve_property_with(contexts, field_property!(as_import, glob)),
+        "target_doc_hidden" => resolve_property_with(contexts, move |vertex| {
+            let indexed_crate = match vertex.origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+            let import = vertex.as_import().expect("not an Import");
+            match import
+                .id
+                .as_ref()
+                .and_then(|id| indexed_crate.inner.index.get(id))
+            {
+                Some(target) => parse_attributes(&target.attrs)
+                    .any(|attr| attr.is_doc_hidden())
+                    .into(),
+                None => FieldValue::Null,
+            }
+        }),
+        _ => unreachable!("Import property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_primitive_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "primitive_name" => resolve_property_with(contexts, field_property!(as_primitive, name)),
+        _ => unreachable!("Primitive property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_other_item_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "kind" => resolve_property_with(contexts, |vertex| {
+            let item = vertex.as_item().expect("not an item");
+            item_kind(item).into()
+        }),
+        _ => unreachable!("OtherItem property {property_name}"),
+    }
+}
+
+/// The kind of the given item, in snake case.
+fn item_kind(item: &Item) -> &'static str {
+    match &item.inner {
+        ItemEnum::Module(..) if is_keyword_page(item) => "keyword",
+        ItemEnum::Module(..) => "module",
+        ItemEnum::ExternCrate { .. } => "extern_crate",
+        ItemEnum::Import(..) => "import",
+        ItemEnum::Union(..) => "union",
+        ItemEnum::Struct(..) => "struct",
+        ItemEnum::StructField(..) => "struct_field",
+        ItemEnum::Enum(..) => "enum",
+        ItemEnum::Variant(..) => "variant",
+        ItemEnum::Function(..) => "function",
+        ItemEnum::Trait(..) => "trait",
+        ItemEnum::TraitAlias(..) => "trait_alias",
+        ItemEnum::Impl(..) => "impl",
+        ItemEnum::Typedef(..) => "typedef",
+        ItemEnum::OpaqueTy(..) => "opaque_ty",
+        ItemEnum::Constant(..) => "constant",
+        ItemEnum::Static(..) => "static",
+        ItemEnum::ForeignType => "foreign_type",
+        ItemEnum::Macro(..) => "macro",
+        ItemEnum::ProcMacro(..) => "proc_macro",
+        ItemEnum::Primitive(..) => "primitive",
+        ItemEnum::AssocConst { .. } => "assoc_const",
+        ItemEnum::AssocType { .. } => "assoc_type",
+    }
+}
+
+pub(super) fn resolve_associated_type_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "has_default" => resolve_property_with(contexts, |vertex| {
+            let (_, default) = vertex.as_associated_type().expect("not an AssociatedType");
+            default.is_some().into()
+        }),
+        "lifetime_bound" => resolve_property_with(contexts, |vertex| {
+            let (bounds, _) = vertex.as_associated_type().expect("not an AssociatedType");
+            bounds
+                .iter()
+                .filter_map(|bound| match bound {
+                    GenericBound::Outlives(lifetime) => Some(lifetime.clone()),
+                    GenericBound::TraitBound { .. } => None,
+                })
+                .collect::<Vec<_>>()
+                .into()
+        }),
+        _ => unreachable!("AssociatedType property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_trait_alias_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "lifetime_bound" => resolve_property_with(contexts, |vertex| {
+            let trait_alias = vertex.as_trait_alias().expect("not a TraitAlias");
+            trait_alias
+                .params
+                .iter()
+                .filter_map(|bound| match bound {
+                    GenericBound::Outlives(lifetime) => Some(lifetime.clone()),
+                    GenericBound::TraitBound { .. } => None,
+                })
+                .collect::<Vec<_>>()
+                .into()
+        }),
+        _ => unreachable!("TraitAlias property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_constant_like_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: Option<&'a IndexedCrate<'a>>,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    let evaluate = |vertex: &Vertex<'a>| {
+        let (_, expr) = vertex.as_constant_like().expect("not a ConstantLike");
+        expr.and_then(ConstValue::evaluate)
+    };
+    match property_name {
+        "type_name" => resolve_property_with(contexts, move |vertex| {
+            let indexed_crate = match vertex.origin {
+                Origin::CurrentCrate => current_crate,
+                Origin::PreviousCrate => previous_crate.expect("no previous crate provided"),
+            };
+            let item = vertex.as_item().expect("not an item");
+            let (type_, _) = vertex.as_constant_like().expect("not a ConstantLike");
+            TypeRenderer::default()
+                .with_self_type(indexed_crate.impl_self_type(&item.id))
+                .render(type_)
+                .into()
+        }),
+        "is_associated" => resolve_property_with(contexts, |vertex| {
+            vertex.as_associated_constant().is_some().into()
+        }),
+        "int_value" => resolve_property_with(contexts, move |vertex| match evaluate(vertex) {
+            // Values outside the range of both `i64` and `u64` can't be represented.
+            Some(ConstValue::Int(value)) => i64::try_from(value)
+                .map(FieldValue::from)
+                .or_else(|_| u64::try_from(value).map(FieldValue::from))
+                .unwrap_or(FieldValue::Null),
+            _ => FieldValue::Null,
+        }),
+        "float_value" => resolve_property_with(contexts, move |vertex| match evaluate(vertex) {
+            // Evaluated floats are never NaN, since float literals start with a digit.
+            Some(ConstValue::Float(value)) => FieldValue::Float64(value),
+            _ => FieldValue::Null,
+        }),
+        "bool_value" => resolve_property_with(contexts, move |vertex| match evaluate(vertex) {
+            Some(ConstValue::Bool(value)) => value.into(),
+            _ => FieldValue::Null,
+        }),
+        "str_value" => resolve_property_with(contexts, move |vertex| match evaluate(vertex) {
+            Some(ConstValue::Str(value)) => value.into(),
+            _ => FieldValue::Null,
+        }),
+        _ => unreachable!("ConstantLike property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_constant_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "expr" => resolve_property_with(
+            contexts,
+            field_property!(as_constant, expr, { expr.clone().into() }),
+        ),
+        "value" => resolve_property_with(
+            contexts,
+            field_property!(as_constant, value, { value.clone().into() }),
+        ),
+        "is_literal" => resolve_property_with(
+            contexts,
+            field_property!(as_constant, is_literal, { (*is_literal).into() }),
+        ),
+        _ => unreachable!("Constant property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_module_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "is_crate_root" => resolve_property_with(
+            contexts,
+            field_property!(as_module, is_crate, { (*is_crate).into() }),
+        ),
+        _ => unreachable!("Module property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_static_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "mutable" => resolve_property_with(
+            contexts,
+            field_property!(as_static, mutable, { (*mutable).into() }),
+        ),
+        "type_name" => resolve_property_with(
+            contexts,
+            field_property!(as_static, type_, {
+                TypeRenderer::default().render(type_).into()
+            }),
+        ),
+        "expr" => resolve_property_with(
+            contexts,
+            field_property!(as_static, expr, { expr.clone().into() }),
+        ),
+        _ => unreachable!("Static property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_associated_constant_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "default" => resolve_property_with(contexts, |vertex| {
+            vertex
+                .as_associated_constant()
+                .expect("not an AssociatedConstant")
+                .into()
+        }),
+        _ => unreachable!("AssociatedConstant property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_trait_bound_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "name" => resolve_property_with(contexts, |vertex| {
+            let (path, _) = vertex.as_trait_bound().expect("not a TraitBound");
+            path.name.clone().into()
+        }),
+        "is_maybe" => resolve_property_with(contexts, |vertex| {
+            let (_, modifier) = vertex.as_trait_bound().expect("not a TraitBound");
+            matches!(modifier, TraitBoundModifier::Maybe).into()
+        }),
+        _ => unreachable!("TraitBound property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_dyn_trait_type_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "lifetime" => resolve_property_with(contexts, |vertex| {
+            let dyn_trait = vertex.as_dyn_trait().expect("not a DynTraitType");
+            dyn_trait.lifetime.clone().into()
+        }),
+        _ => unreachable!("DynTraitType property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_generic_type_parameter_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "name" => resolve_property_with(contexts, |vertex| {
+            let (_, param) = vertex
+                .as_generic_type_parameter()
+                .expect("not a GenericTypeParameter");
+            param.name.clone().into()
+        }),
+        "lifetime_bound" => resolve_property_with(contexts, |vertex| {
+            let (generics, param) = vertex
+                .as_generic_type_parameter()
+                .expect("not a GenericTypeParameter");
+
+            let mut lifetimes: Vec<String> = vec![];
+            for bound in generic_type_parameter_bounds(generics, param) {
+                if let GenericBound::Outlives(lifetime) = bound {
+                    if !lifetimes.contains(lifetime) {
+                        lifetimes.push(lifetime.clone());
+                    }
+                }
+            }
+            lifetimes.into()
+        }),
+        "is_synthetic" => resolve_property_with(contexts, |vertex| {
+            let (_, param) = vertex
+                .as_generic_type_parameter()
+                .expect("not a GenericTypeParameter");
+            match &param.kind {
+                GenericParamDefKind::Type { synthetic, .. } => (*synthetic).into(),
+                _ => unreachable!("not a type parameter: {param:?}"),
+            }
+        }),
+        "maybe_bound" => resolve_property_with(contexts, |vertex| {
+            let (generics, param) = vertex
+                .as_generic_type_parameter()
+                .expect("not a GenericTypeParameter");
+
+            let mut traits: Vec<String> = vec![];
+            for bound in generic_type_parameter_bounds(generics, param) {
+                if let GenericBound::TraitBound {
+                    trait_,
+                    modifier: TraitBoundModifier::Maybe,
+                    ..
+                } = bound
+                {
+                    if !traits.contains(&trait_.name) {
+                        traits.push(trait_.name.clone());
+                    }
+                }
+            }
+            traits.into()
+        }),
+        _ => unreachable!("GenericTypeParameter property {property_name}"),
+    }
+}
+
+/// The bounds of a generic type parameter, both those declared inline
+/// like `<T: 'a>` and those in where clauses like `where T: 'a`.
+fn generic_type_parameter_bounds<'a>(
+    generics: &'a Generics,
+    param: &'a GenericParamDef,
+) -> impl Iterator<Item = &'a GenericBound> {
+    let inline_bounds = match &param.kind {
+        GenericParamDefKind::Type { bounds, .. } => bounds.as_slice(),
+        _ => &[],
+    };
+    let where_clause_bounds = generics
+        .where_predicates
+        .iter()
+        .filter_map(move |predicate| match predicate {
+            WherePredicate::BoundPredicate {
+                type_: Type::Generic(name),
+                bounds,
+                ..
+            } if name == &param.name => Some(bounds),
+            _ => None,
+        })
+        .flatten();
+
+    inline_bounds.iter().chain(where_clause_bounds)
+}
+
+pub(super) fn resolve_method_name_conflict_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "name" => resolve_property_with(contexts, |vertex| {
+            let (name, _) = vertex
+                .as_method_name_conflict()
+                .expect("not a MethodNameConflict");
+            name.into()
+        }),
+        "involves_inherent_impl" => resolve_property_with(contexts, |vertex| {
+            let (_, providers) = vertex
+                .as_method_name_conflict()
+                .expect("not a MethodNameConflict");
+            providers
+                .iter()
+                .any(|(impl_item, _)| {
+                    matches!(&impl_item.inner, ItemEnum::Impl(impl_) if impl_.trait_.is_none())
+                })
+                .into()
+        }),
+        _ => unreachable!("MethodNameConflict property {property_name}"),
+    }
+}
+
+pub(super) fn resolve_function_like_item_property<'a>(
+    contexts: ContextIterator<'a, Vertex<'a>>,
+    property_name: &str,
+) -> ContextOutcomeIterator<'a, Vertex<'a>, FieldValue> {
+    match property_name {
+        "kind" => resolve_property_with(contexts, |vertex| {
+            let function_like = vertex
+                .as_function_like_item()
+                .expect("not a FunctionLikeItem");
+            function_like.kind.into()
+        }),
+        _ => unreachable!("FunctionLikeItem property {property_name}"),
+    }
+}
+
 pub(super) fn resolve_implemented_trait_property<'a>(
     contexts: ContextIterator<'a, Vertex<'a>>,
     property_name: &str,
@@ -1,10 +1,18 @@
-use std::{rc::Rc, sync::Arc};
+use std::{collections::BTreeMap, rc::Rc, sync::Arc};
 
 use anyhow::Context;
 use maplit::btreemap;
-use trustfall::{FieldValue, Schema};
+use trustfall::{provider::EdgeParameters, FieldValue, Schema};
 
-use crate::{IndexedCrate, RustdocAdapter};
+use crate::{
+    AdapterExtension, Crate, ExecutionCounters, IndexedCrate, PackageMetadata, PathNormalization,
+    RustdocAdapter, Vertex,
+};
+
+#[test]
+fn schema_is_valid() {
+    RustdocAdapter::schema();
+}
 
 #[test]
 fn rustdoc_json_format_version() {
@@ -70,3 +78,4701 @@ fn impl_for_ref() {
         results
     );
 }
+
+#[test]
+fn item_name_search() {
+    let path = "./localdata/test_data/type_and_value_with_matching_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item_name_search(prefix: "Fo") {
+            name @output
+            __typename @output(name: "kind")
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["kind"].as_str().cmp(&b["kind"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => FieldValue::String("Foo".to_string()),
+                Arc::from("kind") => FieldValue::String("Function".to_string()),
+            },
+            btreemap! {
+                Arc::from("name") => FieldValue::String("Foo".to_string()),
+                Arc::from("kind") => FieldValue::String("Struct".to_string()),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn item_substring_search() {
+    let path = "./localdata/test_data/structs_are_not_modules/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item_substring_search(substring: "_f") {
+            name @output
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    // The results are deliberately not sorted here: the search returns items ordered by name.
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => FieldValue::String("associated_fn".to_string()),
+            },
+            btreemap! {
+                Arc::from("name") => FieldValue::String("top_level_function".to_string()),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn item_case_insensitive_search() {
+    let path = "./localdata/test_data/type_and_value_with_matching_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::builder(&crate_)
+        .with_case_insensitive_name_index(true)
+        .build();
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item_case_insensitive_search(name: "foo") {
+            name @output
+            __typename @output(name: "kind")
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["kind"].as_str().cmp(&b["kind"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => FieldValue::String("Foo".to_string()),
+                Arc::from("kind") => FieldValue::String("Function".to_string()),
+            },
+            btreemap! {
+                Arc::from("name") => FieldValue::String("Foo".to_string()),
+                Arc::from("kind") => FieldValue::String("Struct".to_string()),
+            },
+        ],
+        results
+    );
+}
+
+#[cfg(feature = "fuzzy")]
+#[test]
+fn item_fuzzy_search() {
+    let path = "./localdata/test_data/structs_are_not_modules/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item_fuzzy_search(name: "methd", max_distance: 2) {
+            name @output
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("name") => FieldValue::String("method".to_string()),
+        }],
+        results
+    );
+}
+
+#[cfg(feature = "fuzzy")]
+#[test]
+fn item_fuzzy_search_negative_distance() {
+    let path = "./localdata/test_data/structs_are_not_modules/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item_fuzzy_search(name: "method", max_distance: -1) {
+            name @output
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert!(results.is_empty());
+}
+
+#[cfg(not(feature = "fuzzy"))]
+#[test]
+fn item_fuzzy_search_without_feature() {
+    let path = "./localdata/test_data/structs_are_not_modules/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item_fuzzy_search(name: "method", max_distance: 2) {
+            name @output
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert!(results.is_empty());
+}
+
+#[cfg(feature = "source-snippets")]
+#[test]
+fn span_source_snippets() {
+    let path = "./localdata/test_data/source_snippets/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = crate::IndexedCrateBuilder::new(&crate_)
+        .with_source_root("./test_crates/source_snippets")
+        .build();
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            name @output
+                @filter(op: "one_of", value: ["$names"])
+
+            span {
+                source @output
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "names" => vec!["Unit", "answer"],
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => FieldValue::String("Unit".to_string()),
+                Arc::from("source") => FieldValue::String("pub struct Unit;".to_string()),
+            },
+            btreemap! {
+                Arc::from("name") => FieldValue::String("answer".to_string()),
+                Arc::from("source") => FieldValue::String(
+                    "pub fn answer() -> i32 {\n    42\n}".to_string()
+                ),
+            },
+        ],
+        results
+    );
+}
+
+#[cfg(not(feature = "source-snippets"))]
+#[test]
+fn span_source_without_feature() {
+    let path = "./localdata/test_data/source_snippets/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            name @output
+                @filter(op: "=", value: ["$name"])
+
+            span {
+                source @output
+                begin_byte_offset @output
+                end_byte_offset @output
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "name" => "answer",
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("name") => FieldValue::String("answer".to_string()),
+            Arc::from("source") => FieldValue::Null,
+        }],
+        results
+    );
+}
+
+#[cfg(feature = "source-snippets")]
+#[test]
+fn span_coordinates() {
+    let path = "./localdata/test_data/source_snippets/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = crate::IndexedCrateBuilder::new(&crate_)
+        .with_source_root("./test_crates/source_snippets")
+        .build();
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            name @output
+                @filter(op: "one_of", value: ["$names"])
+
+            span {
+                begin_line @output
+                begin_column @output
+                end_column @output
+                editor_begin_column @output
+                editor_end_column @output
+                begin_byte_offset @output
+                end_byte_offset @output
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "names" => vec!["Unit", "answer"],
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Unit".into(),
+                Arc::from("begin_line") => 5u64.into(),
+                Arc::from("begin_column") => 0u64.into(),
+                Arc::from("end_column") => 16u64.into(),
+                Arc::from("editor_begin_column") => 1u64.into(),
+                Arc::from("editor_end_column") => 17u64.into(),
+                Arc::from("begin_byte_offset") => 146u64.into(),
+                Arc::from("end_byte_offset") => 162u64.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "answer".into(),
+                Arc::from("begin_line") => 7u64.into(),
+                Arc::from("begin_column") => 0u64.into(),
+                Arc::from("end_column") => 1u64.into(),
+                Arc::from("editor_begin_column") => 1u64.into(),
+                Arc::from("editor_end_column") => 2u64.into(),
+                Arc::from("begin_byte_offset") => 164u64.into(),
+                Arc::from("end_byte_offset") => 197u64.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn adapter_extension() {
+    struct TestExtension<'a> {
+        crate_: &'a IndexedCrate<'a>,
+    }
+
+    impl<'a> AdapterExtension<'a> for TestExtension<'a> {
+        fn schema_fields(&self) -> Vec<(&'static str, &'static str)> {
+            vec![
+                ("Item", "name_length: Int"),
+                ("Struct", "crate_function: [Function!]"),
+            ]
+        }
+
+        fn resolve_property(
+            &self,
+            vertex: &Vertex<'a>,
+            _type_name: &str,
+            property_name: &str,
+        ) -> FieldValue {
+            assert_eq!("name_length", property_name);
+            let item = vertex.as_item().expect("not an item");
+            item.name.as_ref().map(|name| name.len() as u64).into()
+        }
+
+        fn resolve_neighbors(
+            &self,
+            _vertex: &Vertex<'a>,
+            _type_name: &str,
+            edge_name: &str,
+            _parameters: &EdgeParameters,
+        ) -> Box<dyn Iterator<Item = &'a rustdoc_types::Item> + 'a> {
+            assert_eq!("crate_function", edge_name);
+            Box::new(
+                self.crate_
+                    .inner
+                    .index
+                    .values()
+                    .filter(|item| matches!(item.inner, rustdoc_types::ItemEnum::Function(..))),
+            )
+        }
+    }
+
+    let path = "./localdata/test_data/structs_are_not_modules/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None).with_extension(TestExtension {
+        crate_: &indexed_crate,
+    });
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @output
+                name_length @output
+
+                crate_function {
+                    name @output(name: "function")
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema = adapter.extended_schema();
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["function"].as_str().cmp(&b["function"].as_str()));
+
+    let expected: Vec<_> = ["associated_fn", "method", "top_level_function"]
+        .into_iter()
+        .map(|function| {
+            btreemap! {
+                Arc::from("name") => FieldValue::String("Foo".to_string()),
+                Arc::from("name_length") => FieldValue::Uint64(3),
+                Arc::from("function") => FieldValue::String(function.to_string()),
+            }
+        })
+        .collect();
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn doc_hidden_items() {
+    let path = "./localdata/test_data/doc_hidden/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            name @filter(op: "is_not_null") @output
+            doc_hidden @output
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => FieldValue::String("Hidden".to_string()),
+                Arc::from("doc_hidden") => FieldValue::Boolean(true),
+            },
+            btreemap! {
+                Arc::from("name") => FieldValue::String("Visible".to_string()),
+                Arc::from("doc_hidden") => FieldValue::Boolean(false),
+            },
+            btreemap! {
+                Arc::from("name") => FieldValue::String("__private".to_string()),
+                Arc::from("doc_hidden") => FieldValue::Boolean(true),
+            },
+            btreemap! {
+                Arc::from("name") => FieldValue::String("doc_hidden".to_string()),
+                Arc::from("doc_hidden") => FieldValue::Boolean(false),
+            },
+            btreemap! {
+                Arc::from("name") => FieldValue::String("macro_support".to_string()),
+                Arc::from("doc_hidden") => FieldValue::Boolean(false),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn crate_impls() {
+    let path = "./localdata/test_data/trait_impls/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        impl {
+            synthetic @filter(op: "!=", value: ["$true"])
+            unsafe @output
+
+            implemented_trait {
+                name @filter(op: "one_of", value: ["$traits"]) @output(name: "trait_name")
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "true" => FieldValue::Boolean(true),
+        "traits" => vec!["Marker", "Send"].into(),
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["trait_name"].as_str().cmp(&b["trait_name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("unsafe") => FieldValue::Boolean(false),
+                Arc::from("trait_name") => FieldValue::String("Marker".to_string()),
+            },
+            btreemap! {
+                Arc::from("unsafe") => FieldValue::Boolean(false),
+                Arc::from("trait_name") => FieldValue::String("Marker".to_string()),
+            },
+            btreemap! {
+                Arc::from("unsafe") => FieldValue::Boolean(true),
+                Arc::from("trait_name") => FieldValue::String("Send".to_string()),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn crate_trait_impls() {
+    let path = "./localdata/test_data/trait_impls/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+
+    let query = r#"
+{
+    Crate {
+        trait_impl(trait: "TRAIT") @fold @transform(op: "count") @output(name: "impls") {
+            synthetic @filter(op: "!=", value: ["$true"])
+        }
+    }
+}
+"#;
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+
+    for (trait_, expected_count) in [
+        ("Marker", 2),
+        ("trait_impls::Marker", 2),
+        ("Send", 1),
+        ("core::marker::Send", 1),
+        ("Sync", 0),
+    ] {
+        // Edge parameters must be literals, so the trait is spliced into the query.
+        let trait_query = query.replace("TRAIT", trait_);
+        let variables = btreemap! {
+            "true" => FieldValue::Boolean(true),
+        };
+        let results: Vec<_> =
+            trustfall::execute_query(&schema, adapter.clone(), &trait_query, variables)
+                .expect("failed to run query")
+                .collect();
+
+        assert_eq!(
+            vec![btreemap! {
+                Arc::from("impls") => FieldValue::Uint64(expected_count),
+            }],
+            results,
+            "{trait_}"
+        );
+    }
+}
+
+#[test]
+fn impl_owner_trait_impls() {
+    let path = "./localdata/test_data/trait_impls/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @output
+
+                trait_impl(trait: "TRAIT") @fold @transform(op: "count") @output(name: "impls") {
+                    synthetic @filter(op: "!=", value: ["$true"])
+                }
+            }
+        }
+    }
+}
+"#;
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+
+    for (trait_, expected_foo_count, expected_bar_count) in [
+        ("Marker", 1, 1),
+        ("trait_impls::Marker", 1, 1),
+        ("Send", 0, 1),
+        ("core::marker::Send", 0, 1),
+        ("Sync", 0, 0),
+    ] {
+        // Edge parameters must be literals, so the trait is spliced into the query.
+        let trait_query = query.replace("TRAIT", trait_);
+        let variables = btreemap! {
+            "true" => FieldValue::Boolean(true),
+        };
+        let mut results: Vec<_> =
+            trustfall::execute_query(&schema, adapter.clone(), &trait_query, variables)
+                .expect("failed to run query")
+                .collect();
+        results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+        assert_eq!(
+            vec![
+                btreemap! {
+                    Arc::from("name") => "Bar".into(),
+                    Arc::from("impls") => FieldValue::Uint64(expected_bar_count),
+                },
+                btreemap! {
+                    Arc::from("name") => "Foo".into(),
+                    Arc::from("impls") => FieldValue::Uint64(expected_foo_count),
+                },
+            ],
+            results,
+            "{trait_}"
+        );
+    }
+}
+
+#[test]
+fn associated_type_bounds() {
+    let path = "./localdata/test_data/associated_type_bounds/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Trait {
+                name @filter(op: "=", value: ["$trait"])
+
+                associated_type {
+                    name @output
+                    has_default @output
+                    lifetime_bound @output
+
+                    trait_bound @optional {
+                        name @output(name: "bound")
+
+                        trait @optional {
+                            id @output(name: "trait_id")
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "trait" => "Container",
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| {
+        (a["name"].as_str(), a["bound"].as_str()).cmp(&(b["name"].as_str(), b["bound"].as_str()))
+    });
+
+    let marker_id = crate_
+        .index
+        .values()
+        .find(|item| item.name.as_deref() == Some("Marker"))
+        .expect("no Marker trait")
+        .id
+        .0
+        .clone();
+
+    // `Clone` is one of the manually-inlined built-in traits, so its trait is found too.
+    let clone_id = indexed_crate
+        .manually_inlined_builtin_traits
+        .values()
+        .find(|item| item.name.as_deref() == Some("Clone"))
+        .expect("no Clone trait")
+        .id
+        .0
+        .clone();
+
+    let expected = vec![
+        btreemap! {
+            Arc::from("name") => "Error".into(),
+            Arc::from("has_default") => false.into(),
+            Arc::from("lifetime_bound") => Vec::<&str>::new().into(),
+            Arc::from("bound") => FieldValue::Null,
+            Arc::from("trait_id") => FieldValue::Null,
+        },
+        btreemap! {
+            Arc::from("name") => "Item".into(),
+            Arc::from("has_default") => false.into(),
+            Arc::from("lifetime_bound") => vec!["'static"].into(),
+            Arc::from("bound") => "Clone".into(),
+            Arc::from("trait_id") => clone_id.into(),
+        },
+        btreemap! {
+            Arc::from("name") => "Item".into(),
+            Arc::from("has_default") => false.into(),
+            Arc::from("lifetime_bound") => vec!["'static"].into(),
+            Arc::from("bound") => "Marker".into(),
+            Arc::from("trait_id") => marker_id.into(),
+        },
+    ];
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn trait_object_components() {
+    let path = "./localdata/test_data/trait_objects/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                field {
+                    name @output(name: "field")
+
+                    raw_type {
+                        trait_object @optional {
+                            name @output
+                            lifetime @output
+
+                            trait_bound @fold {
+                                name @output(name: "bounds")
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["field"].as_str().cmp(&b["field"].as_str()));
+
+    let expected = vec![
+        btreemap! {
+            Arc::from("field") => "count".into(),
+            Arc::from("name") => FieldValue::Null,
+            Arc::from("lifetime") => FieldValue::Null,
+            Arc::from("bounds") => FieldValue::Null,
+        },
+        btreemap! {
+            Arc::from("field") => "error".into(),
+            Arc::from("name") => "dyn Error + Send + Sync + 'a".into(),
+            Arc::from("lifetime") => "'a".into(),
+            Arc::from("bounds") => vec!["Error", "Send", "Sync"].into(),
+        },
+        btreemap! {
+            Arc::from("field") => "observers".into(),
+            Arc::from("name") => "dyn Debug".into(),
+            Arc::from("lifetime") => FieldValue::Null,
+            Arc::from("bounds") => vec!["Debug"].into(),
+        },
+    ];
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn generic_type_parameter_lifetime_bounds() {
+    let path = "./localdata/test_data/generic_lifetime_bounds/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let struct_query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @output
+
+                generic_type_parameter {
+                    name @output(name: "parameter")
+                    lifetime_bound @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let mut results: Vec<_> =
+        trustfall::execute_query(&schema, adapter.clone(), struct_query, variables.clone())
+            .expect("failed to run query")
+            .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    let expected = vec![
+        btreemap! {
+            Arc::from("name") => "Inline".into(),
+            Arc::from("parameter") => "T".into(),
+            Arc::from("lifetime_bound") => vec!["'a"].into(),
+        },
+        btreemap! {
+            Arc::from("name") => "Unbounded".into(),
+            Arc::from("parameter") => "T".into(),
+            Arc::from("lifetime_bound") => Vec::<&str>::new().into(),
+        },
+        btreemap! {
+            Arc::from("name") => "WhereClause".into(),
+            Arc::from("parameter") => "T".into(),
+            Arc::from("lifetime_bound") => vec!["'a"].into(),
+        },
+    ];
+    assert_eq!(expected, results);
+
+    let function_query = r#"
+{
+    Crate {
+        item {
+            ... on Function {
+                name @output
+
+                generic_type_parameter {
+                    name @output(name: "parameter")
+                    lifetime_bound @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let results: Vec<_> =
+        trustfall::execute_query(&schema, adapter.clone(), function_query, variables)
+            .expect("failed to run query")
+            .collect();
+
+    let expected = vec![btreemap! {
+        Arc::from("name") => "spawn".into(),
+        Arc::from("parameter") => "F".into(),
+        Arc::from("lifetime_bound") => vec!["'static"].into(),
+    }];
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn function_c_variadic() {
+    let path = "./localdata/test_data/c_variadic/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Function {
+                name @output
+                c_variadic @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    let expected = vec![
+        btreemap! {
+            Arc::from("name") => "printf".into(),
+            Arc::from("c_variadic") => true.into(),
+        },
+        btreemap! {
+            Arc::from("name") => "puts".into(),
+            Arc::from("c_variadic") => false.into(),
+        },
+    ];
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn item_at_location() {
+    let path = "./localdata/test_data/generic_lifetime_bounds/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+
+    let query = r#"
+{
+    Crate {
+        item_at_location(file: "FILE", line: 8) {
+            name @output
+            __typename @output(name: "kind")
+        }
+    }
+}
+"#;
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    // Edge parameters must be literals, so the file is spliced into the query.
+    // Line 8 is the `value` field of `struct Inline`, inside the crate's root module.
+    let lib_query = query.replace("FILE", "src/lib.rs");
+    let results: Vec<_> =
+        trustfall::execute_query(&schema, adapter.clone(), &lib_query, variables.clone())
+            .expect("failed to run query")
+            .collect();
+    let expected = vec![
+        btreemap! {
+            Arc::from("name") => "generic_lifetime_bounds".into(),
+            Arc::from("kind") => "Module".into(),
+        },
+        btreemap! {
+            Arc::from("name") => "Inline".into(),
+            Arc::from("kind") => "Struct".into(),
+        },
+        btreemap! {
+            Arc::from("name") => "value".into(),
+            Arc::from("kind") => "StructField".into(),
+        },
+    ];
+    assert_eq!(expected, results);
+
+    // Files that aren't part of the crate don't contain any items.
+    let main_query = query.replace("FILE", "src/main.rs");
+    let results: Vec<_> = trustfall::execute_query(&schema, adapter, &main_query, variables)
+        .expect("failed to run query")
+        .collect();
+    assert!(results.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "the span index is disabled")]
+fn item_at_location_without_span_index() {
+    let path = "./localdata/test_data/generic_lifetime_bounds/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::builder(&crate_)
+        .with_span_index(false)
+        .build();
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item_at_location(file: "src/lib.rs", line: 8) {
+            name @output
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let _: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+}
+
+#[test]
+fn source_files() {
+    let path = "./localdata/test_data/source_files/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    // Blanket impls from the standard library have spans in its files, so skip those.
+    let query = r#"
+{
+    Crate {
+        file {
+            path @output
+                 @filter(op: "has_prefix", value: ["$prefix"])
+
+            item @fold {
+                name @output(name: "item")
+                     @filter(op: "is_not_null")
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "prefix" => "src/",
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("path") => "src/lib.rs".into(),
+                Arc::from("item") => vec!["source_files", "Config"].into(),
+            },
+            btreemap! {
+                Arc::from("path") => "src/shapes.rs".into(),
+                Arc::from("item") => vec!["shapes", "Circle", "area"].into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn execution_counters() {
+    let path = "./localdata/test_data/reexport/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_: Crate = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None).with_execution_counters());
+    assert_eq!(
+        Some(ExecutionCounters::default()),
+        adapter.execution_counters()
+    );
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, adapter.clone(), query, variables)
+        .expect("failed to run query")
+        .collect();
+    assert!(!results.is_empty());
+
+    let counters = adapter
+        .execution_counters()
+        .expect("execution counters were enabled");
+    let crate_item_edge = ("Crate".to_string(), "item".to_string());
+    assert_eq!(
+        btreemap! { "Crate".to_string() => 1 },
+        counters.entry_points
+    );
+    assert_eq!(
+        btreemap! { crate_item_edge.clone() => 1 },
+        counters.edge_expansions
+    );
+    assert_eq!(
+        btreemap! { crate_item_edge => crate_.index.len() as u64 },
+        counters.edge_vertices
+    );
+
+    // Counting is disabled by default.
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+    assert_eq!(None, adapter.execution_counters());
+}
+
+#[test]
+fn interface_hierarchy() {
+    let path = "./localdata/test_data/generic_lifetime_bounds/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    // A single fragment on `GenericItem` covers both structs and functions,
+    // and its properties from `Documented` and `HasAttributes` are available too.
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on GenericItem {
+                # Impls don't have names, and aren't part of this test.
+                name @filter(op: "is_not_null") @output
+                docs @output
+                attrs @output
+                __typename @output(name: "kind")
+
+                generic_type_parameter {
+                    name @output(name: "parameter")
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    let expected: Vec<_> = [
+        ("Inline", "Struct", "T"),
+        ("Unbounded", "Struct", "T"),
+        ("WhereClause", "Struct", "T"),
+        ("spawn", "Function", "F"),
+    ]
+    .into_iter()
+    .map(|(name, kind, parameter)| {
+        btreemap! {
+            Arc::from("name") => name.into(),
+            Arc::from("docs") => FieldValue::Null,
+            Arc::from("attrs") => Vec::<&str>::new().into(),
+            Arc::from("kind") => kind.into(),
+            Arc::from("parameter") => parameter.into(),
+        }
+    })
+    .collect();
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn enum_discriminants() {
+    let path = "./localdata/test_data/enum_discriminants/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Variant {
+                name @output
+                discriminant_expr @output
+                discriminant_value @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Expression".into(),
+                Arc::from("discriminant_expr") => "i64::MAX - 1".into(),
+                Arc::from("discriminant_value") => FieldValue::Int64(i64::MAX - 1),
+            },
+            btreemap! {
+                Arc::from("name") => "Hex".into(),
+                Arc::from("discriminant_expr") => "0x20".into(),
+                Arc::from("discriminant_value") => FieldValue::Int64(32),
+            },
+            btreemap! {
+                Arc::from("name") => "Implicit".into(),
+                Arc::from("discriminant_expr") => FieldValue::Null,
+                Arc::from("discriminant_value") => FieldValue::Null,
+            },
+            btreemap! {
+                Arc::from("name") => "Literal".into(),
+                Arc::from("discriminant_expr") => "16".into(),
+                Arc::from("discriminant_value") => FieldValue::Int64(16),
+            },
+            btreemap! {
+                Arc::from("name") => "Max".into(),
+                Arc::from("discriminant_expr") => "u64::MAX".into(),
+                Arc::from("discriminant_value") => FieldValue::Uint64(u64::MAX),
+            },
+            btreemap! {
+                Arc::from("name") => "Negative".into(),
+                Arc::from("discriminant_expr") => "-1".into(),
+                Arc::from("discriminant_value") => FieldValue::Int64(-1),
+            },
+            btreemap! {
+                Arc::from("name") => "Shift".into(),
+                Arc::from("discriminant_expr") => "1 << 6".into(),
+                Arc::from("discriminant_value") => FieldValue::Int64(64),
+            },
+            btreemap! {
+                Arc::from("name") => "TooLarge".into(),
+                Arc::from("discriminant_expr") => "u128::MAX".into(),
+                Arc::from("discriminant_value") => FieldValue::Null,
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn struct_field_positional_names() {
+    let path = "./localdata/test_data/struct_field_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on StructField {
+                name @output
+                is_positional @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    let expected: Vec<_> = [
+        ("0", true),
+        ("0", true),
+        ("1", true),
+        ("first", false),
+        ("second", false),
+        ("value", false),
+    ]
+    .into_iter()
+    .map(|(name, is_positional)| {
+        btreemap! {
+            Arc::from("name") => name.into(),
+            Arc::from("is_positional") => is_positional.into(),
+        }
+    })
+    .collect();
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn proc_macros() {
+    let path = "./localdata/test_data/proc_macros/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on ProcMacro {
+                name @output
+                kind @output
+                helper_attributes @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Builder".into(),
+                Arc::from("kind") => "derive".into(),
+                Arc::from("helper_attributes") => vec!["builder", "builder_field"].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "make_answer".into(),
+                Arc::from("kind") => "function-like".into(),
+                Arc::from("helper_attributes") => Vec::<String>::new().into(),
+            },
+            btreemap! {
+                Arc::from("name") => "route".into(),
+                Arc::from("kind") => "attribute".into(),
+                Arc::from("helper_attributes") => Vec::<String>::new().into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn crate_doc_attributes() {
+    let path = "./localdata/test_data/crate_doc_attributes/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        html_root_url @output
+        html_logo_url @output
+        html_favicon_url @output
+        html_playground_url @output
+        issue_tracker_base_url @output
+
+        attribute @fold {
+            raw_attribute @output(name: "attributes")
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(1, results.len());
+    let result = &results[0];
+    assert_eq!(
+        FieldValue::from("https://docs.rs/crate_doc_attributes/0.1.0"),
+        result["html_root_url"]
+    );
+    assert_eq!(
+        FieldValue::from("https://example.com/logo.png"),
+        result["html_logo_url"]
+    );
+    assert_eq!(FieldValue::Null, result["html_favicon_url"]);
+    assert_eq!(
+        FieldValue::from("https://play.rust-lang.org/"),
+        result["html_playground_url"]
+    );
+    assert_eq!(FieldValue::Null, result["issue_tracker_base_url"]);
+
+    let attributes = match &result["attributes"] {
+        FieldValue::List(attributes) => attributes,
+        other => panic!("unexpected attributes value: {other:?}"),
+    };
+    assert!(
+        attributes.contains(&FieldValue::from("#![no_std]")),
+        "{attributes:?}"
+    );
+}
+
+#[test]
+fn import_resolves_through() {
+    let path = "./localdata/test_data/reexport_chain/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Import {
+                imported_as @filter(op: "=", value: ["$name"])
+                source @output
+
+                resolves_through @fold {
+                    __typename @output(name: "kind")
+                    id @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "name" => "Final",
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    let renamed_import = crate_
+        .index
+        .values()
+        .find(|item| {
+            matches!(
+                &item.inner,
+                rustdoc_types::ItemEnum::Import(import) if import.name == "Renamed"
+            )
+        })
+        .expect("no Renamed import");
+    let item_id = |name: &str| {
+        crate_
+            .index
+            .values()
+            .find(|item| item.name.as_deref() == Some(name))
+            .map(|item| item.id.0.as_str())
+            .unwrap_or_else(|| panic!("no item named {name}"))
+    };
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("source") => "middle::Renamed".into(),
+            Arc::from("kind") => vec!["Import", "Typedef", "Struct"].into(),
+            Arc::from("id") => vec![
+                renamed_import.id.0.as_str(),
+                item_id("Alias"),
+                item_id("Foo"),
+            ].into(),
+        }],
+        results
+    );
+}
+
+#[test]
+fn item_lint_attributes() {
+    let path = "./localdata/test_data/type_and_value_with_matching_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            name @output
+            __typename @output(name: "kind")
+
+            lint_attribute {
+                level @output
+                lint @output
+                reason @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Bar".into(),
+                Arc::from("kind") => "Constant".into(),
+                Arc::from("level") => "allow".into(),
+                Arc::from("lint") => "non_upper_case_globals".into(),
+                Arc::from("reason") => FieldValue::Null,
+            },
+            btreemap! {
+                Arc::from("name") => "Foo".into(),
+                Arc::from("kind") => "Function".into(),
+                Arc::from("level") => "allow".into(),
+                Arc::from("lint") => "non_snake_case".into(),
+                Arc::from("reason") => FieldValue::Null,
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn modules() {
+    let path = "./localdata/test_data/reexport_chain/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Module {
+                name @output
+                is_crate_root @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    let expected: Vec<_> = [
+        ("inner", false),
+        ("middle", false),
+        ("reexport_chain", true),
+    ]
+    .into_iter()
+    .map(|(name, is_crate_root)| {
+        btreemap! {
+            Arc::from("name") => name.into(),
+            Arc::from("is_crate_root") => is_crate_root.into(),
+        }
+    })
+    .collect();
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn keyword_pages() {
+    let path = "./localdata/test_data/keywords/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on OtherItem {
+                name @output
+                kind @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    // The keyword page is a module in the rustdoc, but unlike `ordinary`, not a `Module` vertex.
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("name") => "match_keyword".into(),
+            Arc::from("kind") => "keyword".into(),
+        }],
+        results
+    );
+}
+
+#[test]
+fn primitive_items() {
+    let path = "./localdata/test_data/primitive_docs/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Primitive {
+                __typename @output(name: "kind")
+                name @output
+                primitive_name @output
+                docs @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("kind") => "Primitive".into(),
+            Arc::from("name") => "char".into(),
+            Arc::from("primitive_name") => "char".into(),
+            Arc::from("docs") => "The `char` primitive.".into(),
+        }],
+        results
+    );
+}
+
+#[test]
+fn function_like_items() {
+    let path = "./localdata/test_data/public_api/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        function_like {
+            kind @output
+
+            function {
+                name @output
+            }
+            owner @optional {
+                __typename @output(name: "owner_kind")
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("kind") => "inherent_method".into(),
+                Arc::from("name") => "area".into(),
+                Arc::from("owner_kind") => "Impl".into(),
+            },
+            btreemap! {
+                Arc::from("kind") => "trait_method".into(),
+                Arc::from("name") => "draw".into(),
+                Arc::from("owner_kind") => "Trait".into(),
+            },
+            btreemap! {
+                Arc::from("kind") => "inherent_method".into(),
+                Arc::from("name") => "new".into(),
+                Arc::from("owner_kind") => "Impl".into(),
+            },
+            btreemap! {
+                Arc::from("kind") => "inherent_method".into(),
+                Arc::from("name") => "private_helper".into(),
+                Arc::from("owner_kind") => "Impl".into(),
+            },
+            btreemap! {
+                Arc::from("kind") => "function".into(),
+                Arc::from("name") => "total_area".into(),
+                Arc::from("owner_kind") => FieldValue::Null,
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn crate_generation_properties() {
+    let path = "./localdata/test_data/public_api/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_: Crate = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        format_version @output
+        includes_private @output
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("format_version") => (rustdoc_types::FORMAT_VERSION as u64).into(),
+            Arc::from("includes_private") => crate_.includes_private.into(),
+        }],
+        results
+    );
+}
+
+#[test]
+fn enum_matchable_variants() {
+    let path = "./localdata/test_data/enum_matchability/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Enum {
+                name @output
+                matchable_variant_names @output
+                exhaustively_matchable @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Exhaustive".into(),
+                Arc::from("matchable_variant_names") => vec!["First", "Second"].into(),
+                Arc::from("exhaustively_matchable") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "NonExhaustive".into(),
+                Arc::from("matchable_variant_names") => vec!["First", "Second"].into(),
+                Arc::from("exhaustively_matchable") => false.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "WithHiddenVariant".into(),
+                Arc::from("matchable_variant_names") => vec!["First", "Second"].into(),
+                Arc::from("exhaustively_matchable") => false.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn available_methods_through_deref() {
+    let path = "./localdata/test_data/method_availability/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @output
+
+                available_method(follow_deref: FOLLOW_DEREF) {
+                    name @output(name: "method")
+                }
+            }
+        }
+    }
+}
+"#;
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+
+    let run_query = |follow_deref: bool| {
+        // Edge parameters must be literals, so the flag is spliced into the query.
+        let query = query.replace("FOLLOW_DEREF", &follow_deref.to_string());
+        let variables: BTreeMap<&str, &str> = BTreeMap::new();
+        let mut results: Vec<_> =
+            trustfall::execute_query(&schema, adapter.clone(), &query, variables)
+                .expect("failed to run query")
+                .map(|row| {
+                    (
+                        row["name"].as_str().expect("no name").to_string(),
+                        row["method"].as_str().expect("no method").to_string(),
+                    )
+                })
+                .collect();
+        results.sort_unstable();
+        results
+    };
+    let pairs = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, method)| (name.to_string(), method.to_string()))
+            .collect()
+    };
+
+    assert_eq!(
+        pairs(&[
+            ("Impostor", "deref"),
+            ("Inner", "len"),
+            ("Inner", "shadowed"),
+            ("Outer", "deref"),
+            ("Wrapper", "deref"),
+            ("Wrapper", "describe"),
+            ("Wrapper", "shadowed"),
+        ]),
+        run_query(false)
+    );
+    assert_eq!(
+        pairs(&[
+            ("Impostor", "deref"),
+            ("Inner", "len"),
+            ("Inner", "shadowed"),
+            ("Outer", "deref"),
+            ("Outer", "describe"),
+            ("Outer", "len"),
+            ("Outer", "shadowed"),
+            ("Wrapper", "deref"),
+            ("Wrapper", "describe"),
+            ("Wrapper", "len"),
+            ("Wrapper", "shadowed"),
+        ]),
+        run_query(true)
+    );
+}
+
+#[test]
+fn available_methods_from_supertraits() {
+    let path = "./localdata/test_data/supertrait_methods/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @filter(op: "=", value: ["$struct"])
+
+                available_method(include_supertraits: true) {
+                    name @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! { "struct" => "Greeter" };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    // Each method is found exactly once, whether or not the blanket impls are visible.
+    assert_eq!(
+        vec![
+            btreemap! { Arc::from("name") => "greet".into() },
+            btreemap! { Arc::from("name") => "id".into() },
+            btreemap! { Arc::from("name") => "name".into() },
+        ],
+        results
+    );
+}
+
+#[test]
+fn method_name_conflicts() {
+    let path = "./localdata/test_data/method_name_conflicts/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @output(name: "struct")
+
+                method_name_conflict {
+                    name @output
+                    involves_inherent_impl @output
+
+                    impl @fold @transform(op: "count") @output(name: "impls")
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("struct") => "Widget".into(),
+                Arc::from("name") => "draw".into(),
+                Arc::from("involves_inherent_impl") => true.into(),
+                Arc::from("impls") => FieldValue::Uint64(2),
+            },
+            btreemap! {
+                Arc::from("struct") => "Widget".into(),
+                Arc::from("name") => "resize".into(),
+                Arc::from("involves_inherent_impl") => false.into(),
+                Arc::from("impls") => FieldValue::Uint64(2),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn self_type_in_signatures() {
+    let path = "./localdata/test_data/self_in_signatures/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Function {
+                name @output
+                return_type_name @output
+
+                parameter {
+                    name @output(name: "parameter")
+                    type_name @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .map(|row| {
+            [
+                row["name"].as_str().expect("no name").to_string(),
+                row["parameter"].as_str().expect("no parameter").to_string(),
+                row["type_name"].as_str().expect("no type_name").to_string(),
+                row["return_type_name"]
+                    .as_str()
+                    .expect("no return_type_name")
+                    .to_string(),
+            ]
+        })
+        .collect();
+    results.sort_unstable();
+
+    let expected: Vec<_> = [
+        ["duplicate", "self", "&Point<i64>", "Point<i64>"],
+        ["duplicate", "self", "&Self", "Self"],
+        ["get", "self", "&Point<T>", "&T"],
+        ["new", "x", "T", "Point<T>"],
+        ["swap", "other", "Point<T>", "Point<T>"],
+        ["swap", "self", "Point<T>", "Point<T>"],
+    ]
+    .into_iter()
+    .map(|row| row.map(String::from))
+    .collect();
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn raw_type_display_names() {
+    let path = "./localdata/test_data/type_display_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                field {
+                    name @output
+
+                    raw_type {
+                        display_name @output
+                        qualified_display_name @output
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    let expected: Vec<_> = [
+        ("bytes", "[u8; 4]", "[u8; 4]"),
+        ("callback", "fn(u32) -> bool", "fn(u32) -> bool"),
+        ("names", "Vec<&'a str>", "alloc::vec::Vec<&'a str>"),
+        (
+            "pair",
+            "(u8, Option<shapes::Square>)",
+            "(u8, core::option::Option<type_display_names::shapes::Square>)",
+        ),
+        (
+            "square",
+            "shapes::Square",
+            "type_display_names::shapes::Square",
+        ),
+    ]
+    .into_iter()
+    .map(|(name, display_name, qualified_display_name)| {
+        btreemap! {
+            Arc::from("name") => name.into(),
+            Arc::from("display_name") => display_name.into(),
+            Arc::from("qualified_display_name") => qualified_display_name.into(),
+        }
+    })
+    .collect();
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn raw_type_display_names_with_path_normalization() {
+    let path = "./localdata/test_data/type_display_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None).with_path_normalization(
+        PathNormalization::new()
+            .with_resolved_paths(true)
+            .with_stripped_std_prefixes(true),
+    );
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                field {
+                    name @filter(op: "one_of", value: ["$fields"]) @output
+
+                    raw_type {
+                        display_name @output
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! { "fields" => vec!["names", "pair"] };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "names".into(),
+                Arc::from("display_name") => "vec::Vec<&'a str>".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "pair".into(),
+                Arc::from("display_name") =>
+                    "(u8, option::Option<type_display_names::shapes::Square>)".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn ffi_symbols() {
+    let path = "./localdata/test_data/ffi_surface/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        ffi_symbol {
+            name @output
+            __typename @output(name: "kind")
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "COUNTER".into(),
+                Arc::from("kind") => "Static".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "add".into(),
+                Arc::from("kind") => "Function".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "callback".into(),
+                Arc::from("kind") => "Function".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "renamed".into(),
+                Arc::from("kind") => "Function".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn transparent_struct_inner_types() {
+    let path = "./localdata/test_data/transparent_structs/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @output
+
+                transparent_inner_type {
+                    display_name @output(name: "inner_type")
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Named".into(),
+                Arc::from("inner_type") => "Vec<u8>".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Tagged".into(),
+                Arc::from("inner_type") => "u32".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Wrapper".into(),
+                Arc::from("inner_type") => "u64".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn non_exhaustive_variants() {
+    let path = "./localdata/test_data/non_exhaustive_variants/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Enum {
+                name @output(name: "enum_name")
+
+                variant {
+                    name @output
+                    non_exhaustive @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("enum_name") => "Shape".into(),
+                Arc::from("name") => "Circle".into(),
+                Arc::from("non_exhaustive") => false.into(),
+            },
+            btreemap! {
+                Arc::from("enum_name") => "Event".into(),
+                Arc::from("name") => "Click".into(),
+                Arc::from("non_exhaustive") => true.into(),
+            },
+            btreemap! {
+                Arc::from("enum_name") => "Event".into(),
+                Arc::from("name") => "Close".into(),
+                Arc::from("non_exhaustive") => false.into(),
+            },
+            btreemap! {
+                Arc::from("enum_name") => "Event".into(),
+                Arc::from("name") => "Key".into(),
+                Arc::from("non_exhaustive") => true.into(),
+            },
+            btreemap! {
+                Arc::from("enum_name") => "Shape".into(),
+                Arc::from("name") => "Point".into(),
+                Arc::from("non_exhaustive") => false.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn importable_paths_without_impl_index() {
+    let path = "./localdata/test_data/reexport/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::builder(&crate_)
+        .with_impl_index(false)
+        .build();
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Function {
+                name @output
+
+                importable_path @fold @transform(op: "count") @output(name: "importable_paths") {
+                    path
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("name") => "foo".into(),
+            Arc::from("importable_paths") => FieldValue::Uint64(2),
+        }],
+        results
+    );
+}
+
+#[test]
+#[should_panic(expected = "the impl index is disabled")]
+fn method_lookup_without_impl_index() {
+    let path = "./localdata/test_data/impl_for_ref/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::builder(&crate_)
+        .with_impl_index(false)
+        .build();
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                impl {
+                    method {
+                        name @filter(op: "=", value: ["$method"]) @output
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "method" => "eq",
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let _: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+}
+
+#[test]
+fn item_lookup_by_name_and_kind() {
+    let path = "./localdata/test_data/type_and_value_with_matching_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None).with_execution_counters());
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @filter(op: "=", value: ["$name"]) @output
+                __typename @output(name: "kind")
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "name" => "Foo",
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, adapter.clone(), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("name") => "Foo".into(),
+            Arc::from("kind") => "Struct".into(),
+        }],
+        results
+    );
+
+    // The struct was looked up directly, without producing any of the crate's other items,
+    // like the function with the same name.
+    let counters = adapter
+        .execution_counters()
+        .expect("execution counters were enabled");
+    assert_eq!(
+        btreemap! { ("Crate".to_string(), "item".to_string()) => 1 },
+        counters.edge_vertices
+    );
+}
+
+#[test]
+fn deprecated_items() {
+    let path = "./localdata/test_data/deprecated_items/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        deprecated_item {
+            name @output
+            __typename @output(name: "kind")
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "OldStruct".into(),
+                Arc::from("kind") => "Struct".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "old_function".into(),
+                Arc::from("kind") => "Function".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "old_method".into(),
+                Arc::from("kind") => "Function".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn items_of_kind() {
+    let path = "./localdata/test_data/type_and_value_with_matching_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None).with_execution_counters());
+
+    let query = r#"
+{
+    Crate {
+        item(kind: ["Struct", "Function"]) {
+            name @output
+            __typename @output(name: "kind")
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, adapter.clone(), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| {
+        (a["name"].as_str(), a["kind"].as_str()).cmp(&(b["name"].as_str(), b["kind"].as_str()))
+    });
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Bar".into(),
+                Arc::from("kind") => "Struct".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Foo".into(),
+                Arc::from("kind") => "Function".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Foo".into(),
+                Arc::from("kind") => "Struct".into(),
+            },
+        ],
+        results
+    );
+
+    // Only the items of the requested kinds were produced.
+    let counters = adapter
+        .execution_counters()
+        .expect("execution counters were enabled");
+    assert_eq!(
+        btreemap! { ("Crate".to_string(), "item".to_string()) => 3 },
+        counters.edge_vertices
+    );
+}
+
+#[test]
+fn items_coerced_to_type() {
+    let path = "./localdata/test_data/type_and_value_with_matching_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None).with_execution_counters());
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on ImplOwner {
+                name @output
+                __typename @output(name: "kind")
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, adapter.clone(), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Bar".into(),
+                Arc::from("kind") => "Struct".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Foo".into(),
+                Arc::from("kind") => "Struct".into(),
+            },
+        ],
+        results
+    );
+
+    // Only the structs and enums were produced, rather than every item in the crate.
+    let counters = adapter
+        .execution_counters()
+        .expect("execution counters were enabled");
+    assert_eq!(
+        btreemap! { ("Crate".to_string(), "item".to_string()) => 2 },
+        counters.edge_vertices
+    );
+}
+
+#[test]
+fn maybe_sized_bounds() {
+    let path = "./localdata/test_data/maybe_sized_bounds/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on GenericItem {
+                # Impls, including the ones rustdoc synthesizes for auto traits, have no name.
+                name @filter(op: "is_not_null") @output
+
+                generic_type_parameter {
+                    maybe_bound @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, adapter.clone(), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "WhereClause".into(),
+                Arc::from("maybe_bound") => vec!["Sized"].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "inline".into(),
+                Arc::from("maybe_bound") => vec!["Sized"].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "sized".into(),
+                Arc::from("maybe_bound") => Vec::<&str>::new().into(),
+            },
+        ],
+        results
+    );
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Trait {
+                associated_type {
+                    name @output
+
+                    trait_bound {
+                        name @output(name: "bound")
+                        is_maybe @output
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let results: Vec<_> = trustfall::execute_query(&schema, adapter, query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("name") => "Target".into(),
+            Arc::from("bound") => "Sized".into(),
+            Arc::from("is_maybe") => true.into(),
+        }],
+        results
+    );
+}
+
+#[test]
+fn synthetic_generic_parameters() {
+    let path = "./localdata/test_data/synthetic_generics/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Function {
+                name @output
+
+                generic_type_parameter {
+                    is_synthetic @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| {
+        (a["name"].as_str(), a["is_synthetic"].as_bool())
+            .cmp(&(b["name"].as_str(), b["is_synthetic"].as_bool()))
+    });
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "argument_position".into(),
+                Arc::from("is_synthetic") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "explicit".into(),
+                Arc::from("is_synthetic") => false.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "mixed".into(),
+                Arc::from("is_synthetic") => false.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "mixed".into(),
+                Arc::from("is_synthetic") => true.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn impl_module_paths() {
+    let path = "./localdata/test_data/impl_locations/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @filter(op: "=", value: ["$struct"])
+
+                impl {
+                    module_path @filter(op: "is_not_null") @output
+
+                    implemented_trait @optional {
+                        name @output(name: "trait")
+                    }
+
+                    span {
+                        filename @output
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "struct" => "Circle",
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["trait"].as_str().cmp(&b["trait"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("module_path") => vec!["impl_locations", "shapes"].into(),
+                Arc::from("trait") => FieldValue::Null,
+                Arc::from("filename") => "src/lib.rs".into(),
+            },
+            btreemap! {
+                Arc::from("module_path") => vec!["impl_locations", "traits"].into(),
+                Arc::from("trait") => "Area".into(),
+                Arc::from("filename") => "src/lib.rs".into(),
+            },
+            btreemap! {
+                Arc::from("module_path") => vec!["impl_locations"].into(),
+                Arc::from("trait") => "Display".into(),
+                Arc::from("filename") => "src/lib.rs".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn effective_deprecation() {
+    let path = "./localdata/test_data/effective_deprecation/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            name @filter(op: "one_of", value: ["$names"]) @output
+            is_effectively_deprecated @output
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "names" => vec!["inside", "InOld", "fine", "field", "method", "new_method", "x"],
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "InOld".into(),
+                Arc::from("is_effectively_deprecated") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "field".into(),
+                Arc::from("is_effectively_deprecated") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "fine".into(),
+                Arc::from("is_effectively_deprecated") => false.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "inside".into(),
+                Arc::from("is_effectively_deprecated") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "method".into(),
+                Arc::from("is_effectively_deprecated") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "new_method".into(),
+                Arc::from("is_effectively_deprecated") => false.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "x".into(),
+                Arc::from("is_effectively_deprecated") => true.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn effective_visibility() {
+    let path = "./localdata/test_data/effective_visibility/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            name @filter(op: "one_of", value: ["$names"]) @output
+            visibility_limit @output
+            effective_visibility @output
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "names" => vec![
+            "Visible",
+            "visible_method",
+            "Reexported",
+            "Stranded",
+            "stranded_method",
+            "crate_function",
+            "private_function",
+        ],
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Reexported".into(),
+                Arc::from("visibility_limit") => "public".into(),
+                Arc::from("effective_visibility") => "public".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Stranded".into(),
+                Arc::from("visibility_limit") => "public".into(),
+                Arc::from("effective_visibility") => "unreachable".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Visible".into(),
+                Arc::from("visibility_limit") => "public".into(),
+                Arc::from("effective_visibility") => "public".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "crate_function".into(),
+                Arc::from("visibility_limit") => "crate".into(),
+                Arc::from("effective_visibility") => "crate".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "private_function".into(),
+                Arc::from("visibility_limit") => "default".into(),
+                Arc::from("effective_visibility") => "private".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "stranded_method".into(),
+                Arc::from("visibility_limit") => "public".into(),
+                Arc::from("effective_visibility") => "unreachable".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "visible_method".into(),
+                Arc::from("visibility_limit") => "public".into(),
+                Arc::from("effective_visibility") => "public".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn unreachable_pub_items() {
+    let path = "./localdata/test_data/effective_visibility/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        unreachable_pub_item {
+            name @output
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Stranded".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "stranded_method".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn transitive_module_items() {
+    let path = "./localdata/test_data/module_contents/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Module {
+                name @filter(op: "=", value: ["$module"])
+
+                module_item(include_reexports: INCLUDE_REEXPORTS) {
+                    __typename @filter(op: "!=", value: ["$import"])
+                    name @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+
+    let mut expected_names = vec!["Internal", "nested", "nested_fn"];
+    for include_reexports in [false, true] {
+        // Edge parameters must be literals, so the flag is spliced into the query.
+        let query = query.replace("INCLUDE_REEXPORTS", &include_reexports.to_string());
+        let variables = btreemap! {
+            "module" => "internal",
+            "import" => "Import",
+        };
+        let mut results: Vec<_> =
+            trustfall::execute_query(&schema, adapter.clone(), &query, variables)
+                .expect("failed to run query")
+                .map(|row| row["name"].as_str().expect("no name").to_owned())
+                .collect();
+        results.sort_unstable();
+
+        if include_reexports {
+            expected_names.extend(["Reexported", "globbed_fn"]);
+            expected_names.sort_unstable();
+        }
+        assert_eq!(
+            expected_names, results,
+            "include_reexports: {include_reexports}"
+        );
+    }
+}
+
+#[test]
+fn module_contents() {
+    let path = "./localdata/test_data/module_contents/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Module {
+                name @output(name: "module")
+                is_crate_root @output
+
+                contains {
+                    name @output
+                    __typename @output(name: "type")
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| {
+        (a["module"].as_str(), a["name"].as_str()).cmp(&(b["module"].as_str(), b["name"].as_str()))
+    });
+
+    let expected: Vec<_> = [
+        ("elsewhere", false, Some("NotReexported"), "Struct"),
+        ("elsewhere", false, Some("Reexported"), "Struct"),
+        ("globbed", false, Some("globbed_fn"), "Function"),
+        ("internal", false, None, "Import"),
+        ("internal", false, None, "Import"),
+        ("internal", false, Some("Internal"), "Struct"),
+        ("internal", false, Some("nested"), "Module"),
+        ("module_contents", true, Some("elsewhere"), "Module"),
+        ("module_contents", true, Some("globbed"), "Module"),
+        ("module_contents", true, Some("internal"), "Module"),
+        ("nested", false, Some("nested_fn"), "Function"),
+    ]
+    .into_iter()
+    .map(|(module, is_crate_root, name, type_name)| {
+        btreemap! {
+            Arc::from("module") => module.into(),
+            Arc::from("is_crate_root") => is_crate_root.into(),
+            Arc::from("name") => name.into(),
+            Arc::from("type") => type_name.into(),
+        }
+    })
+    .collect();
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn module_importable_paths() {
+    let path = "./localdata/test_data/importable_item_kinds/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Module {
+                name @output
+                is_crate_root @output
+
+                importable_path {
+                    path @filter(op: "=", value: ["$path"])
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "path" => vec!["importable_item_kinds", "nested"],
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("name") => "nested".into(),
+            Arc::from("is_crate_root") => false.into(),
+        }],
+        results
+    );
+}
+
+#[test]
+fn counterparts_across_versions() {
+    let load_crate = |name: &str| -> rustdoc_types::Crate {
+        let path = format!("./localdata/test_data/{name}/rustdoc.json");
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+            .expect("failed to load rustdoc");
+        serde_json::from_str(&content).expect("failed to parse rustdoc")
+    };
+    let baseline_crate = load_crate("counterparts_baseline");
+    let current_crate = load_crate("counterparts_current");
+
+    // Both versions must have the same crate name for their paths to match.
+    let baseline = IndexedCrate::builder(&baseline_crate)
+        .with_crate_name("counterparts")
+        .build();
+    let current = IndexedCrate::builder(&current_crate)
+        .with_crate_name("counterparts")
+        .build();
+    let adapter = RustdocAdapter::new(&current, Some(&baseline));
+
+    let query = r#"
+{
+    CrateDiff {
+        current {
+            item {
+                ... on ImplOwner {
+                    name @output
+                    __typename @output(name: "current_kind")
+
+                    counterpart {
+                        __typename @output(name: "baseline_kind")
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Circle".into(),
+                Arc::from("current_kind") => "Struct".into(),
+                Arc::from("baseline_kind") => "Struct".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Kind".into(),
+                Arc::from("current_kind") => "Enum".into(),
+                Arc::from("baseline_kind") => "Struct".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Moved".into(),
+                Arc::from("current_kind") => "Struct".into(),
+                Arc::from("baseline_kind") => "Struct".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn crate_package_metadata() {
+    let path = "./localdata/test_data/effective_visibility/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::builder(&crate_)
+        .with_package_metadata(
+            PackageMetadata::new()
+                .with_name("effective-visibility")
+                .with_version("0.1.0")
+                .with_repository("https://example.com/effective-visibility")
+                .with_rust_version("1.68")
+                .with_description("Tests for effective visibility."),
+        )
+        .build();
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        package_name @output
+        package_version @output
+        package_repository @output
+        package_license @output
+        package_rust_version @output
+        package_description @output
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("package_name") => "effective-visibility".into(),
+            Arc::from("package_version") => "0.1.0".into(),
+            Arc::from("package_repository") => "https://example.com/effective-visibility".into(),
+            Arc::from("package_license") => FieldValue::Null,
+            Arc::from("package_rust_version") => "1.68".into(),
+            Arc::from("package_description") => "Tests for effective visibility.".into(),
+        }],
+        results
+    );
+}
+
+#[test]
+fn package_features() {
+    let path = "./localdata/test_data/effective_visibility/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::builder(&crate_)
+        .with_package_metadata(
+            PackageMetadata::new()
+                .with_feature("default", ["std"])
+                .with_feature("std", ["serde?/std"])
+                .with_feature("serde", ["dep:serde"])
+                .with_feature("full", ["std", "serde", "regex/unicode"])
+                .with_optional_dependency("serde")
+                .with_optional_dependency("regex"),
+        )
+        .build();
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        feature {
+            name @output
+            enables @output
+
+            enabled_feature @fold {
+                name @output(name: "enabled_feature")
+            }
+            optional_dependency @fold {
+                name @output(name: "optional_dependency")
+
+                enabling_feature @fold {
+                    name @output(name: "enabling_feature")
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "default".into(),
+                Arc::from("enables") => vec!["std"].into(),
+                Arc::from("enabled_feature") => vec!["std"].into(),
+                Arc::from("optional_dependency") => Vec::<&str>::new().into(),
+                Arc::from("enabling_feature") => Vec::<FieldValue>::new().into(),
+            },
+            btreemap! {
+                Arc::from("name") => "full".into(),
+                Arc::from("enables") => vec!["std", "serde", "regex/unicode"].into(),
+                Arc::from("enabled_feature") => vec!["serde", "std"].into(),
+                Arc::from("optional_dependency") => vec!["regex"].into(),
+                Arc::from("enabling_feature") => vec![vec!["full"]].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "serde".into(),
+                Arc::from("enables") => vec!["dep:serde"].into(),
+                Arc::from("enabled_feature") => Vec::<&str>::new().into(),
+                Arc::from("optional_dependency") => vec!["serde"].into(),
+                Arc::from("enabling_feature") => vec![vec!["serde"]].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "std".into(),
+                Arc::from("enables") => vec!["serde?/std"].into(),
+                Arc::from("enabled_feature") => Vec::<&str>::new().into(),
+                Arc::from("optional_dependency") => Vec::<&str>::new().into(),
+                Arc::from("enabling_feature") => Vec::<FieldValue>::new().into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn item_required_features() {
+    let path = "./localdata/test_data/feature_gated_items/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::builder(&crate_)
+        .with_package_metadata(
+            PackageMetadata::new()
+                .with_feature("std", ["alloc"])
+                .with_feature("alloc", Vec::<String>::new())
+                .with_feature("serde", Vec::<String>::new()),
+        )
+        .build();
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            name @output
+                @filter(op: "one_of", value: ["$names"])
+
+            requires_feature @fold {
+                name @output(name: "feature")
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "names" => vec!["serde_support", "Serializer", "StdAndAlloc", "StdOrAlloc", "Ungated"],
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Serializer".into(),
+                Arc::from("feature") => vec!["serde"].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "StdAndAlloc".into(),
+                Arc::from("feature") => vec!["alloc", "std"].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "StdOrAlloc".into(),
+                Arc::from("feature") => Vec::<&str>::new().into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Ungated".into(),
+                Arc::from("feature") => Vec::<&str>::new().into(),
+            },
+            btreemap! {
+                Arc::from("name") => "serde_support".into(),
+                Arc::from("feature") => vec!["serde"].into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn function_abis() {
+    let path = "./localdata/test_data/function_abis/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Function {
+                name @output
+                abi @output
+                abi_unwind @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "c_abi".into(),
+                Arc::from("abi") => "C".into(),
+                Arc::from("abi_unwind") => false.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "c_unwind_abi".into(),
+                Arc::from("abi") => "C".into(),
+                Arc::from("abi_unwind") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "foreign_c_unwind".into(),
+                Arc::from("abi") => "C".into(),
+                Arc::from("abi_unwind") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "rust_abi".into(),
+                Arc::from("abi") => "Rust".into(),
+                Arc::from("abi_unwind") => false.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "system_unwind_abi".into(),
+                Arc::from("abi") => "system".into(),
+                Arc::from("abi_unwind") => true.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn all_macros() {
+    let path = "./localdata/test_data/proc_macros/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        macro {
+            name @output
+            kind @output
+            __typename @output(name: "type")
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Builder".into(),
+                Arc::from("kind") => "derive".into(),
+                Arc::from("type") => "ProcMacro".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "helper".into(),
+                Arc::from("kind") => "declarative".into(),
+                Arc::from("type") => "DeclarativeMacro".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "make_answer".into(),
+                Arc::from("kind") => "function-like".into(),
+                Arc::from("type") => "ProcMacro".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "route".into(),
+                Arc::from("kind") => "attribute".into(),
+                Arc::from("type") => "ProcMacro".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn declarative_macro_exports() {
+    let path = "./localdata/test_data/declarative_macros/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on DeclarativeMacro {
+                name @output
+                exported @output
+                local_inner_macros @output
+
+                importable_path @fold {
+                    path @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "exported".into(),
+                Arc::from("exported") => true.into(),
+                Arc::from("local_inner_macros") => false.into(),
+                Arc::from("path") => vec![vec!["declarative_macros", "exported"]].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "exported_local_inner".into(),
+                Arc::from("exported") => true.into(),
+                Arc::from("local_inner_macros") => true.into(),
+                Arc::from("path") => vec![vec!["declarative_macros", "exported_local_inner"]].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "local_helper".into(),
+                Arc::from("exported") => false.into(),
+                Arc::from("local_inner_macros") => false.into(),
+                Arc::from("path") => Vec::<Vec<&str>>::new().into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn notable_traits() {
+    let path = "./localdata/test_data/notable_traits/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Trait {
+                name @output
+                notable_trait @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "AlsoNotable".into(),
+                Arc::from("notable_trait") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Notable".into(),
+                Arc::from("notable_trait") => true.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Ordinary".into(),
+                Arc::from("notable_trait") => false.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn public_reexports() {
+    let path = "./localdata/test_data/public_reexports/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    PublicReexport {
+        source @output
+        imported_as @output
+        glob @output
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["source"].as_str().cmp(&b["source"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("source") => "inner::Bar".into(),
+                Arc::from("imported_as") => "Baz".into(),
+                Arc::from("glob") => false.into(),
+            },
+            btreemap! {
+                Arc::from("source") => "inner::Foo".into(),
+                Arc::from("imported_as") => "Foo".into(),
+                Arc::from("glob") => false.into(),
+            },
+            btreemap! {
+                Arc::from("source") => "inner::shapes".into(),
+                Arc::from("imported_as") => "shapes".into(),
+                Arc::from("glob") => true.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn trait_bounded_functions() {
+    let path = "./localdata/test_data/trait_bounded_functions/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Trait {
+                name @output
+
+                bounded_function {
+                    name @output(name: "function")
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| {
+        a["name"]
+            .as_str()
+            .cmp(&b["name"].as_str())
+            .then_with(|| a["function"].as_str().cmp(&b["function"].as_str()))
+    });
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Named".into(),
+                Arc::from("function") => "describe".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Named".into(),
+                Arc::from("function") => "label".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Shape".into(),
+                Arc::from("function") => "draw".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Shape".into(),
+                Arc::from("function") => "label".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Shape".into(),
+                Arc::from("function") => "paint".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn trait_foreign_impls() {
+    let path = "./localdata/test_data/foreign_type_impls/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Trait {
+                name @output
+
+                foreign_impl {
+                    self_type {
+                        display_name @output(name: "self_type")
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| {
+        a["name"]
+            .as_str()
+            .cmp(&b["name"].as_str())
+            .then_with(|| a["self_type"].as_str().cmp(&b["self_type"].as_str()))
+    });
+
+    // `Local` lists its own impl, and `Marker` only has a blanket impl.
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "Describe".into(),
+                Arc::from("self_type") => "String".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Describe".into(),
+                Arc::from("self_type") => "Vec<T>".into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn associated_constant_values() {
+    let path = "./localdata/test_data/associated_constants/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on AssociatedConstant {
+                name @output
+                default @output
+                int_value @output
+                float_value @output
+                bool_value @output
+                str_value @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| {
+        a["name"]
+            .as_str()
+            .cmp(&b["name"].as_str())
+            .then_with(|| a["default"].as_str().cmp(&b["default"].as_str()))
+    });
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "ENABLED".into(),
+                Arc::from("default") => "true".into(),
+                Arc::from("int_value") => FieldValue::Null,
+                Arc::from("float_value") => FieldValue::Null,
+                Arc::from("bool_value") => true.into(),
+                Arc::from("str_value") => FieldValue::Null,
+            },
+            btreemap! {
+                Arc::from("name") => "LARGEST".into(),
+                Arc::from("default") => "u8::MAX".into(),
+                Arc::from("int_value") => FieldValue::Null,
+                Arc::from("float_value") => FieldValue::Null,
+                Arc::from("bool_value") => FieldValue::Null,
+                Arc::from("str_value") => FieldValue::Null,
+            },
+            btreemap! {
+                Arc::from("name") => "MAX_ITEMS".into(),
+                Arc::from("default") => FieldValue::Null,
+                Arc::from("int_value") => FieldValue::Null,
+                Arc::from("float_value") => FieldValue::Null,
+                Arc::from("bool_value") => FieldValue::Null,
+                Arc::from("str_value") => FieldValue::Null,
+            },
+            btreemap! {
+                Arc::from("name") => "MAX_ITEMS".into(),
+                Arc::from("default") => "4 * 1024".into(),
+                Arc::from("int_value") => 4096i64.into(),
+                Arc::from("float_value") => FieldValue::Null,
+                Arc::from("bool_value") => FieldValue::Null,
+                Arc::from("str_value") => FieldValue::Null,
+            },
+            btreemap! {
+                Arc::from("name") => "NAME".into(),
+                Arc::from("default") => "\"config\"".into(),
+                Arc::from("int_value") => FieldValue::Null,
+                Arc::from("float_value") => FieldValue::Null,
+                Arc::from("bool_value") => FieldValue::Null,
+                Arc::from("str_value") => "config".into(),
+            },
+            btreemap! {
+                Arc::from("name") => "OFFSET".into(),
+                Arc::from("default") => "-3".into(),
+                Arc::from("int_value") => (-3i64).into(),
+                Arc::from("float_value") => FieldValue::Null,
+                Arc::from("bool_value") => FieldValue::Null,
+                Arc::from("str_value") => FieldValue::Null,
+            },
+            btreemap! {
+                Arc::from("name") => "RATIO".into(),
+                Arc::from("default") => "1.5".into(),
+                Arc::from("int_value") => FieldValue::Null,
+                Arc::from("float_value") => FieldValue::Float64(1.5),
+                Arc::from("bool_value") => FieldValue::Null,
+                Arc::from("str_value") => FieldValue::Null,
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn hidden_reexports_and_targets() {
+    let path = "./localdata/test_data/hidden_reexports/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let paths_query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                importable_path {
+                    path @output
+                    reexport_doc_hidden @output
+                    target_doc_hidden @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+    let mut results: Vec<_> =
+        trustfall::execute_query(&schema, Rc::new(adapter), paths_query, variables.clone())
+            .expect("failed to run query")
+            .collect();
+    results.sort_unstable_by_key(|row| format!("{:?}", row["path"]));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("path") => vec!["hidden_reexports", "Hidden"].into(),
+                Arc::from("reexport_doc_hidden") => false.into(),
+                Arc::from("target_doc_hidden") => true.into(),
+            },
+            btreemap! {
+                Arc::from("path") => vec!["hidden_reexports", "HiddenAlias"].into(),
+                Arc::from("reexport_doc_hidden") => true.into(),
+                Arc::from("target_doc_hidden") => false.into(),
+            },
+            btreemap! {
+                Arc::from("path") => vec!["hidden_reexports", "Visible"].into(),
+                Arc::from("reexport_doc_hidden") => false.into(),
+                Arc::from("target_doc_hidden") => false.into(),
+            },
+        ],
+        results
+    );
+
+    let imports_query = r#"
+{
+    Crate {
+        item {
+            ... on Import {
+                imported_as @output
+                doc_hidden @output
+                target_doc_hidden @output
+            }
+        }
+    }
+}
+"#;
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+    let mut results: Vec<_> =
+        trustfall::execute_query(&schema, Rc::new(adapter), imports_query, variables)
+            .expect("failed to run query")
+            .collect();
+    results.sort_unstable_by(|a, b| a["imported_as"].as_str().cmp(&b["imported_as"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("imported_as") => "Hidden".into(),
+                Arc::from("doc_hidden") => false.into(),
+                Arc::from("target_doc_hidden") => true.into(),
+            },
+            btreemap! {
+                Arc::from("imported_as") => "HiddenAlias".into(),
+                Arc::from("doc_hidden") => true.into(),
+                Arc::from("target_doc_hidden") => false.into(),
+            },
+            btreemap! {
+                Arc::from("imported_as") => "Visible".into(),
+                Arc::from("doc_hidden") => false.into(),
+                Arc::from("target_doc_hidden") => false.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn function_arity() {
+    let path = "./localdata/test_data/function_arity/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Function {
+                name @output
+                param_count @output
+                generic_type_param_count @output
+                lifetime_param_count @output
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "add".into(),
+                Arc::from("param_count") => 2u64.into(),
+                Arc::from("generic_type_param_count") => 0u64.into(),
+                Arc::from("lifetime_param_count") => 0u64.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "convert".into(),
+                Arc::from("param_count") => 1u64.into(),
+                Arc::from("generic_type_param_count") => 1u64.into(),
+                Arc::from("lifetime_param_count") => 0u64.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "longest".into(),
+                Arc::from("param_count") => 2u64.into(),
+                Arc::from("generic_type_param_count") => 0u64.into(),
+                Arc::from("lifetime_param_count") => 1u64.into(),
+            },
+            btreemap! {
+                Arc::from("name") => "nullary".into(),
+                Arc::from("param_count") => 0u64.into(),
+                Arc::from("generic_type_param_count") => 0u64.into(),
+                Arc::from("lifetime_param_count") => 0u64.into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn importable_path_namespaces() {
+    let path = "./localdata/test_data/type_and_value_with_matching_names/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on KIND {
+                name @filter(op: "=", value: ["$name"])
+                __typename @output(name: "kind")
+
+                importable_path {
+                    path @output
+                    namespaces @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables = btreemap! {
+        "name" => "Foo",
+    };
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+
+    // `Importable` isn't a subtype of `Item`, so query each importable kind named `Foo`.
+    let mut results: Vec<_> = ["Function", "Struct"]
+        .into_iter()
+        .flat_map(|kind| {
+            let kind_query = query.replace("KIND", kind);
+            trustfall::execute_query(&schema, adapter.clone(), &kind_query, variables.clone())
+                .expect("failed to run query")
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    results.sort_unstable_by_key(|row| format!("{:?}", (&row["kind"], &row["path"])));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("kind") => "Function".into(),
+                Arc::from("path") => vec!["type_and_value_with_matching_names", "Foo"].into(),
+                Arc::from("namespaces") => vec!["value"].into(),
+            },
+            btreemap! {
+                Arc::from("kind") => "Function".into(),
+                Arc::from("path") => vec!["type_and_value_with_matching_names", "nested", "Foo"].into(),
+                Arc::from("namespaces") => vec!["value"].into(),
+            },
+            btreemap! {
+                Arc::from("kind") => "Struct".into(),
+                Arc::from("path") => vec!["type_and_value_with_matching_names", "Foo"].into(),
+                Arc::from("namespaces") => vec!["type"].into(),
+            },
+            btreemap! {
+                Arc::from("kind") => "Struct".into(),
+                Arc::from("path") => vec!["type_and_value_with_matching_names", "nested", "Foo"].into(),
+                Arc::from("namespaces") => vec!["type"].into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn constants() {
+    let path = "./localdata/test_data/constants/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Constant {
+                name @output
+                type_name @output
+                is_associated @output
+                is_literal @output
+                int_value @output
+                str_value @output
+
+                importable_path {
+                    path @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+    let mut results: Vec<_> = trustfall::execute_query(&schema, adapter.clone(), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "GREETING".into(),
+                Arc::from("type_name") => "&'static str".into(),
+                Arc::from("is_associated") => false.into(),
+                Arc::from("is_literal") => true.into(),
+                Arc::from("int_value") => FieldValue::Null,
+                Arc::from("str_value") => "hello".into(),
+                Arc::from("path") => vec!["constants", "GREETING"].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "SIZE".into(),
+                Arc::from("type_name") => "usize".into(),
+                Arc::from("is_associated") => false.into(),
+                Arc::from("is_literal") => false.into(),
+                Arc::from("int_value") => 4096i64.into(),
+                Arc::from("str_value") => FieldValue::Null,
+                Arc::from("path") => vec!["constants", "SIZE"].into(),
+            },
+        ],
+        results
+    );
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                inherent_impl {
+                    associated_constant {
+                        name @output
+                        type_name @output
+                        is_associated @output
+                        int_value @output
+
+                        raw_type {
+                            name @output(name: "raw_type")
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+    let results: Vec<_> = trustfall::execute_query(&schema, adapter, query, variables)
+        .expect("failed to run query")
+        .collect();
+
+    assert_eq!(
+        vec![btreemap! {
+            Arc::from("name") => "MAX".into(),
+            Arc::from("type_name") => "u32".into(),
+            Arc::from("is_associated") => true.into(),
+            Arc::from("int_value") => 10i64.into(),
+            Arc::from("raw_type") => "u32".into(),
+        }],
+        results
+    );
+}
+
+#[test]
+fn statics() {
+    let path = "./localdata/test_data/statics/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Static {
+                name @output
+                mutable @output
+                type_name @output
+
+                raw_type {
+                    name @output(name: "raw_type")
+                }
+
+                importable_path {
+                    path @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "COUNTER".into(),
+                Arc::from("mutable") => true.into(),
+                Arc::from("type_name") => "u64".into(),
+                Arc::from("raw_type") => "u64".into(),
+                Arc::from("path") => vec!["statics", "COUNTER"].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "LIMIT".into(),
+                Arc::from("mutable") => false.into(),
+                Arc::from("type_name") => "u32".into(),
+                Arc::from("raw_type") => "u32".into(),
+                Arc::from("path") => vec!["statics", "LIMIT"].into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn unions() {
+    let path = "./localdata/test_data/unions/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on Union {
+                name @output
+                fields_stripped @output
+                generic_type_param_count @output
+
+                field @fold {
+                    name @output(name: "field")
+                }
+                inherent_impl @fold {
+                    method {
+                        name @output(name: "method")
+                    }
+                }
+                trait_impl(trait: "Copy") @fold {
+                    implemented_trait {
+                        name @output(name: "copy_trait")
+                    }
+                }
+                importable_path {
+                    path @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "IntOrFloat".into(),
+                Arc::from("fields_stripped") => false.into(),
+                Arc::from("generic_type_param_count") => 0u64.into(),
+                Arc::from("field") => vec!["int", "float"].into(),
+                Arc::from("method") => vec!["to_bits"].into(),
+                Arc::from("copy_trait") => vec!["Copy"].into(),
+                Arc::from("path") => vec!["unions", "IntOrFloat"].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "MaybeValue".into(),
+                Arc::from("fields_stripped") => false.into(),
+                Arc::from("generic_type_param_count") => 1u64.into(),
+                Arc::from("field") => vec!["value", "empty"].into(),
+                Arc::from("method") => Vec::<String>::new().into(),
+                Arc::from("copy_trait") => Vec::<String>::new().into(),
+                Arc::from("path") => vec!["unions", "MaybeValue"].into(),
+            },
+        ],
+        results
+    );
+}
+
+#[test]
+fn trait_aliases() {
+    let path = "./localdata/test_data/trait_aliases/rustdoc.json";
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
+        .expect("failed to load rustdoc");
+
+    let crate_ = serde_json::from_str(&content).expect("failed to parse rustdoc");
+    let indexed_crate = IndexedCrate::new(&crate_);
+    let adapter = RustdocAdapter::new(&indexed_crate, None);
+
+    let query = r#"
+{
+    Crate {
+        item {
+            ... on TraitAlias {
+                name @output
+                lifetime_bound @output
+                generic_type_param_count @output
+
+                trait_bound @fold {
+                    name @output(name: "bound")
+                }
+                importable_path {
+                    path @output
+                }
+            }
+        }
+    }
+}
+"#;
+    let variables: BTreeMap<&str, &str> = BTreeMap::new();
+
+    let schema =
+        Schema::parse(include_str!("../rustdoc_schema.graphql")).expect("schema failed to parse");
+    let mut results: Vec<_> = trustfall::execute_query(&schema, Rc::new(adapter), query, variables)
+        .expect("failed to run query")
+        .collect();
+    results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    assert_eq!(
+        vec![
+            btreemap! {
+                Arc::from("name") => "ConvertibleMarker".into(),
+                Arc::from("lifetime_bound") => Vec::<String>::new().into(),
+                Arc::from("generic_type_param_count") => 1u64.into(),
+                Arc::from("bound") => vec!["Into", "Marker"].into(),
+                Arc::from("path") => vec!["trait_aliases", "ConvertibleMarker"].into(),
+            },
+            btreemap! {
+                Arc::from("name") => "Shareable".into(),
+                Arc::from("lifetime_bound") => vec!["'static"].into(),
+                Arc::from("generic_type_param_count") => 0u64.into(),
+                Arc::from("bound") => vec!["Clone", "Send"].into(),
+                Arc::from("path") => vec!["trait_aliases", "Shareable"].into(),
+            },
+        ],
+        results
+    );
+}
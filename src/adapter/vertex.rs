@@ -1,13 +1,18 @@
 use std::rc::Rc;
 
 use rustdoc_types::{
-    Crate, Enum, Function, Impl, Item, Path, Span, Struct, Trait, Type, Variant, VariantKind,
+    Constant, Crate, DynTrait, Enum, Function, GenericBound, GenericParamDef, Generics, Impl,
+    Import, Item, Module, Path, Primitive, ProcMacro, Span, Static, Struct, Trait, TraitAlias,
+    TraitBoundModifier, Type, Typedef, Union, Variant, VariantKind,
 };
 use trustfall::provider::Typename;
 
 use crate::{
-    attributes::{Attribute, AttributeMetaItem},
-    IndexedCrate,
+    attributes::{
+        parse_attributes, Attribute, AttributeMetaItem, LintAttribute, StabilityAttribute,
+    },
+    indexed_crate::ImportableName,
+    IndexedCrate, PackageMetadata,
 };
 
 use super::origin::Origin;
@@ -27,34 +32,119 @@ pub enum VertexKind<'a> {
     Item(&'a Item),
     Span(&'a Span),
     Path(&'a [String]),
-    ImportablePath(Vec<&'a str>),
+    ImportablePath(ImportableName<'a>),
     RawType(&'a Type),
     Attribute(Attribute<'a>),
     AttributeMetaItem(Rc<AttributeMetaItem<'a>>),
+    LintAttribute(LintAttribute<'a>),
+    Stability(StabilityAttribute<'a>),
     ImplementedTrait(&'a Path, &'a Item),
-    FunctionParameter(&'a str),
+    TraitBound(&'a Path, &'a TraitBoundModifier),
+    GenericTypeParameter(&'a Generics, &'a GenericParamDef),
+    /// A function parameter's name and type, together with the self type of the impl
+    /// defining the function, if any.
+    FunctionParameter(&'a str, &'a Type, Option<&'a Type>),
+    FunctionLikeItem(FunctionLikeItem<'a>),
+    MethodNameConflict(&'a str, Rc<[(&'a Item, &'a Item)]>),
+    /// A package feature's name and the entries it enables, with the metadata declaring it.
+    Feature(&'a PackageMetadata, &'a str, &'a [String]),
+    /// An optional dependency's name, with the metadata declaring it.
+    OptionalDependency(&'a PackageMetadata, &'a str),
+    /// A source file's path, with the items defined in it.
+    File(&'a std::path::Path, &'a [&'a Item]),
 }
 
+/// A function-like item, together with where it is defined.
+#[derive(Debug, Clone, Copy)]
+pub struct FunctionLikeItem<'a> {
+    /// "function", "inherent_method", "trait_method" or "trait_impl_method"
+    pub(super) kind: &'static str,
+
+    pub(super) function: &'a Item,
+
+    /// The impl or trait the function is defined in, if any.
+    pub(super) owner: Option<&'a Item>,
+}
+
+/// The schema type of the vertex representing the given item.
+pub(crate) fn item_typename(item: &Item) -> &'static str {
+    match &item.inner {
+        rustdoc_types::ItemEnum::Struct(..) => "Struct",
+        rustdoc_types::ItemEnum::Enum(..) => "Enum",
+        rustdoc_types::ItemEnum::Union(..) => "Union",
+        rustdoc_types::ItemEnum::Function(..) => "Function",
+        rustdoc_types::ItemEnum::Variant(variant) => match variant.kind {
+            VariantKind::Plain => "PlainVariant",
+            VariantKind::Tuple(..) => "TupleVariant",
+            VariantKind::Struct { .. } => "StructVariant",
+        },
+        rustdoc_types::ItemEnum::StructField(..) => "StructField",
+        rustdoc_types::ItemEnum::Impl(..) => "Impl",
+        rustdoc_types::ItemEnum::Trait(..) => "Trait",
+        rustdoc_types::ItemEnum::TraitAlias(..) => "TraitAlias",
+        rustdoc_types::ItemEnum::ProcMacro(..) => "ProcMacro",
+        rustdoc_types::ItemEnum::Macro(..) => "DeclarativeMacro",
+        rustdoc_types::ItemEnum::Import(..) => "Import",
+        rustdoc_types::ItemEnum::Typedef(..) => "Typedef",
+        rustdoc_types::ItemEnum::Primitive(..) => "Primitive",
+        rustdoc_types::ItemEnum::AssocType { .. } => "AssociatedType",
+        rustdoc_types::ItemEnum::Constant(..) => "Constant",
+        rustdoc_types::ItemEnum::Static(..) => "Static",
+        rustdoc_types::ItemEnum::AssocConst { .. } => "AssociatedConstant",
+        rustdoc_types::ItemEnum::Module(..) if !is_keyword_page(item) => "Module",
+        _ => "OtherItem",
+    }
+}
+
+/// Whether the item is a keyword's documentation page, which rustdoc represents
+/// as a module with a `#[doc(keyword = "...")]` attribute.
+pub(super) fn is_keyword_page(item: &Item) -> bool {
+    matches!(item.inner, rustdoc_types::ItemEnum::Module(..))
+        && parse_attributes(&item.attrs).any(|attribute| {
+            attribute.content.base == "doc"
+                && attribute
+                    .content
+                    .arguments
+                    .iter()
+                    .flatten()
+                    .any(|argument| argument.base == "keyword")
+        })
+}
+
+/// All the types that [`item_typename`] can return. Unlike the interfaces they implement,
+/// each of these types is the runtime type of a specific set of items.
+pub(crate) const ITEM_TYPENAMES: &[&str] = &[
+    "Struct",
+    "Enum",
+    "Union",
+    "Function",
+    "PlainVariant",
+    "TupleVariant",
+    "StructVariant",
+    "StructField",
+    "Impl",
+    "Trait",
+    "TraitAlias",
+    "ProcMacro",
+    "DeclarativeMacro",
+    "Import",
+    "Typedef",
+    "Primitive",
+    "AssociatedType",
+    "Constant",
+    "Static",
+    "AssociatedConstant",
+    "Module",
+    "OtherItem",
+];
+
 impl<'a> Typename for Vertex<'a> {
     /// The name of the actual runtime type of this vertex,
     /// intended to fulfill resolution requests for the __typename property.
     #[inline]
     fn typename(&self) -> &'static str {
         match self.kind {
-            VertexKind::Item(item) => match &item.inner {
-                rustdoc_types::ItemEnum::Struct(..) => "Struct",
-                rustdoc_types::ItemEnum::Enum(..) => "Enum",
-                rustdoc_types::ItemEnum::Function(..) => "Function",
-                rustdoc_types::ItemEnum::Variant(variant) => match variant.kind {
-                    VariantKind::Plain => "PlainVariant",
-                    VariantKind::Tuple(..) => "TupleVariant",
-                    VariantKind::Struct { .. } => "StructVariant",
-                },
-                rustdoc_types::ItemEnum::StructField(..) => "StructField",
-                rustdoc_types::ItemEnum::Impl(..) => "Impl",
-                rustdoc_types::ItemEnum::Trait(..) => "Trait",
-                _ => unreachable!("unexpected item.inner for item: {item:?}"),
-            },
+            VertexKind::Item(item) => item_typename(item),
             VertexKind::Span(..) => "Span",
             VertexKind::Path(..) => "Path",
             VertexKind::ImportablePath(..) => "ImportablePath",
@@ -62,13 +152,23 @@ impl<'a> Typename for Vertex<'a> {
             VertexKind::CrateDiff(..) => "CrateDiff",
             VertexKind::Attribute(..) => "Attribute",
             VertexKind::AttributeMetaItem(..) => "AttributeMetaItem",
+            VertexKind::LintAttribute(..) => "LintAttribute",
+            VertexKind::Stability(..) => "Stability",
             VertexKind::ImplementedTrait(..) => "ImplementedTrait",
+            VertexKind::TraitBound(..) => "TraitBound",
+            VertexKind::GenericTypeParameter(..) => "GenericTypeParameter",
             VertexKind::RawType(ty) => match ty {
                 rustdoc_types::Type::ResolvedPath { .. } => "ResolvedPathType",
                 rustdoc_types::Type::Primitive(..) => "PrimitiveType",
+                rustdoc_types::Type::DynTrait(..) => "DynTraitType",
                 _ => "OtherType",
             },
             VertexKind::FunctionParameter(..) => "FunctionParameter",
+            VertexKind::FunctionLikeItem(..) => "FunctionLikeItem",
+            VertexKind::MethodNameConflict(..) => "MethodNameConflict",
+            VertexKind::Feature(..) => "Feature",
+            VertexKind::OptionalDependency(..) => "OptionalDependency",
+            VertexKind::File(..) => "File",
         }
     }
 }
@@ -89,18 +189,21 @@ impl<'a> Vertex<'a> {
         }
     }
 
-    pub(super) fn as_indexed_crate(&self) -> Option<&'a IndexedCrate<'a>> {
+    /// The indexed crate represented by a `Crate` vertex.
+    pub fn as_indexed_crate(&self) -> Option<&'a IndexedCrate<'a>> {
         match self.kind {
             VertexKind::Crate(c) => Some(c),
             _ => None,
         }
     }
 
-    pub(super) fn as_crate(&self) -> Option<&'a Crate> {
+    /// The rustdoc data of the crate represented by a `Crate` vertex.
+    pub fn as_crate(&self) -> Option<&'a Crate> {
         self.as_indexed_crate().map(|c| c.inner)
     }
 
-    pub(super) fn as_item(&self) -> Option<&'a Item> {
+    /// The rustdoc item represented by a vertex of `Item` type or any of its subtypes.
+    pub fn as_item(&self) -> Option<&'a Item> {
         match self.kind {
             VertexKind::Item(item) => Some(item),
             _ => None,
@@ -114,6 +217,20 @@ impl<'a> Vertex<'a> {
         })
     }
 
+    pub(super) fn as_trait_alias(&self) -> Option<&'a TraitAlias> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::TraitAlias(t) => Some(t),
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_union(&self) -> Option<&'a Union> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::Union(u) => Some(u),
+            _ => None,
+        })
+    }
+
     pub(super) fn as_struct_field(&self) -> Option<&'a Type> {
         self.as_item().and_then(|item| match &item.inner {
             rustdoc_types::ItemEnum::StructField(s) => Some(s),
@@ -156,7 +273,7 @@ impl<'a> Vertex<'a> {
         }
     }
 
-    pub(super) fn as_importable_path(&self) -> Option<&'_ Vec<&'a str>> {
+    pub(super) fn as_importable_path(&self) -> Option<&'_ ImportableName<'a>> {
         match &self.kind {
             VertexKind::ImportablePath(path) => Some(path),
             _ => None,
@@ -170,9 +287,46 @@ impl<'a> Vertex<'a> {
         })
     }
 
-    pub(super) fn as_function_parameter(&self) -> Option<&'a str> {
+    pub(super) fn as_function_parameter(&self) -> Option<(&'a str, &'a Type, Option<&'a Type>)> {
+        match &self.kind {
+            VertexKind::FunctionParameter(name, type_, self_type) => {
+                Some((name, type_, *self_type))
+            }
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_function_like_item(&self) -> Option<&'_ FunctionLikeItem<'a>> {
+        match &self.kind {
+            VertexKind::FunctionLikeItem(function_like) => Some(function_like),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_method_name_conflict(&self) -> Option<(&'a str, &[(&'a Item, &'a Item)])> {
         match &self.kind {
-            VertexKind::FunctionParameter(name) => Some(name),
+            VertexKind::MethodNameConflict(name, providers) => Some((name, providers)),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_feature(&self) -> Option<(&'a PackageMetadata, &'a str, &'a [String])> {
+        match self.kind {
+            VertexKind::Feature(metadata, name, enables) => Some((metadata, name, enables)),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_file(&self) -> Option<(&'a std::path::Path, &'a [&'a Item])> {
+        match self.kind {
+            VertexKind::File(path, items) => Some((path, items)),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_optional_dependency(&self) -> Option<(&'a PackageMetadata, &'a str)> {
+        match self.kind {
+            VertexKind::OptionalDependency(metadata, name) => Some((metadata, name)),
             _ => None,
         }
     }
@@ -184,6 +338,115 @@ impl<'a> Vertex<'a> {
         })
     }
 
+    pub(super) fn as_proc_macro(&self) -> Option<&'a ProcMacro> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::ProcMacro(x) => Some(x),
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_import(&self) -> Option<&'a Import> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::Import(x) => Some(x),
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_typedef(&self) -> Option<&'a Typedef> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::Typedef(x) => Some(x),
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_primitive(&self) -> Option<&'a Primitive> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::Primitive(x) => Some(x),
+            _ => None,
+        })
+    }
+
+    /// The bounds and the default (if any) of an associated type.
+    pub(super) fn as_associated_type(&self) -> Option<(&'a [GenericBound], Option<&'a Type>)> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::AssocType {
+                bounds, default, ..
+            } => Some((bounds.as_slice(), default.as_ref())),
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_associated_constant(&self) -> Option<Option<&'a str>> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::AssocConst { default, .. } => Some(default.as_deref()),
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_constant(&self) -> Option<&'a Constant> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::Constant(constant) => Some(constant),
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_module(&self) -> Option<&'a Module> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::Module(module) => Some(module),
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_static(&self) -> Option<&'a Static> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::Static(static_) => Some(static_),
+            _ => None,
+        })
+    }
+
+    /// The type of a free or associated constant, and the expression its value is evaluated from.
+    pub(super) fn as_constant_like(&self) -> Option<(&'a Type, Option<&'a str>)> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::Constant(constant) => Some((
+                &constant.type_,
+                Some(constant.value.as_deref().unwrap_or(&constant.expr)),
+            )),
+            rustdoc_types::ItemEnum::AssocConst { type_, default } => {
+                Some((type_, default.as_deref()))
+            }
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_trait_bound(&self) -> Option<(&'a Path, &'a TraitBoundModifier)> {
+        match &self.kind {
+            VertexKind::TraitBound(path, modifier) => Some((*path, *modifier)),
+            _ => None,
+        }
+    }
+
+    /// The generics of items that can have generic parameters.
+    pub(super) fn as_generics(&self) -> Option<&'a Generics> {
+        self.as_item().and_then(|item| match &item.inner {
+            rustdoc_types::ItemEnum::Struct(s) => Some(&s.generics),
+            rustdoc_types::ItemEnum::Enum(e) => Some(&e.generics),
+            rustdoc_types::ItemEnum::Union(u) => Some(&u.generics),
+            rustdoc_types::ItemEnum::Trait(t) => Some(&t.generics),
+            rustdoc_types::ItemEnum::TraitAlias(t) => Some(&t.generics),
+            rustdoc_types::ItemEnum::Function(f) => Some(&f.generics),
+            rustdoc_types::ItemEnum::Impl(i) => Some(&i.generics),
+            rustdoc_types::ItemEnum::Typedef(t) => Some(&t.generics),
+            _ => None,
+        })
+    }
+
+    pub(super) fn as_generic_type_parameter(&self) -> Option<(&'a Generics, &'a GenericParamDef)> {
+        match &self.kind {
+            VertexKind::GenericTypeParameter(generics, param) => Some((*generics, *param)),
+            _ => None,
+        }
+    }
+
     pub(super) fn as_attribute(&self) -> Option<&'_ Attribute<'a>> {
         match &self.kind {
             VertexKind::Attribute(attr) => Some(attr),
@@ -198,6 +461,20 @@ impl<'a> Vertex<'a> {
         }
     }
 
+    pub(super) fn as_lint_attribute(&self) -> Option<&'_ LintAttribute<'a>> {
+        match &self.kind {
+            VertexKind::LintAttribute(lint_attribute) => Some(lint_attribute),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_stability(&self) -> Option<&'_ StabilityAttribute<'a>> {
+        match &self.kind {
+            VertexKind::Stability(stability) => Some(stability),
+            _ => None,
+        }
+    }
+
     pub(super) fn as_raw_type(&self) -> Option<&'a rustdoc_types::Type> {
         match &self.kind {
             VertexKind::RawType(ty) => Some(*ty),
@@ -205,6 +482,13 @@ impl<'a> Vertex<'a> {
         }
     }
 
+    pub(super) fn as_dyn_trait(&self) -> Option<&'a DynTrait> {
+        match self.as_raw_type()? {
+            rustdoc_types::Type::DynTrait(dyn_trait) => Some(dyn_trait),
+            _ => None,
+        }
+    }
+
     pub(super) fn as_implemented_trait(&self) -> Option<(&'a rustdoc_types::Path, &'a Item)> {
         match &self.kind {
             VertexKind::ImplementedTrait(path, trait_item) => Some((*path, *trait_item)),
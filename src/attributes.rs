@@ -15,6 +15,224 @@ impl<'a> Attribute<'a> {
         )
     }
 
+    /// The attribute rendered in a canonical form, so the same attribute renders the same
+    /// regardless of how rustdoc spelled it. See [`AttributeMetaItem::normalized_item`].
+    pub fn normalized_attribute(&self) -> String {
+        format!(
+            "#{}[{}]",
+            if self.is_inner { "!" } else { "" },
+            self.content.normalized_item()
+        )
+    }
+
+    /// The attributes this attribute applies: the listed attributes for
+    /// `#[cfg_attr(predicate, attrs...)]`, with nested `cfg_attr`s expanded too,
+    /// and the attribute itself otherwise.
+    ///
+    /// Depending on how rustdoc was invoked, attributes applied with `cfg_attr` are
+    /// recorded either expanded or as written. Predicates can't be evaluated from
+    /// rustdoc JSON, so they are assumed to hold, as they must have if rustdoc expanded them.
+    pub fn expand_cfg_attr(self) -> Vec<Attribute<'a>> {
+        match self.content.arguments.as_deref() {
+            Some([_predicate, applied @ ..]) if self.content.base == "cfg_attr" => applied
+                .iter()
+                .flat_map(|content| {
+                    Attribute {
+                        is_inner: self.is_inner,
+                        content: Rc::clone(content),
+                    }
+                    .expand_cfg_attr()
+                })
+                .collect(),
+            _ => vec![self],
+        }
+    }
+
+    /// Whether this is a `#[deprecated]` attribute, with or without arguments.
+    pub fn is_deprecated(&self) -> bool {
+        !self.is_inner && self.content.base == "deprecated"
+    }
+
+    /// Whether this is a `#[doc(hidden)]` attribute,
+    /// possibly also carrying other `doc` arguments like `#[doc(hidden, alias = "x")]`.
+    pub fn is_doc_hidden(&self) -> bool {
+        !self.is_inner
+            && self.content.base == "doc"
+            && self
+                .content
+                .arguments
+                .iter()
+                .flatten()
+                .any(|argument| argument.raw_item == "hidden")
+    }
+
+    /// Whether this is a `#[doc(...)]` attribute that includes `notable_trait`,
+    /// which makes rustdoc highlight the trait on functions returning its implementors.
+    pub fn is_doc_notable_trait(&self) -> bool {
+        !self.is_inner
+            && self.content.base == "doc"
+            && self
+                .content
+                .arguments
+                .iter()
+                .flatten()
+                .any(|argument| argument.raw_item == "notable_trait")
+    }
+
+    /// Whether this is a `#[non_exhaustive]` attribute.
+    pub fn is_non_exhaustive(&self) -> bool {
+        !self.is_inner && self.content.base == "non_exhaustive" && self.content.arguments.is_none()
+    }
+
+    /// Whether this is a `#[repr(...)]` attribute that includes `transparent`.
+    pub fn is_repr_transparent(&self) -> bool {
+        !self.is_inner
+            && self.content.base == "repr"
+            && self
+                .content
+                .arguments
+                .iter()
+                .flatten()
+                .any(|argument| argument.raw_item == "transparent")
+    }
+
+    /// Whether this is a `#[macro_export]` attribute, with or without arguments,
+    /// making the `macro_rules!` macro it's on importable from the crate root.
+    pub fn is_macro_export(&self) -> bool {
+        !self.is_inner && self.content.base == "macro_export"
+    }
+
+    /// Whether this is a `#[macro_export(local_inner_macros)]` attribute.
+    pub fn is_macro_export_local_inner_macros(&self) -> bool {
+        self.is_macro_export()
+            && self
+                .content
+                .arguments
+                .iter()
+                .flatten()
+                .any(|argument| argument.raw_item == "local_inner_macros")
+    }
+
+    /// Whether this attribute makes the item it's on export a symbol with a stable name,
+    /// like `#[no_mangle]`, `#[export_name = "..."]`, or their `#[unsafe(...)]` forms.
+    pub fn exports_symbol(&self) -> bool {
+        let exports =
+            |item: &AttributeMetaItem<'_>| matches!(item.base, "no_mangle" | "export_name");
+        !self.is_inner
+            && (exports(&self.content)
+                || (self.content.base == "unsafe"
+                    && self
+                        .content
+                        .arguments
+                        .iter()
+                        .flatten()
+                        .any(|argument| exports(argument))))
+    }
+
+    /// The features this attribute requires to be enabled, if it's `#[cfg(...)]`
+    /// or `#[doc(cfg(...))]`, like `serde` for `#[cfg(feature = "serde")]`.
+    ///
+    /// Only features that must be enabled are included: ones in `all(...)` are,
+    /// but ones in `any(...)` or `not(...)` aren't.
+    pub fn required_features(&self) -> Vec<&'a str> {
+        let predicates: Vec<&AttributeMetaItem<'a>> = match self.content.base {
+            "cfg" => self
+                .content
+                .arguments
+                .iter()
+                .flatten()
+                .map(Rc::as_ref)
+                .collect(),
+            "doc" => self
+                .content
+                .arguments
+                .iter()
+                .flatten()
+                .filter(|argument| argument.base == "cfg")
+                .flat_map(|cfg| cfg.arguments.iter().flatten().map(Rc::as_ref))
+                .collect(),
+            _ => vec![],
+        };
+
+        let mut features = vec![];
+        for predicate in predicates {
+            predicate.collect_required_features(&mut features);
+        }
+        features
+    }
+
+    /// The lint levels set by this attribute, if it's one of
+    /// `#[allow(...)]`, `#[expect(...)]`, `#[warn(...)]`, `#[deny(...)]`, or `#[forbid(...)]`.
+    pub fn lint_attributes(&self) -> Vec<LintAttribute<'a>> {
+        let level = self.content.base;
+        if !matches!(level, "allow" | "expect" | "warn" | "deny" | "forbid") {
+            return vec![];
+        }
+        let Some(arguments) = &self.content.arguments else {
+            return vec![];
+        };
+
+        let reason = arguments
+            .iter()
+            .find(|argument| argument.base == "reason")
+            .and_then(|argument| argument.assigned_item)
+            .map(|reason| reason.trim_matches('"'));
+        arguments
+            .iter()
+            .filter(|argument| argument.assigned_item.is_none() && argument.arguments.is_none())
+            .map(|argument| LintAttribute {
+                level,
+                lint: argument.base,
+                reason,
+            })
+            .collect()
+    }
+
+    /// The stability set by this attribute, if it's `#[stable(...)]` or `#[unstable(...)]`.
+    pub fn stability(&self) -> Option<StabilityAttribute<'a>> {
+        let level = self.content.base;
+        if !matches!(level, "stable" | "unstable") {
+            return None;
+        }
+        let arguments = self.content.arguments.as_ref()?;
+
+        let argument_value = |key: &str| {
+            arguments
+                .iter()
+                .find(|argument| argument.base == key)
+                .and_then(|argument| argument.assigned_item)
+                .map(|value| value.trim_matches('"'))
+        };
+        Some(StabilityAttribute {
+            level,
+            feature: argument_value("feature"),
+            since: argument_value("since"),
+            issue: argument_value("issue"),
+            reason: argument_value("reason"),
+        })
+    }
+
+    /// The diagnostic hints set by this attribute, if it's `#[diagnostic::on_unimplemented(...)]`.
+    pub fn on_unimplemented(&self) -> Option<OnUnimplementedAttribute<'a>> {
+        if self.is_inner || self.content.base != "diagnostic::on_unimplemented" {
+            return None;
+        }
+        let arguments = self.content.arguments.as_ref()?;
+
+        let argument_values = |key: &'static str| {
+            arguments
+                .iter()
+                .filter(move |argument| argument.base == key)
+                .filter_map(|argument| argument.assigned_item)
+                .map(|value| value.trim_matches('"'))
+        };
+        Some(OnUnimplementedAttribute {
+            message: argument_values("message").next(),
+            label: argument_values("label").next(),
+            notes: argument_values("note").collect(),
+        })
+    }
+
     pub fn new(raw: &'a str) -> Self {
         let raw_trimmed = raw.trim();
         let raw_without_closing = raw_trimmed.strip_suffix(']').unwrap_or_else(|| {
@@ -45,6 +263,44 @@ because it starts with neither `#[` nor `#![`."
     }
 }
 
+/// Parse an item's attributes, expanding `cfg_attr` attributes into the attributes they apply
+/// so that attributes are recognized the same way regardless of the form rustdoc recorded them in.
+/// See [`Attribute::expand_cfg_attr`].
+pub fn parse_attributes<'a>(attrs: &'a [String]) -> impl Iterator<Item = Attribute<'a>> + 'a {
+    attrs
+        .iter()
+        .flat_map(|attr| Attribute::new(attr).expand_cfg_attr())
+}
+
+/// The level of a single lint, set by an attribute like `#[allow(missing_docs)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintAttribute<'a> {
+    pub level: &'a str,
+    pub lint: &'a str,
+    pub reason: Option<&'a str>,
+}
+
+/// The stability of an item, set by a `#[stable(...)]` or `#[unstable(...)]` attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StabilityAttribute<'a> {
+    pub level: &'a str,
+    pub feature: Option<&'a str>,
+    pub since: Option<&'a str>,
+    pub issue: Option<&'a str>,
+    pub reason: Option<&'a str>,
+}
+
+/// The diagnostic hints a trait sets with `#[diagnostic::on_unimplemented(...)]`,
+/// shown when the trait is required but not implemented.
+///
+/// Only the first `message` and `label` take effect, while every `note` is shown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OnUnimplementedAttribute<'a> {
+    pub message: Option<&'a str>,
+    pub label: Option<&'a str>,
+    pub notes: Vec<&'a str>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AttributeMetaItem<'a> {
     pub raw_item: &'a str,
@@ -71,6 +327,21 @@ impl<'a> AttributeMetaItem<'a> {
         }
     }
 
+    /// Adds the features this `cfg` predicate requires to be enabled to `features`.
+    fn collect_required_features(&self, features: &mut Vec<&'a str>) {
+        match self.base {
+            "feature" => {
+                features.extend(self.assigned_item.map(|feature| feature.trim_matches('"')))
+            }
+            "all" => {
+                for argument in self.arguments.iter().flatten() {
+                    argument.collect_required_features(features);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Tries to parse `raw` as a comma-separated sequence of `AttributeMetaItem`'s
     /// wrapped in parentheses, square brackets or curly brackets.
     fn slice_arguments(raw: &'a str) -> Option<Vec<Rc<AttributeMetaItem<'a>>>> {
@@ -129,6 +400,33 @@ impl<'a> AttributeMetaItem<'a> {
         Some(arguments)
     }
 
+    /// The meta item rendered in a canonical form: whitespace outside string literals
+    /// is collapsed, `=` is surrounded by single spaces, and arguments are wrapped
+    /// in parentheses and separated by `", "`.
+    ///
+    /// The arguments of `repr` are sorted, since their order doesn't matter
+    /// and differs between rustdoc versions. Other arguments keep their order.
+    pub fn normalized_item(&self) -> String {
+        let mut rendered = collapse_whitespace(self.base);
+        if let Some(assigned_item) = self.assigned_item {
+            rendered.push_str(" = ");
+            rendered.push_str(&collapse_whitespace(assigned_item));
+        }
+        if let Some(arguments) = &self.arguments {
+            let mut rendered_arguments: Vec<String> = arguments
+                .iter()
+                .map(|argument| argument.normalized_item())
+                .collect();
+            if self.base == "repr" {
+                rendered_arguments.sort_unstable();
+            }
+            rendered.push('(');
+            rendered.push_str(&rendered_arguments.join(", "));
+            rendered.push(')');
+        }
+        rendered
+    }
+
     pub fn new(raw: &'a str) -> Self {
         let raw_trimmed = raw.trim();
 
@@ -165,11 +463,40 @@ impl<'a> AttributeMetaItem<'a> {
     }
 }
 
+/// Trims the string and replaces each run of whitespace outside string literals with one space.
+fn collapse_whitespace(raw: &str) -> String {
+    let mut collapsed = String::with_capacity(raw.len());
+    let mut previous_is_escape = false;
+    let mut inside_string_literal = false;
+    let mut pending_space = false;
+
+    for c in raw.trim().chars() {
+        if c.is_whitespace() && !inside_string_literal {
+            pending_space = true;
+            continue;
+        }
+        if pending_space {
+            collapsed.push(' ');
+            pending_space = false;
+        }
+        if c == '"' && !previous_is_escape {
+            inside_string_literal = !inside_string_literal;
+        }
+        previous_is_escape = c == '\\' && !previous_is_escape;
+        collapsed.push(c);
+    }
+
+    collapsed
+}
+
 #[cfg(test)]
 mod tests {
     use std::rc::Rc;
 
-    use super::{Attribute, AttributeMetaItem};
+    use super::{
+        parse_attributes, Attribute, AttributeMetaItem, LintAttribute, OnUnimplementedAttribute,
+        StabilityAttribute,
+    };
 
     #[test]
     fn attribute_simple_inner() {
@@ -319,6 +646,251 @@ mod tests {
         )
     }
 
+    #[test]
+    fn attribute_doc_hidden() {
+        assert!(Attribute::new("#[doc(hidden)]").is_doc_hidden());
+        assert!(Attribute::new("#[doc(alias = \"x\", hidden)]").is_doc_hidden());
+        assert!(!Attribute::new("#[doc = \"hidden\"]").is_doc_hidden());
+        assert!(!Attribute::new("#[doc(alias = \"hidden\")]").is_doc_hidden());
+        assert!(!Attribute::new("#![doc(hidden)]").is_doc_hidden());
+    }
+
+    #[test]
+    fn attribute_non_exhaustive() {
+        assert!(Attribute::new("#[non_exhaustive]").is_non_exhaustive());
+        assert!(!Attribute::new("#![non_exhaustive]").is_non_exhaustive());
+        assert!(!Attribute::new("#[doc(non_exhaustive)]").is_non_exhaustive());
+    }
+
+    #[test]
+    fn attribute_repr_transparent() {
+        assert!(Attribute::new("#[repr(transparent)]").is_repr_transparent());
+        assert!(!Attribute::new("#[repr(C)]").is_repr_transparent());
+        assert!(!Attribute::new("#[doc(transparent)]").is_repr_transparent());
+    }
+
+    #[test]
+    fn attribute_doc_notable_trait() {
+        assert!(Attribute::new("#[doc(notable_trait)]").is_doc_notable_trait());
+        assert!(Attribute::new("#[doc(notable_trait, alias = \"x\")]").is_doc_notable_trait());
+        assert!(!Attribute::new("#[doc(hidden)]").is_doc_notable_trait());
+        assert!(!Attribute::new("#[notable_trait]").is_doc_notable_trait());
+    }
+
+    #[test]
+    fn attribute_exports_symbol() {
+        assert!(Attribute::new("#[no_mangle]").exports_symbol());
+        assert!(Attribute::new("#[export_name = \"foo\"]").exports_symbol());
+        assert!(Attribute::new("#[unsafe(no_mangle)]").exports_symbol());
+        assert!(Attribute::new("#[unsafe(export_name = \"foo\")]").exports_symbol());
+        assert!(!Attribute::new("#[inline]").exports_symbol());
+        assert!(!Attribute::new("#[doc(alias = \"no_mangle\")]").exports_symbol());
+    }
+
+    #[test]
+    fn attribute_macro_export() {
+        let plain = Attribute::new("#[macro_export]");
+        assert!(plain.is_macro_export());
+        assert!(!plain.is_macro_export_local_inner_macros());
+
+        let local_inner = Attribute::new("#[macro_export(local_inner_macros)]");
+        assert!(local_inner.is_macro_export());
+        assert!(local_inner.is_macro_export_local_inner_macros());
+
+        assert!(!Attribute::new("#![macro_export]").is_macro_export());
+        assert!(!Attribute::new("#[macro_use]").is_macro_export());
+        assert!(!Attribute::new("#[doc(local_inner_macros)]").is_macro_export_local_inner_macros());
+    }
+
+    #[test]
+    fn attribute_normalized_spelling() {
+        for raw in [
+            "#[must_use = \"x\"]",
+            "#[must_use=\"x\"]",
+            "#[must_use   =   \"x\"]",
+        ] {
+            assert_eq!(
+                "#[must_use = \"x\"]",
+                Attribute::new(raw).normalized_attribute()
+            );
+        }
+        assert_eq!(
+            "#[cfg(all(unix, target_pointer_width = \"64\"))]",
+            Attribute::new("#[cfg(all( unix,target_pointer_width=\"64\" ))]")
+                .normalized_attribute()
+        );
+        assert_eq!(
+            "#[repr(C, align(8))]",
+            Attribute::new("#[repr(align(8), C)]").normalized_attribute()
+        );
+        assert_eq!(
+            "#[derive(Debug, Clone)]",
+            Attribute::new("#[derive(Debug,Clone)]").normalized_attribute()
+        );
+        assert_eq!(
+            "#![doc = \"two  spaces\"]",
+            Attribute::new("#![doc=\"two  spaces\"]").normalized_attribute()
+        );
+    }
+
+    #[test]
+    fn attribute_lint_levels() {
+        assert_eq!(
+            Attribute::new("#[allow(missing_docs, clippy::unwrap_used, reason = \"generated\")]")
+                .lint_attributes(),
+            vec![
+                LintAttribute {
+                    level: "allow",
+                    lint: "missing_docs",
+                    reason: Some("generated"),
+                },
+                LintAttribute {
+                    level: "allow",
+                    lint: "clippy::unwrap_used",
+                    reason: Some("generated"),
+                },
+            ]
+        );
+        assert_eq!(
+            Attribute::new("#[forbid(unsafe_code)]").lint_attributes(),
+            vec![LintAttribute {
+                level: "forbid",
+                lint: "unsafe_code",
+                reason: None,
+            }]
+        );
+        assert!(Attribute::new("#[derive(Debug)]")
+            .lint_attributes()
+            .is_empty());
+    }
+
+    #[test]
+    fn attribute_stability() {
+        assert_eq!(
+            Attribute::new("#[stable(feature = \"rust1\", since = \"1.0.0\")]").stability(),
+            Some(StabilityAttribute {
+                level: "stable",
+                feature: Some("rust1"),
+                since: Some("1.0.0"),
+                issue: None,
+                reason: None,
+            })
+        );
+        assert_eq!(
+            Attribute::new(
+                "#[unstable(feature = \"try_trait_v2\", issue = \"84277\", reason = \"new API\")]"
+            )
+            .stability(),
+            Some(StabilityAttribute {
+                level: "unstable",
+                feature: Some("try_trait_v2"),
+                since: None,
+                issue: Some("84277"),
+                reason: Some("new API"),
+            })
+        );
+        assert_eq!(Attribute::new("#[stable]").stability(), None);
+        assert_eq!(Attribute::new("#[non_exhaustive]").stability(), None);
+    }
+
+    #[test]
+    fn attribute_on_unimplemented() {
+        assert_eq!(
+            Attribute::new(
+                "#[diagnostic::on_unimplemented(message = \"not a widget\", \
+                 label = \"needs Widget\", note = \"first\", note = \"second\")]"
+            )
+            .on_unimplemented(),
+            Some(OnUnimplementedAttribute {
+                message: Some("not a widget"),
+                label: Some("needs Widget"),
+                notes: vec!["first", "second"],
+            })
+        );
+        assert_eq!(
+            Attribute::new("#[diagnostic::on_unimplemented(note = \"only a note\")]")
+                .on_unimplemented(),
+            Some(OnUnimplementedAttribute {
+                message: None,
+                label: None,
+                notes: vec!["only a note"],
+            })
+        );
+        assert_eq!(
+            Attribute::new("#[diagnostic::on_unimplemented]").on_unimplemented(),
+            None
+        );
+        assert_eq!(
+            Attribute::new("#[on_unimplemented(message = \"x\")]").on_unimplemented(),
+            None
+        );
+    }
+
+    #[test]
+    fn attribute_required_features() {
+        assert_eq!(
+            Attribute::new("#[doc(cfg(feature = \"serde\"))]").required_features(),
+            vec!["serde"]
+        );
+        assert_eq!(
+            Attribute::new("#![cfg(all(feature = \"std\", unix, all(feature = \"alloc\")))]")
+                .required_features(),
+            vec!["std", "alloc"]
+        );
+        assert_eq!(
+            Attribute::new("#[doc(cfg(any(feature = \"std\", feature = \"alloc\")))]")
+                .required_features(),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            Attribute::new("#[cfg(not(feature = \"std\"))]").required_features(),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            Attribute::new("#[cfg_attr(feature = \"serde\", derive(Serialize))]")
+                .required_features(),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn attribute_expand_cfg_attr() {
+        let expanded: Vec<_> = Attribute::new(
+            "#[cfg_attr(feature = \"std\", must_use, cfg_attr(test, deprecated(note = \"x\")))]",
+        )
+        .expand_cfg_attr()
+        .iter()
+        .map(Attribute::raw_attribute)
+        .collect();
+        assert_eq!(vec!["#[must_use]", "#[deprecated(note = \"x\")]"], expanded);
+
+        let expanded: Vec<_> = Attribute::new("#![cfg_attr(docsrs, doc(hidden))]")
+            .expand_cfg_attr()
+            .iter()
+            .map(Attribute::raw_attribute)
+            .collect();
+        assert_eq!(vec!["#![doc(hidden)]"], expanded);
+
+        // Attributes other than `cfg_attr` are left as they are.
+        assert_eq!(
+            vec![Attribute::new("#[repr(C)]")],
+            Attribute::new("#[repr(C)]").expand_cfg_attr()
+        );
+    }
+
+    #[test]
+    fn parse_attributes_expands_cfg_attr() {
+        let attrs = [
+            "#[cfg_attr(feature = \"serde\", doc(hidden))]".to_string(),
+            "#[cfg_attr(all(), repr(transparent), non_exhaustive)]".to_string(),
+            "#[cfg_attr(unix, deprecated)]".to_string(),
+        ];
+        assert!(parse_attributes(&attrs).any(|attr| attr.is_doc_hidden()));
+        assert!(parse_attributes(&attrs).any(|attr| attr.is_repr_transparent()));
+        assert!(parse_attributes(&attrs).any(|attr| attr.is_non_exhaustive()));
+        assert!(parse_attributes(&attrs).any(|attr| attr.is_deprecated()));
+    }
+
     #[test]
     fn attribute_meta_item_custom_brackets() {
         for raw_attribute in ["macro{arg1,arg2}", "macro[arg1,arg2]"] {
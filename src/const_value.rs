@@ -0,0 +1,310 @@
+/// The value of a constant's expression, for expressions simple enough to evaluate
+/// without a compiler: literals, and integer arithmetic on integer literals.
+///
+/// Expressions referring to other items, like `u8::MAX` or `OTHER_CONST + 1`,
+/// can't be evaluated and have no value.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ConstValue {
+    Int(i128),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+}
+
+impl ConstValue {
+    pub(crate) fn evaluate(expr: &str) -> Option<Self> {
+        let expr = expr.trim();
+        match expr {
+            "true" => return Some(Self::Bool(true)),
+            "false" => return Some(Self::Bool(false)),
+            _ => {}
+        }
+        if let Some(value) = parse_str_literal(expr) {
+            return Some(Self::Str(value));
+        }
+        if let Some(value) = evaluate_int_expr(expr) {
+            return Some(Self::Int(value));
+        }
+        parse_float_literal(expr).map(Self::Float)
+    }
+}
+
+const INT_SUFFIXES: &[&str] = &[
+    "i128", "u128", "isize", "usize", "i16", "u16", "i32", "u32", "i64", "u64", "i8", "u8",
+];
+
+fn parse_int_literal(literal: &str) -> Option<i128> {
+    let literal = INT_SUFFIXES
+        .iter()
+        .find_map(|suffix| literal.strip_suffix(suffix))
+        .unwrap_or(literal);
+    let (digits, radix) = if let Some(digits) = literal.strip_prefix("0x") {
+        (digits, 16)
+    } else if let Some(digits) = literal.strip_prefix("0o") {
+        (digits, 8)
+    } else if let Some(digits) = literal.strip_prefix("0b") {
+        (digits, 2)
+    } else {
+        (literal, 10)
+    };
+    let digits = digits.replace('_', "");
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    i128::from_str_radix(&digits, radix).ok()
+}
+
+fn parse_float_literal(expr: &str) -> Option<f64> {
+    let (negative, literal) = match expr.strip_prefix('-') {
+        Some(literal) => (true, literal.trim_start()),
+        None => (false, expr),
+    };
+    let literal = ["f32", "f64"]
+        .into_iter()
+        .find_map(|suffix| literal.strip_suffix(suffix))
+        .unwrap_or(literal)
+        .replace('_', "");
+    // Rust float literals start with a digit, which also rules out `inf` and `NaN`.
+    if !literal.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let value: f64 = literal.parse().ok()?;
+    Some(if negative { -value } else { value })
+}
+
+fn parse_str_literal(expr: &str) -> Option<String> {
+    if let Some(raw) = expr.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let delimiter = "#".repeat(hashes);
+        return raw
+            .strip_prefix(&delimiter)?
+            .strip_prefix('"')?
+            .strip_suffix(&delimiter)?
+            .strip_suffix('"')
+            .map(str::to_string);
+    }
+
+    let content = expr.strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                '0' => value.push('\0'),
+                '\\' => value.push('\\'),
+                '"' => value.push('"'),
+                '\'' => value.push('\''),
+                'x' => {
+                    let code: String = chars.by_ref().take(2).collect();
+                    value.push(char::from(u8::from_str_radix(&code, 16).ok()?));
+                }
+                'u' => {
+                    if chars.next()? != '{' {
+                        return None;
+                    }
+                    let code: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                    let code = u32::from_str_radix(&code.replace('_', ""), 16).ok()?;
+                    value.push(char::from_u32(code)?);
+                }
+                '\n' => {
+                    // A line continuation skips the newline and the next line's leading whitespace.
+                    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                }
+                _ => return None,
+            },
+            // An unescaped quote means this is more than a single string literal.
+            '"' => return None,
+            _ => value.push(c),
+        }
+    }
+    Some(value)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Int(&'a str),
+    Op(&'a str),
+    LeftParen,
+    RightParen,
+}
+
+fn tokenize(expr: &str) -> Option<Vec<Token<'_>>> {
+    let mut tokens = vec![];
+    let mut rest = expr.trim_start();
+    while !rest.is_empty() {
+        let token = if rest.starts_with(|c: char| c.is_ascii_digit()) {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            Token::Int(&rest[..end])
+        } else if rest.starts_with('(') {
+            Token::LeftParen
+        } else if rest.starts_with(')') {
+            Token::RightParen
+        } else if rest.starts_with("<<") || rest.starts_with(">>") {
+            Token::Op(&rest[..2])
+        } else if rest.starts_with(['+', '-', '*', '/', '%', '&', '|', '^']) {
+            Token::Op(&rest[..1])
+        } else {
+            return None;
+        };
+        let len = match token {
+            Token::Int(literal) | Token::Op(literal) => literal.len(),
+            Token::LeftParen | Token::RightParen => 1,
+        };
+        tokens.push(token);
+        rest = rest[len..].trim_start();
+    }
+    Some(tokens)
+}
+
+/// Evaluates integer arithmetic on integer literals, following Rust's operator precedence.
+///
+/// Overflow is only detected past the range of `i128`, regardless of the constant's type.
+fn evaluate_int_expr(expr: &str) -> Option<i128> {
+    let tokens = tokenize(expr)?;
+    let mut parser = IntExprParser {
+        tokens: &tokens,
+        position: 0,
+    };
+    let value = parser.parse_expr(0)?;
+    (parser.position == tokens.len()).then_some(value)
+}
+
+struct IntExprParser<'a, 'b> {
+    tokens: &'b [Token<'a>],
+    position: usize,
+}
+
+impl<'a, 'b> IntExprParser<'a, 'b> {
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.tokens.get(self.position).copied();
+        self.position += 1;
+        token
+    }
+
+    /// Binding power of a binary operator; operators that bind tighter have higher powers.
+    fn binding_power(op: &str) -> Option<u8> {
+        match op {
+            "|" => Some(1),
+            "^" => Some(2),
+            "&" => Some(3),
+            "<<" | ">>" => Some(4),
+            "+" | "-" => Some(5),
+            "*" | "/" | "%" => Some(6),
+            _ => None,
+        }
+    }
+
+    fn parse_operand(&mut self) -> Option<i128> {
+        match self.next()? {
+            Token::Int(literal) => parse_int_literal(literal),
+            Token::Op("-") => self.parse_operand()?.checked_neg(),
+            Token::LeftParen => {
+                let value = self.parse_expr(0)?;
+                (self.next()? == Token::RightParen).then_some(value)
+            }
+            Token::Op(..) | Token::RightParen => None,
+        }
+    }
+
+    fn parse_expr(&mut self, min_power: u8) -> Option<i128> {
+        let mut lhs = self.parse_operand()?;
+        while let Some(Token::Op(op)) = self.tokens.get(self.position).copied() {
+            let power = Self::binding_power(op)?;
+            if power <= min_power {
+                break;
+            }
+            self.position += 1;
+            let rhs = self.parse_expr(power)?;
+            lhs = match op {
+                "+" => lhs.checked_add(rhs)?,
+                "-" => lhs.checked_sub(rhs)?,
+                "*" => lhs.checked_mul(rhs)?,
+                "/" => lhs.checked_div(rhs)?,
+                "%" => lhs.checked_rem(rhs)?,
+                "<<" => lhs.checked_shl(u32::try_from(rhs).ok()?)?,
+                ">>" => lhs.checked_shr(u32::try_from(rhs).ok()?)?,
+                "&" => lhs & rhs,
+                "|" => lhs | rhs,
+                "^" => lhs ^ rhs,
+                _ => unreachable!("operator {op} has a binding power"),
+            };
+        }
+        Some(lhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConstValue;
+
+    #[test]
+    fn literals() {
+        assert_eq!(Some(ConstValue::Bool(true)), ConstValue::evaluate("true"));
+        assert_eq!(Some(ConstValue::Int(42)), ConstValue::evaluate("42"));
+        assert_eq!(Some(ConstValue::Int(-42)), ConstValue::evaluate("-42i32"));
+        assert_eq!(Some(ConstValue::Int(255)), ConstValue::evaluate("0xFF_u8"));
+        assert_eq!(Some(ConstValue::Int(5)), ConstValue::evaluate("0b101"));
+        assert_eq!(
+            Some(ConstValue::Int(1_000_000)),
+            ConstValue::evaluate("1_000_000")
+        );
+        assert_eq!(Some(ConstValue::Float(1.5)), ConstValue::evaluate("1.5"));
+        assert_eq!(
+            Some(ConstValue::Float(-2e3)),
+            ConstValue::evaluate("-2e3_f64")
+        );
+        assert_eq!(
+            Some(ConstValue::Str("a \"quoted\"\n\u{e9}".to_string())),
+            ConstValue::evaluate(r#""a \"quoted\"\n\u{e9}""#)
+        );
+        assert_eq!(
+            Some(ConstValue::Str(r"C:\path".to_string())),
+            ConstValue::evaluate(r###"r#"C:\path"#"###)
+        );
+    }
+
+    #[test]
+    fn integer_arithmetic() {
+        assert_eq!(
+            Some(ConstValue::Int(4096)),
+            ConstValue::evaluate("4 * 1024")
+        );
+        assert_eq!(Some(ConstValue::Int(7)), ConstValue::evaluate("1 + 2 * 3"));
+        assert_eq!(
+            Some(ConstValue::Int(9)),
+            ConstValue::evaluate("(1 + 2) * 3")
+        );
+        assert_eq!(
+            Some(ConstValue::Int(16)),
+            ConstValue::evaluate("1 << 2 + 2")
+        );
+        assert_eq!(
+            Some(ConstValue::Int(6)),
+            ConstValue::evaluate("0b0110 & 0b1110 | 0b0010")
+        );
+        assert_eq!(Some(ConstValue::Int(-1)), ConstValue::evaluate("1 - 2"));
+        assert_eq!(Some(ConstValue::Int(3600)), ConstValue::evaluate("60 * 60"));
+    }
+
+    #[test]
+    fn unevaluable_expressions() {
+        for expr in [
+            "u8::MAX",
+            "OTHER + 1",
+            "1 / 0",
+            "1 +",
+            "(1 + 2",
+            "\"a\" \"b\"",
+            "[1, 2, 3]",
+            "_",
+            "f64::NAN",
+        ] {
+            assert_eq!(None, ConstValue::evaluate(expr), "{expr}");
+        }
+    }
+}
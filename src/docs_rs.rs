@@ -0,0 +1,223 @@
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::PathBuf,
+};
+
+use rustdoc_types::Crate;
+
+use crate::loading::parse_rustdoc;
+
+const DOCS_RS_URL: &str = "https://docs.rs";
+
+/// Downloads the rustdoc JSON that docs.rs publishes for crates,
+/// so their API can be queried without building them locally.
+///
+/// Downloaded files are kept in a cache directory and reused by later fetches
+/// of the same crate version. Only exact versions like `1.0.2` are cached:
+/// version requirements like `latest` or `~1` may resolve differently over time.
+///
+/// docs.rs only has rustdoc JSON for crate versions built after it started
+/// publishing it, and only in the format versions its toolchain produced.
+/// Fetches for versions without JSON in this crate's format version fail
+/// with an error of kind [`io::ErrorKind::NotFound`].
+///
+/// Load the fetched crate into an [`IndexedCrate`](crate::IndexedCrate) to query it.
+#[derive(Debug, Clone)]
+pub struct DocsRsFetcher {
+    cache_dir: PathBuf,
+}
+
+impl DocsRsFetcher {
+    /// Create a fetcher that caches downloaded rustdoc JSON in the given directory.
+    ///
+    /// The directory is created when the first file is cached, if necessary.
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    /// Fetch the rustdoc JSON of the given crate version, from the cache if possible.
+    ///
+    /// The version may be anything docs.rs accepts, like `1.0.2`, `~1` or `latest`.
+    ///
+    /// Fetches for names that aren't valid crate names fail with an error of kind
+    /// [`io::ErrorKind::InvalidInput`].
+    pub fn fetch(&self, crate_name: &str, version: &str) -> io::Result<Crate> {
+        if !is_valid_crate_name(crate_name) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid crate name: {crate_name:?}"),
+            ));
+        }
+        if !is_exact_version(version) {
+            return decompress(self.download(crate_name, version)?);
+        }
+
+        let cache_path = self.cache_path(crate_name, version)?;
+        if !cache_path.exists() {
+            std::fs::create_dir_all(&self.cache_dir)?;
+
+            // Download into a temporary file first, so that interrupted downloads
+            // don't leave behind corrupted files in the cache.
+            let partial_path = cache_path.with_extension("partial");
+            let mut partial_file = File::create(&partial_path)?;
+            io::copy(&mut self.download(crate_name, version)?, &mut partial_file)?;
+            std::fs::rename(&partial_path, &cache_path)?;
+        }
+
+        decompress(BufReader::new(File::open(&cache_path)?))
+    }
+
+    /// The cache file for the given crate version.
+    ///
+    /// Both the name and the version become part of the file name, so they must be
+    /// a valid crate name and an exact version: anything else could escape the cache directory.
+    fn cache_path(&self, crate_name: &str, version: &str) -> io::Result<PathBuf> {
+        if !is_valid_crate_name(crate_name) || !is_exact_version(version) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("no cache file for crate {crate_name:?} version {version:?}"),
+            ));
+        }
+        Ok(self.cache_dir.join(format!(
+            "{crate_name}-{version}-v{}.json.zst",
+            rustdoc_types::FORMAT_VERSION
+        )))
+    }
+
+    /// Start downloading the zstd-compressed rustdoc JSON of the given crate version.
+    #[allow(clippy::io_other_error)] // `io::Error::other()` is newer than our MSRV
+    fn download(&self, crate_name: &str, version: &str) -> io::Result<impl io::Read> {
+        let url = format!(
+            "{DOCS_RS_URL}/crate/{crate_name}/{version}/json/{}",
+            rustdoc_types::FORMAT_VERSION
+        );
+        match ureq::get(&url).call() {
+            Ok(response) => Ok(response.into_reader()),
+            Err(ureq::Error::Status(404, _)) => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("docs.rs has no rustdoc JSON at {url}"),
+            )),
+            Err(error) => Err(io::Error::new(io::ErrorKind::Other, error)),
+        }
+    }
+}
+
+fn decompress(reader: impl io::Read) -> io::Result<Crate> {
+    parse_rustdoc(zstd::stream::read::Decoder::new(reader)?)
+}
+
+/// Whether the name is one crates.io accepts: up to 64 ASCII letters, digits, `-` and `_`,
+/// starting with a letter.
+fn is_valid_crate_name(crate_name: &str) -> bool {
+    crate_name.len() <= 64
+        && crate_name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && crate_name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_'))
+}
+
+/// Whether the version refers to exactly one crate version, like `1.0.2` or `0.1.0-alpha.1`,
+/// instead of a version requirement like `latest` or `~1`.
+///
+/// Exact versions are semver versions: three numbers, optionally followed by
+/// pre-release and build identifiers made of ASCII letters, digits and `-`.
+fn is_exact_version(version: &str) -> bool {
+    let (version, build) = match version.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (version, None),
+    };
+    let (release, pre_release) = match version.split_once('-') {
+        Some((release, pre_release)) => (release, Some(pre_release)),
+        None => (version, None),
+    };
+    let is_identifier = |part: &str| {
+        !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    };
+
+    release.split('.').count() == 3
+        && release
+            .split('.')
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+        && pre_release
+            .into_iter()
+            .chain(build)
+            .all(|identifiers| identifiers.split('.').all(is_identifier))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::load_pregenerated_rustdoc;
+
+    use super::{is_exact_version, is_valid_crate_name, DocsRsFetcher};
+
+    #[test]
+    fn exact_versions() {
+        assert!(is_exact_version("1.0.2"));
+        assert!(is_exact_version("0.1.0-alpha.1"));
+        assert!(is_exact_version("1.2.3+build.5"));
+
+        assert!(!is_exact_version("latest"));
+        assert!(!is_exact_version("~1"));
+        assert!(!is_exact_version("1.2"));
+        assert!(!is_exact_version("=1.2.3"));
+        assert!(!is_exact_version("1.2.3-beta/../.."));
+        assert!(!is_exact_version("1.2.3-.."));
+        assert!(!is_exact_version("1.2.3+"));
+    }
+
+    #[test]
+    fn valid_crate_names() {
+        assert!(is_valid_crate_name("serde"));
+        assert!(is_valid_crate_name("trustfall-rustdoc-adapter"));
+        assert!(is_valid_crate_name("tokio_util"));
+
+        assert!(!is_valid_crate_name(""));
+        assert!(!is_valid_crate_name("1password"));
+        assert!(!is_valid_crate_name("../serde"));
+        assert!(!is_valid_crate_name("serde/../../etc"));
+        assert!(!is_valid_crate_name(&"a".repeat(65)));
+    }
+
+    #[test]
+    fn fetch_rejects_invalid_input() {
+        let fetcher = DocsRsFetcher::new(std::env::temp_dir());
+
+        // Invalid names are rejected before touching the network or the cache.
+        let error = fetcher
+            .fetch("../serde", "1.0.0")
+            .expect_err("invalid crate name was accepted");
+        assert_eq!(std::io::ErrorKind::InvalidInput, error.kind());
+
+        assert!(fetcher.cache_path("serde", "1.0.0-..").is_err());
+    }
+
+    #[test]
+    fn fetch_from_cache() {
+        let cache_dir = std::env::temp_dir().join(format!(
+            "trustfall-rustdoc-adapter-{}-docs-rs-cache",
+            std::process::id()
+        ));
+        let fetcher = DocsRsFetcher::new(&cache_dir);
+
+        // Pre-populate the cache, so the fetch doesn't need the network.
+        let content = std::fs::read("./localdata/test_data/reexport/rustdoc.json")
+            .expect("failed to read rustdoc");
+        let compressed = zstd::encode_all(content.as_slice(), 0).expect("failed to compress");
+        std::fs::create_dir_all(&cache_dir).expect("failed to create cache dir");
+        let cache_path = fetcher
+            .cache_path("reexport", "0.1.0")
+            .expect("invalid cache path");
+        std::fs::write(cache_path, compressed).expect("failed to write cache file");
+
+        let crate_ = fetcher.fetch("reexport", "0.1.0");
+        std::fs::remove_dir_all(&cache_dir).expect("failed to remove cache dir");
+
+        assert_eq!(
+            load_pregenerated_rustdoc("reexport"),
+            crate_.expect("failed to fetch rustdoc")
+        );
+    }
+}
@@ -1,27 +1,112 @@
 use std::{
     borrow::Borrow,
-    collections::{BTreeSet, HashMap, HashSet},
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    path::Path,
+    sync::Arc,
 };
 
-use rustdoc_types::{Crate, GenericArgs, Id, Item, ItemEnum, Typedef, Visibility};
+use rustdoc_types::{
+    Crate, GenericArgs, GenericBound, GenericParamDefKind, Id, Import, Item, ItemEnum, ItemSummary,
+    Type, Typedef, Visibility, WherePredicate,
+};
+
+use crate::{
+    adapter::item_typename,
+    attributes::parse_attributes,
+    memo::ItemMemo,
+    verification::{verify_indexed_crate, VerificationReport},
+};
+
+type ImplIndex<'a> = HashMap<ImplEntry<'a>, Vec<(&'a Item, &'a Item)>>;
+type OwnerTraitImplIndex<'a> = HashMap<&'a Id, HashMap<String, Vec<&'a Item>>>;
 
 /// The rustdoc for a crate, together with associated indexed data to speed up common operations.
 ///
 /// Besides the parsed rustdoc, it also contains some manually-inlined `rustdoc_types::Trait`s
 /// of the most common built-in traits.
 /// This is a temporary step, until we're able to combine rustdocs of multiple crates.
+///
+/// Cloning is cheap: clones share the indexes and memoized data instead of copying them,
+/// so each thread or query can have its own clone.
 #[derive(Debug, Clone)]
 pub struct IndexedCrate<'a> {
     pub(crate) inner: &'a Crate,
 
+    /// The name to use for the crate root in paths, instead of the root module's name.
+    pub(crate) crate_name: Option<&'a str>,
+
+    /// Caller-provided metadata about the package the crate comes from.
+    pub(crate) package_metadata: PackageMetadata,
+
+    /// The crate's source files, if the caller provided the directory their paths are relative to.
+    #[cfg(feature = "source-snippets")]
+    pub(crate) source_files: Option<Arc<crate::source_snippet::SourceFiles>>,
+
+    /// Memoized results of `is_effectively_deprecated()`, which walks the enclosing items.
+    effective_deprecation_memo: Arc<ItemMemo<'a, bool>>,
+
+    /// Memoized results of `publicly_importable_paths()` for the items queried so far.
+    importable_paths_memo: Arc<ItemMemo<'a, Vec<ImportableName<'a>>>>,
+
     /// For an Id, give the list of item Ids under which it is publicly visible.
-    pub(crate) visibility_forest: HashMap<&'a Id, Vec<&'a Id>>,
+    pub(crate) visibility_forest: Arc<HashMap<&'a Id, Vec<&'a Id>>>,
 
     /// index: importable name (in any namespace) -> list of items under that name
-    pub(crate) imports_index: Option<HashMap<ImportablePath<'a>, Vec<&'a Item>>>,
+    pub(crate) imports_index: Option<Arc<HashMap<ImportablePath<'a>, Vec<&'a Item>>>>,
 
     /// index: impl owner + impl'd item name -> list of (impl itself, the named item))
-    pub(crate) impl_index: Option<HashMap<ImplEntry<'a>, Vec<(&'a Item, &'a Item)>>>,
+    ///
+    /// `None` if disabled with [`IndexedCrateBuilder::with_impl_index`].
+    pub(crate) impl_index: Option<Arc<ImplIndex<'a>>>,
+
+    /// index: trait name or full trait path (like `Debug` or `core::fmt::Debug`)
+    /// -> impls of that trait
+    pub(crate) trait_impl_index: Option<Arc<HashMap<String, Vec<&'a Item>>>>,
+
+    /// index: impl owner -> trait name or full trait path -> the owner's impls of that trait
+    pub(crate) owner_trait_impl_index: Option<Arc<OwnerTraitImplIndex<'a>>>,
+
+    /// index: id of an item defined in an impl block -> that impl
+    pub(crate) item_impl_index: Option<Arc<HashMap<&'a Id, &'a Item>>>,
+
+    /// index: (item name, item) pairs sorted by name,
+    /// so all items whose names share a prefix are adjacent to each other
+    pub(crate) sorted_name_index: Option<Arc<Vec<(&'a str, &'a Item)>>>,
+
+    /// index: lowercased item name -> items with that name in any case, sorted by id
+    ///
+    /// `None` unless enabled with [`IndexedCrateBuilder::with_case_insensitive_name_index`].
+    pub(crate) case_insensitive_name_index: Option<Arc<HashMap<String, Vec<&'a Item>>>>,
+
+    /// index: the item's type in the schema (like `Struct` or `PlainVariant`)
+    /// -> items of that type, sorted by id
+    pub(crate) kind_index: Option<Arc<HashMap<&'static str, Vec<&'a Item>>>>,
+
+    /// index: source file path -> items defined in that file, sorted by where their span starts,
+    /// with enclosing items before the items they enclose
+    ///
+    /// `None` if disabled with [`IndexedCrateBuilder::with_span_index`].
+    pub(crate) span_index: Option<Arc<HashMap<&'a Path, Vec<&'a Item>>>>,
+
+    /// index: id of an item listed in a module -> that module
+    pub(crate) parent_module_index: Option<Arc<HashMap<&'a Id, &'a Item>>>,
+
+    /// index: id of a field, variant, or trait item -> the struct, union, enum, variant,
+    /// or trait containing it
+    pub(crate) container_index: Option<Arc<HashMap<&'a Id, &'a Item>>>,
+
+    /// index: items marked `#[deprecated]`, sorted by id
+    pub(crate) deprecated_index: Option<Arc<Vec<&'a Item>>>,
+
+    /// index: trait id -> public functions and methods with a generic bound on that trait,
+    /// either on a type parameter or in a `where` clause, sorted by id.
+    /// Only functions in the visibility forest are indexed, so private helpers are left out.
+    pub(crate) bounded_function_index: Option<Arc<HashMap<&'a Id, Vec<&'a Item>>>>,
+
+    /// index: trait id -> impls of that trait for types that aren't defined in the crate,
+    /// like `impl LocalTrait for Vec<T>`, sorted by id
+    pub(crate) foreign_impl_index: Option<Arc<HashMap<&'a Id, Vec<&'a Item>>>>,
 
     /// Trait items defined in external crates are not present in the `inner: &Crate` field,
     /// even if they are implemented by a type in that crate. This also includes
@@ -36,42 +121,108 @@ pub struct IndexedCrate<'a> {
     ///
     /// A more complete future solution may generate multiple crates' rustdoc JSON
     /// and link to the external crate's trait items as necessary.
-    pub(crate) manually_inlined_builtin_traits: HashMap<Id, Item>,
+    pub(crate) manually_inlined_builtin_traits: Arc<HashMap<Id, Item>>,
 }
 
 impl<'a> IndexedCrate<'a> {
     pub fn new(crate_: &'a Crate) -> Self {
-        let mut value = Self {
-            inner: crate_,
-            visibility_forest: compute_parent_ids_for_public_items(crate_)
+        Self::builder(crate_).build()
+    }
+
+    /// Configure which indexes to build for the crate, then build them with
+    /// [`IndexedCrateBuilder::build`].
+    pub fn builder(crate_: &'a Crate) -> IndexedCrateBuilder<'a> {
+        IndexedCrateBuilder::new(crate_)
+    }
+
+    fn build(builder: IndexedCrateBuilder<'a>) -> Self {
+        let IndexedCrateBuilder {
+            crate_,
+            impl_index: build_impl_index,
+            case_insensitive_name_index: build_case_insensitive_name_index,
+            span_index: build_span_index,
+            glob_imported_hidden_items,
+            crate_name,
+            package_metadata,
+            progress_callback,
+            #[cfg(feature = "source-snippets")]
+            source_root,
+        } = builder;
+        let progress = ProgressReporter {
+            callback: progress_callback.as_ref(),
+            total_items: crate_.index.len(),
+        };
+
+        progress.report("visibility forest", 0);
+        let visibility_forest =
+            compute_parent_ids_for_public_items(crate_, glob_imported_hidden_items)
                 .into_iter()
                 .map(|(key, values)| {
                     // Ensure a consistent order, since queries can observe this order directly.
                     let mut values: Vec<_> = values.into_iter().collect();
-                    values.sort_unstable_by_key(|x| &x.0);
+                    values.sort_unstable_by(|a, b| a.stable_cmp(b));
                     (key, values)
                 })
-                .collect(),
-            manually_inlined_builtin_traits: create_manually_inlined_builtin_traits(crate_),
+                .collect();
+        progress.report("visibility forest", crate_.index.len());
+
+        let mut value = Self {
+            inner: crate_,
+            crate_name,
+            package_metadata,
+            #[cfg(feature = "source-snippets")]
+            source_files: source_root
+                .map(|root| Arc::new(crate::source_snippet::SourceFiles::new(root))),
+            effective_deprecation_memo: Arc::new(ItemMemo::new()),
+            importable_paths_memo: Arc::new(ItemMemo::new()),
+            visibility_forest: Arc::new(visibility_forest),
+            manually_inlined_builtin_traits: Arc::new(create_manually_inlined_builtin_traits(
+                crate_,
+            )),
             imports_index: None,
             impl_index: None,
+            trait_impl_index: None,
+            owner_trait_impl_index: None,
+            item_impl_index: None,
+            sorted_name_index: None,
+            case_insensitive_name_index: None,
+            kind_index: None,
+            span_index: None,
+            parent_module_index: None,
+            container_index: None,
+            deprecated_index: None,
+            bounded_function_index: None,
+            foreign_impl_index: None,
         };
 
         let mut imports_index: HashMap<ImportablePath, Vec<&Item>> =
             HashMap::with_capacity(crate_.index.len());
-        for item in crate_.index.values().filter_map(|item| {
-            matches!(
-                item.inner,
-                rustdoc_types::ItemEnum::Struct(..)
-                    | rustdoc_types::ItemEnum::StructField(..)
-                    | rustdoc_types::ItemEnum::Enum(..)
-                    | rustdoc_types::ItemEnum::Variant(..)
-                    | rustdoc_types::ItemEnum::Function(..)
-                    | rustdoc_types::ItemEnum::Impl(..)
-                    | rustdoc_types::ItemEnum::Trait(..)
-            )
-            .then_some(item)
-        }) {
+        for item in progress
+            .track("imports index", crate_.index.values())
+            .filter(|item| {
+                matches!(
+                    item.inner,
+                    rustdoc_types::ItemEnum::Struct(..)
+                        | rustdoc_types::ItemEnum::StructField(..)
+                        | rustdoc_types::ItemEnum::Enum(..)
+                        | rustdoc_types::ItemEnum::Variant(..)
+                        | rustdoc_types::ItemEnum::Function(..)
+                        | rustdoc_types::ItemEnum::Impl(..)
+                        | rustdoc_types::ItemEnum::Trait(..)
+                        | rustdoc_types::ItemEnum::ProcMacro(..)
+                        | rustdoc_types::ItemEnum::Typedef(..)
+                        | rustdoc_types::ItemEnum::Constant(..)
+                        | rustdoc_types::ItemEnum::Static(..)
+                        | rustdoc_types::ItemEnum::Union(..)
+                        | rustdoc_types::ItemEnum::Macro(..)
+                        | rustdoc_types::ItemEnum::TraitAlias(..)
+                ) || matches!(
+                    // Modules are importable too, but the crate root doesn't have a path to import.
+                    &item.inner,
+                    rustdoc_types::ItemEnum::Module(module) if !module.is_crate
+                )
+            })
+        {
             for importable_path in value.publicly_importable_names(&item.id) {
                 imports_index
                     .entry(ImportablePath::new(importable_path))
@@ -80,201 +231,1944 @@ impl<'a> IndexedCrate<'a> {
             }
         }
         let index_size = imports_index.len();
-        value.imports_index = Some(imports_index);
-
-        let mut impl_index: HashMap<ImplEntry<'a>, Vec<(&'a Item, &'a Item)>> =
-            HashMap::with_capacity(index_size);
-        for (id, impl_items) in crate_.index.iter().filter_map(|(id, item)| {
-            let impls = match &item.inner {
-                rustdoc_types::ItemEnum::Struct(s) => &s.impls,
-                rustdoc_types::ItemEnum::Enum(e) => &e.impls,
-                rustdoc_types::ItemEnum::Union(u) => &u.impls,
-                _ => return None,
-            };
+        value.imports_index = Some(Arc::new(imports_index));
+
+        if build_impl_index {
+            let mut impl_index: HashMap<ImplEntry<'a>, Vec<(&'a Item, &'a Item)>> =
+                HashMap::with_capacity(index_size);
+            for (id, impl_items) in progress
+                .track("impl index", crate_.index.iter())
+                .filter_map(|(id, item)| {
+                    let impls = match &item.inner {
+                        rustdoc_types::ItemEnum::Struct(s) => &s.impls,
+                        rustdoc_types::ItemEnum::Enum(e) => &e.impls,
+                        rustdoc_types::ItemEnum::Union(u) => &u.impls,
+                        rustdoc_types::ItemEnum::Primitive(p) => &p.impls,
+                        _ => return None,
+                    };
 
-            let impl_items = impls.iter().filter_map(|impl_id| crate_.index.get(impl_id));
+                    let impl_items = impls.iter().filter_map(|impl_id| crate_.index.get(impl_id));
 
-            Some((id, impl_items))
-        }) {
-            for impl_item in impl_items {
-                let impl_inner = match &impl_item.inner {
-                    rustdoc_types::ItemEnum::Impl(impl_inner) => impl_inner,
-                    _ => unreachable!("expected impl but got another item type: {impl_item:?}"),
-                };
-                let trait_provided_methods: BTreeSet<_> = impl_inner
-                    .provided_trait_methods
-                    .iter()
-                    .map(|x| x.as_str())
-                    .collect();
-                if let Some(trait_item) = impl_inner
-                    .trait_
-                    .as_ref()
-                    .and_then(|trait_path| crate_.index.get(&trait_path.id))
-                {
-                    if let rustdoc_types::ItemEnum::Trait(trait_item) = &trait_item.inner {
-                        for provided_item in trait_item
-                            .items
-                            .iter()
-                            .filter_map(|id| crate_.index.get(id))
-                            .filter(|item| {
-                                item.name
-                                    .as_deref()
-                                    .map(|name| trait_provided_methods.contains(name))
-                                    .unwrap_or_default()
-                            })
-                        {
-                            impl_index
-                                .entry(ImplEntry::new(
-                                    id,
-                                    provided_item
-                                        .name
+                    Some((id, impl_items))
+                })
+            {
+                for impl_item in impl_items {
+                    let impl_inner = match &impl_item.inner {
+                        rustdoc_types::ItemEnum::Impl(impl_inner) => impl_inner,
+                        _ => unreachable!("expected impl but got another item type: {impl_item:?}"),
+                    };
+                    let trait_provided_methods: BTreeSet<_> = impl_inner
+                        .provided_trait_methods
+                        .iter()
+                        .map(|x| x.as_str())
+                        .collect();
+                    if let Some(trait_item) = impl_inner
+                        .trait_
+                        .as_ref()
+                        .and_then(|trait_path| crate_.index.get(&trait_path.id))
+                    {
+                        if let rustdoc_types::ItemEnum::Trait(trait_item) = &trait_item.inner {
+                            for provided_item in trait_item
+                                .items
+                                .iter()
+                                .filter_map(|id| crate_.index.get(id))
+                                .filter(|item| {
+                                    item.name
                                         .as_deref()
-                                        .expect("item should have had a name"),
-                                ))
+                                        .map(|name| trait_provided_methods.contains(name))
+                                        .unwrap_or_default()
+                                })
+                            {
+                                impl_index
+                                    .entry(ImplEntry::new(
+                                        id,
+                                        provided_item
+                                            .name
+                                            .as_deref()
+                                            .expect("item should have had a name"),
+                                    ))
+                                    .or_default()
+                                    .push((impl_item, provided_item));
+                            }
+                        }
+                    }
+
+                    for contained_item in impl_inner
+                        .items
+                        .iter()
+                        .filter_map(|item_id| crate_.index.get(item_id))
+                    {
+                        if let Some(contained_item_name) = contained_item.name.as_deref() {
+                            impl_index
+                                .entry(ImplEntry::new(id, contained_item_name))
                                 .or_default()
-                                .push((impl_item, provided_item));
+                                .push((impl_item, contained_item));
                         }
                     }
                 }
+            }
+            value.impl_index = Some(Arc::new(impl_index));
+        }
+
+        let mut trait_impl_index: HashMap<String, Vec<&'a Item>> = HashMap::new();
+        for (impl_item, trait_path) in progress
+            .track("trait impl index", crate_.index.values())
+            .filter_map(|item| match &item.inner {
+                rustdoc_types::ItemEnum::Impl(impl_inner) => impl_inner
+                    .trait_
+                    .as_ref()
+                    .map(|trait_path| (item, trait_path)),
+                _ => None,
+            })
+        {
+            for trait_key in trait_index_keys(crate_, trait_path) {
+                trait_impl_index
+                    .entry(trait_key)
+                    .or_default()
+                    .push(impl_item);
+            }
+        }
+        value.trait_impl_index = Some(Arc::new(trait_impl_index));
+
+        let mut owner_trait_impl_index: OwnerTraitImplIndex<'a> = HashMap::new();
+        for (owner_id, impls) in progress
+            .track("owner trait impl index", crate_.index.iter())
+            .filter_map(|(id, item)| match &item.inner {
+                rustdoc_types::ItemEnum::Struct(s) => Some((id, &s.impls)),
+                rustdoc_types::ItemEnum::Enum(e) => Some((id, &e.impls)),
+                rustdoc_types::ItemEnum::Union(u) => Some((id, &u.impls)),
+                rustdoc_types::ItemEnum::Primitive(p) => Some((id, &p.impls)),
+                _ => None,
+            })
+        {
+            for (impl_item, trait_path) in impls
+                .iter()
+                .filter_map(|impl_id| crate_.index.get(impl_id))
+                .filter_map(|impl_item| match &impl_item.inner {
+                    rustdoc_types::ItemEnum::Impl(impl_inner) => impl_inner
+                        .trait_
+                        .as_ref()
+                        .map(|trait_path| (impl_item, trait_path)),
+                    _ => None,
+                })
+            {
+                let owner_impls = owner_trait_impl_index.entry(owner_id).or_default();
+                for trait_key in trait_index_keys(crate_, trait_path) {
+                    owner_impls.entry(trait_key).or_default().push(impl_item);
+                }
+            }
+        }
+        value.owner_trait_impl_index = Some(Arc::new(owner_trait_impl_index));
+
+        let mut item_impl_index: HashMap<&'a Id, &'a Item> = HashMap::new();
+        for (impl_item, impl_inner) in progress
+            .track("item impl index", crate_.index.values())
+            .filter_map(|item| match &item.inner {
+                rustdoc_types::ItemEnum::Impl(impl_inner) => Some((item, impl_inner)),
+                _ => None,
+            })
+        {
+            for contained_id in &impl_inner.items {
+                item_impl_index.insert(contained_id, impl_item);
+            }
+        }
+        value.item_impl_index = Some(Arc::new(item_impl_index));
+
+        let mut sorted_name_index: Vec<(&'a str, &'a Item)> = progress
+            .track("name index", crate_.index.values())
+            .filter_map(|item| item.name.as_deref().map(|name| (name, item)))
+            .collect();
+        // Ensure a consistent order for items with the same name,
+        // since queries can observe this order directly.
+        sorted_name_index.sort_unstable_by(|(a_name, a_item), (b_name, b_item)| {
+            a_name
+                .cmp(b_name)
+                .then_with(|| a_item.id.stable_cmp(&b_item.id))
+        });
+
+        if build_case_insensitive_name_index {
+            let mut case_insensitive_name_index: HashMap<String, Vec<&'a Item>> = HashMap::new();
+            for &(name, item) in &sorted_name_index {
+                case_insensitive_name_index
+                    .entry(name.to_lowercase())
+                    .or_default()
+                    .push(item);
+            }
+            for items in case_insensitive_name_index.values_mut() {
+                // Items whose names differ in case are interleaved, so sort them by id again.
+                items.sort_unstable_by(|a, b| a.id.stable_cmp(&b.id));
+            }
+            value.case_insensitive_name_index = Some(Arc::new(case_insensitive_name_index));
+        }
+        value.sorted_name_index = Some(Arc::new(sorted_name_index));
+
+        if build_span_index {
+            let mut span_index: HashMap<&'a Path, Vec<&'a Item>> = HashMap::new();
+            for (span, item) in progress
+                .track("span index", crate_.index.values())
+                .filter_map(|item| item.span.as_ref().map(|span| (span, item)))
+            {
+                span_index
+                    .entry(span.filename.as_path())
+                    .or_default()
+                    .push(item);
+            }
+            for items in span_index.values_mut() {
+                // Items starting at the same place are ordered from the longest span
+                // to the shortest, so enclosing items come first.
+                items.sort_unstable_by(|a_item, b_item| {
+                    let a_span = a_item
+                        .span
+                        .as_ref()
+                        .expect("item in span index without a span");
+                    let b_span = b_item
+                        .span
+                        .as_ref()
+                        .expect("item in span index without a span");
+                    a_span
+                        .begin
+                        .cmp(&b_span.begin)
+                        .then_with(|| b_span.end.cmp(&a_span.end))
+                        .then_with(|| a_item.id.stable_cmp(&b_item.id))
+                });
+            }
+            value.span_index = Some(Arc::new(span_index));
+        }
 
-                for contained_item in impl_inner
-                    .items
+        let mut kind_index: HashMap<&'static str, Vec<&'a Item>> = HashMap::new();
+        for item in progress.track("kind index", crate_.index.values()) {
+            kind_index
+                .entry(item_typename(item))
+                .or_default()
+                .push(item);
+        }
+        for items in kind_index.values_mut() {
+            // Ensure a consistent order, since queries can observe this order directly.
+            items.sort_unstable_by(|a, b| a.id.stable_cmp(&b.id));
+        }
+        value.kind_index = Some(Arc::new(kind_index));
+
+        let mut parent_module_index: HashMap<&'a Id, &'a Item> = HashMap::new();
+        for module_item in progress.track("parent module index", crate_.index.values()) {
+            if let ItemEnum::Module(module) = &module_item.inner {
+                for contained_id in &module.items {
+                    parent_module_index.insert(contained_id, module_item);
+                }
+            }
+        }
+        value.parent_module_index = Some(Arc::new(parent_module_index));
+
+        let mut container_index: HashMap<&'a Id, &'a Item> = HashMap::new();
+        for container in progress.track("container index", crate_.index.values()) {
+            let contained_ids: Box<dyn Iterator<Item = &Id>> = match &container.inner {
+                ItemEnum::Struct(struct_) => match &struct_.kind {
+                    rustdoc_types::StructKind::Unit => Box::new(std::iter::empty()),
+                    rustdoc_types::StructKind::Tuple(fields) => Box::new(fields.iter().flatten()),
+                    rustdoc_types::StructKind::Plain { fields, .. } => Box::new(fields.iter()),
+                },
+                ItemEnum::Union(union_) => Box::new(union_.fields.iter()),
+                ItemEnum::Enum(enum_) => Box::new(enum_.variants.iter()),
+                ItemEnum::Variant(variant) => match &variant.kind {
+                    rustdoc_types::VariantKind::Plain => Box::new(std::iter::empty()),
+                    rustdoc_types::VariantKind::Tuple(fields) => Box::new(fields.iter().flatten()),
+                    rustdoc_types::VariantKind::Struct { fields, .. } => Box::new(fields.iter()),
+                },
+                ItemEnum::Trait(trait_) => Box::new(trait_.items.iter()),
+                _ => continue,
+            };
+            for contained_id in contained_ids {
+                container_index.insert(contained_id, container);
+            }
+        }
+        value.container_index = Some(Arc::new(container_index));
+
+        let mut deprecated_index: Vec<&'a Item> = progress
+            .track("deprecated index", crate_.index.values())
+            .filter(|item| is_deprecated(item))
+            .collect();
+        // Ensure a consistent order, since queries can observe this order directly.
+        deprecated_index.sort_unstable_by(|a, b| a.id.stable_cmp(&b.id));
+        value.deprecated_index = Some(Arc::new(deprecated_index));
+
+        let mut bounded_function_index: HashMap<&'a Id, Vec<&'a Item>> = HashMap::new();
+        for (item, function) in progress
+            .track("bounded function index", crate_.index.values())
+            .filter_map(|item| match &item.inner {
+                ItemEnum::Function(function) if value.visibility_forest.contains_key(&item.id) => {
+                    Some((item, function))
+                }
+                _ => None,
+            })
+        {
+            let param_bounds =
+                function
+                    .generics
+                    .params
                     .iter()
-                    .filter_map(|item_id| crate_.index.get(item_id))
+                    .filter_map(|param| match &param.kind {
+                        GenericParamDefKind::Type { bounds, .. } => Some(bounds),
+                        _ => None,
+                    });
+            let where_bounds = function
+                .generics
+                .where_predicates
+                .iter()
+                .filter_map(|predicate| match predicate {
+                    WherePredicate::BoundPredicate { bounds, .. } => Some(bounds),
+                    _ => None,
+                });
+            // A function bounding the same trait more than once is only listed once for it.
+            let bounded_traits: HashSet<&'a Id> = param_bounds
+                .chain(where_bounds)
+                .flatten()
+                .filter_map(|bound| match bound {
+                    GenericBound::TraitBound { trait_, .. } => Some(&trait_.id),
+                    GenericBound::Outlives(..) => None,
+                })
+                .collect();
+            for trait_id in bounded_traits {
+                bounded_function_index
+                    .entry(trait_id)
+                    .or_default()
+                    .push(item);
+            }
+        }
+        for functions in bounded_function_index.values_mut() {
+            // Ensure a consistent order, since queries can observe this order directly.
+            functions.sort_unstable_by(|a, b| a.id.stable_cmp(&b.id));
+        }
+        value.bounded_function_index = Some(Arc::new(bounded_function_index));
+
+        // Impls for types defined in the crate are listed on those types.
+        // The remaining trait impls are for foreign types, unless they are blanket impls.
+        let owned_impls: HashSet<&'a Id> = crate_
+            .index
+            .values()
+            .filter_map(|item| match &item.inner {
+                ItemEnum::Struct(s) => Some(&s.impls),
+                ItemEnum::Enum(e) => Some(&e.impls),
+                ItemEnum::Union(u) => Some(&u.impls),
+                ItemEnum::Primitive(p) => Some(&p.impls),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let mut foreign_impl_index: HashMap<&'a Id, Vec<&'a Item>> = HashMap::new();
+        for (impl_item, trait_path) in progress
+            .track("foreign impl index", crate_.index.values())
+            .filter_map(|item| match &item.inner {
+                ItemEnum::Impl(impl_inner)
+                    if !owned_impls.contains(&item.id)
+                        && !matches!(impl_inner.for_, Type::Generic(..)) =>
                 {
-                    if let Some(contained_item_name) = contained_item.name.as_deref() {
-                        impl_index
-                            .entry(ImplEntry::new(id, contained_item_name))
-                            .or_default()
-                            .push((impl_item, contained_item));
-                    }
+                    impl_inner
+                        .trait_
+                        .as_ref()
+                        .map(|trait_path| (item, trait_path))
                 }
+                _ => None,
+            })
+        {
+            if matches!(
+                crate_.index.get(&trait_path.id).map(|item| &item.inner),
+                Some(ItemEnum::Trait(..))
+            ) {
+                foreign_impl_index
+                    .entry(&trait_path.id)
+                    .or_default()
+                    .push(impl_item);
             }
         }
-        value.impl_index = Some(impl_index);
+        for impls in foreign_impl_index.values_mut() {
+            // Ensure a consistent order, since queries can observe this order directly.
+            impls.sort_unstable_by(|a, b| a.id.stable_cmp(&b.id));
+        }
+        value.foreign_impl_index = Some(Arc::new(foreign_impl_index));
 
         value
     }
 
-    /// Return all the paths (as Vec<&'a str> of component names, joinable with "::")
-    /// with which the given item can be imported from this crate.
-    pub fn publicly_importable_names(&self, id: &'a Id) -> Vec<Vec<&'a str>> {
-        let mut result = vec![];
+    /// Check the crate's rustdoc JSON as in [`verify()`](crate::verify), as well as
+    /// the indexes built from it, like items listed by more than one module or impl,
+    /// of which the indexes only record one.
+    ///
+    /// Some inconsistencies in the rustdoc JSON cause a panic while indexing, like
+    /// non-impls listed among a type's impls. Check the JSON with [`verify()`](crate::verify)
+    /// before indexing it to report those instead.
+    pub fn verify(&self) -> VerificationReport {
+        verify_indexed_crate(self)
+    }
 
-        if self.inner.index.contains_key(id) {
-            let mut already_visited_ids = Default::default();
-            self.collect_publicly_importable_names(
-                id,
-                &mut already_visited_ids,
-                &mut vec![],
-                &mut result,
-            );
+    /// Estimate the number of bytes used by each of this crate's indexes.
+    ///
+    /// Counts the memory allocated for the indexes' maps, vectors, and strings,
+    /// but not the rustdoc data they point into, which the caller owns.
+    /// The estimate is computed by walking the indexes, so it isn't free on large crates.
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        MemoryFootprint {
+            visibility_forest: hash_map_bytes(&self.visibility_forest)
+                + self
+                    .visibility_forest
+                    .values()
+                    .map(vec_bytes)
+                    .sum::<usize>(),
+            imports_index: self
+                .imports_index
+                .as_deref()
+                .map(|index| {
+                    hash_map_bytes(index)
+                        + index
+                            .iter()
+                            .map(|(path, items)| vec_bytes(&path.components) + vec_bytes(items))
+                            .sum::<usize>()
+                })
+                .unwrap_or_default(),
+            impl_index: self
+                .impl_index
+                .as_deref()
+                .map(|index| hash_map_bytes(index) + index.values().map(vec_bytes).sum::<usize>())
+                .unwrap_or_default(),
+            trait_impl_index: self
+                .trait_impl_index
+                .as_deref()
+                .map(|index| {
+                    hash_map_bytes(index)
+                        + index
+                            .iter()
+                            .map(|(name, impls)| name.capacity() + vec_bytes(impls))
+                            .sum::<usize>()
+                })
+                .unwrap_or_default(),
+            owner_trait_impl_index: self
+                .owner_trait_impl_index
+                .as_deref()
+                .map(|index| {
+                    hash_map_bytes(index)
+                        + index
+                            .values()
+                            .map(|impls_by_trait| {
+                                hash_map_bytes(impls_by_trait)
+                                    + impls_by_trait
+                                        .iter()
+                                        .map(|(name, impls)| name.capacity() + vec_bytes(impls))
+                                        .sum::<usize>()
+                            })
+                            .sum::<usize>()
+                })
+                .unwrap_or_default(),
+            item_impl_index: self
+                .item_impl_index
+                .as_deref()
+                .map(hash_map_bytes)
+                .unwrap_or_default(),
+            sorted_name_index: self
+                .sorted_name_index
+                .as_deref()
+                .map(vec_bytes)
+                .unwrap_or_default(),
+            case_insensitive_name_index: self
+                .case_insensitive_name_index
+                .as_deref()
+                .map(|index| {
+                    hash_map_bytes(index)
+                        + index
+                            .iter()
+                            .map(|(name, items)| name.capacity() + vec_bytes(items))
+                            .sum::<usize>()
+                })
+                .unwrap_or_default(),
+            kind_index: self
+                .kind_index
+                .as_deref()
+                .map(|index| hash_map_bytes(index) + index.values().map(vec_bytes).sum::<usize>())
+                .unwrap_or_default(),
+            span_index: self
+                .span_index
+                .as_deref()
+                .map(|index| hash_map_bytes(index) + index.values().map(vec_bytes).sum::<usize>())
+                .unwrap_or_default(),
+            parent_module_index: self
+                .parent_module_index
+                .as_deref()
+                .map(hash_map_bytes)
+                .unwrap_or_default(),
+            container_index: self
+                .container_index
+                .as_deref()
+                .map(hash_map_bytes)
+                .unwrap_or_default(),
+            deprecated_index: self
+                .deprecated_index
+                .as_deref()
+                .map(vec_bytes)
+                .unwrap_or_default(),
+            bounded_function_index: self
+                .bounded_function_index
+                .as_deref()
+                .map(|index| hash_map_bytes(index) + index.values().map(vec_bytes).sum::<usize>())
+                .unwrap_or_default(),
+            foreign_impl_index: self
+                .foreign_impl_index
+                .as_deref()
+                .map(|index| hash_map_bytes(index) + index.values().map(vec_bytes).sum::<usize>())
+                .unwrap_or_default(),
+            manually_inlined_builtin_traits: hash_map_bytes(&self.manually_inlined_builtin_traits)
+                + self
+                    .manually_inlined_builtin_traits
+                    .iter()
+                    .map(|(id, item)| {
+                        id.heap_bytes()
+                            + item.id.heap_bytes()
+                            + item.name.as_ref().map(String::capacity).unwrap_or_default()
+                    })
+                    .sum::<usize>(),
         }
+    }
 
-        result
+    /// The impl index, for looking up the items defined in a type's impls by name.
+    ///
+    /// Panics if the impl index was disabled with [`IndexedCrateBuilder::with_impl_index`].
+    pub(crate) fn impl_index(&self) -> &HashMap<ImplEntry<'a>, Vec<(&'a Item, &'a Item)>> {
+        self.impl_index.as_deref().expect(
+            "the impl index is disabled for this crate, so impls and their items \
+            can't be looked up by name; build the IndexedCrate with \
+            `IndexedCrateBuilder::with_impl_index(true)` to run this query",
+        )
     }
 
-    fn collect_publicly_importable_names(
+    /// The span index, for looking up the items defined in each source file.
+    ///
+    /// Panics if the span index was disabled with [`IndexedCrateBuilder::with_span_index`].
+    fn span_index(&self) -> &HashMap<&'a Path, Vec<&'a Item>> {
+        self.span_index.as_deref().expect(
+            "the span index is disabled for this crate, so items can't be looked up \
+            by source file; build the IndexedCrate with \
+            `IndexedCrateBuilder::with_span_index(true)` to run this query",
+        )
+    }
+
+    /// The path of the module the given item is defined in, like `["my_crate", "shapes"]`,
+    /// starting at the crate's root module.
+    ///
+    /// `None` if the item isn't listed in any module in the rustdoc, like impls synthesized
+    /// by rustdoc, or items in private modules when private items weren't documented.
+    pub(crate) fn module_path(&self, item_id: &Id) -> Option<Vec<&'a str>> {
+        let parent_module_index = self
+            .parent_module_index
+            .as_deref()
+            .expect("no parent module index present");
+
+        let mut path = vec![];
+        let mut module = *parent_module_index.get(item_id)?;
+        loop {
+            if module.id == self.inner.root {
+                path.push(self.crate_name.or(module.name.as_deref())?);
+            } else {
+                path.push(module.name.as_deref()?);
+            }
+            match parent_module_index.get(&module.id) {
+                Some(parent) => module = parent,
+                None if module.id == self.inner.root => break,
+                None => return None,
+            }
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All the items defined in the given module or in the modules nested in it,
+    /// in the order the rustdoc lists them. Imports are included as items,
+    /// but not followed.
+    ///
+    /// With `include_reexports`, the items imports point to are included too,
+    /// along with the contents of re-exported modules and of glob imports.
+    /// Each item is included at most once. Non-module items contain no items.
+    pub(crate) fn transitive_module_items(
         &self,
-        next_id: &'a Id,
-        already_visited_ids: &mut HashSet<&'a Id>,
-        stack: &mut Vec<&'a str>,
-        output: &mut Vec<Vec<&'a str>>,
-    ) {
-        if !already_visited_ids.insert(next_id) {
-            // We found a cycle, and we've already processed this item.
-            // Nothing more to do here.
-            return;
+        module: &'a Item,
+        include_reexports: bool,
+    ) -> Vec<&'a Item> {
+        let mut items = vec![];
+        let ItemEnum::Module(module_inner) = &module.inner else {
+            return items;
+        };
+
+        let mut visited: HashSet<&'a Id> = HashSet::new();
+        visited.insert(&module.id);
+        let mut pending: Vec<&'a Id> = module_inner.items.iter().rev().collect();
+        while let Some(id) = pending.pop() {
+            let Some(item) = self.inner.index.get(id) else {
+                // Items from other crates aren't in the index.
+                continue;
+            };
+            if !visited.insert(&item.id) {
+                continue;
+            }
+            items.push(item);
+
+            match &item.inner {
+                ItemEnum::Module(nested) => pending.extend(nested.items.iter().rev()),
+                ItemEnum::Import(import) if include_reexports => {
+                    let Some(target) = import.id.as_ref().and_then(|id| self.inner.index.get(id))
+                    else {
+                        continue;
+                    };
+                    if import.glob {
+                        // Glob imports re-export the contents of the module or enum they name.
+                        if let Some(contents) = glob_import_contents(self.inner, target) {
+                            pending.extend(contents.iter().rev());
+                        }
+                    } else {
+                        pending.push(&target.id);
+                    }
+                }
+                _ => {}
+            }
         }
 
-        let item = &self.inner.index[next_id];
-        if !stack.is_empty()
-            && matches!(
-                item.inner,
-                ItemEnum::Impl(..) | ItemEnum::Struct(..) | ItemEnum::Union(..)
-            )
-        {
-            // Structs, unions, and impl blocks are not modules.
-            // They *themselves* can be imported, but the items they contain cannot be imported.
-            // Since the stack is non-empty, we must be trying to determine importable names
-            // for a descendant item of a struct / union / impl. There are none.
-            //
-            // We explicitly do *not* want to check for Enum here,
-            // since enum variants *are* importable.
-            return;
+        items
+    }
+
+    /// How visible the item is in practice, as opposed to its declared visibility:
+    /// - "public" if it's reachable from the crate root through public items,
+    /// - "unreachable" if it's declared `pub` but isn't reachable, like a `pub` item
+    ///   in a private module that isn't re-exported,
+    /// - "crate", "restricted", or "private" otherwise, matching its declared visibility.
+    pub(crate) fn effective_visibility(&self, item: &Item) -> &'static str {
+        if self.visibility_forest.contains_key(&item.id) {
+            return "public";
+        }
+        match &item.visibility {
+            Visibility::Public => "unreachable",
+            Visibility::Crate => "crate",
+            Visibility::Restricted { .. } => "restricted",
+            Visibility::Default => "private",
         }
+    }
 
-        let (push_name, popped_name) = match &item.inner {
-            rustdoc_types::ItemEnum::Import(import_item) => {
-                if import_item.glob {
-                    // Glob imports refer to the *contents* of the named item, not the item itself.
-                    // Rust doesn't allow glob imports to rename items, so there's no name to add.
-                    (None, None)
-                } else {
-                    // Use the name of the imported item, since it might be renaming
-                    // the item being imported.
-                    let push_name = Some(import_item.name.as_str());
+    /// Whether the item is deprecated, either by its own `#[deprecated]` attribute
+    /// or because an item enclosing it is deprecated: a module it's in,
+    /// the impl it's defined in or the type that impl is for,
+    /// or the struct, enum, variant, or trait it's a member of.
+    pub(crate) fn is_effectively_deprecated(&self, item: &'a Item) -> bool {
+        self.effective_deprecation_memo
+            .get_or_compute(&item.id, || self.compute_effective_deprecation(item))
+    }
+
+    fn compute_effective_deprecation(&self, item: &'a Item) -> bool {
+        self.self_and_enclosing_items(item)
+            .into_iter()
+            .any(is_deprecated)
+    }
+
+    /// The features the item requires to be enabled, in name order, according to
+    /// the `#[cfg(...)]` and `#[doc(cfg(...))]` attributes on it and on the items enclosing it.
+    pub(crate) fn required_features(&self, item: &'a Item) -> BTreeSet<&'a str> {
+        self.self_and_enclosing_items(item)
+            .into_iter()
+            .flat_map(|item| parse_attributes(&item.attrs))
+            .flat_map(|attr| attr.required_features())
+            .collect()
+    }
+
+    /// The item itself, followed by the items enclosing it: the modules it's in,
+    /// the impl it's defined in and the type that impl is for,
+    /// and the struct, enum, variant, or trait it's a member of, transitively.
+    fn self_and_enclosing_items(&self, item: &'a Item) -> Vec<&'a Item> {
+        let parent_module_index = self
+            .parent_module_index
+            .as_deref()
+            .expect("no parent module index present");
+        let item_impl_index = self
+            .item_impl_index
+            .as_deref()
+            .expect("no item impl index present");
+        let container_index = self
+            .container_index
+            .as_deref()
+            .expect("no container index present");
+
+        let mut visited: HashSet<&'a Id> = HashSet::new();
+        let mut enclosing_items = vec![];
+        let mut pending: Vec<&'a Item> = vec![item];
+        while let Some(next) = pending.pop() {
+            if !visited.insert(&next.id) {
+                continue;
+            }
+            enclosing_items.push(next);
+
+            pending.extend(parent_module_index.get(&next.id).copied());
+            pending.extend(item_impl_index.get(&next.id).copied());
+            pending.extend(container_index.get(&next.id).copied());
+            if let ItemEnum::Impl(impl_) = &next.inner {
+                if let Type::ResolvedPath(path) = &impl_.for_ {
+                    pending.extend(self.inner.index.get(&path.id));
+                }
+            }
+        }
+        enclosing_items
+    }
+
+    /// Return all items marked `#[deprecated]`.
+    pub(crate) fn deprecated_items(&self) -> &[&'a Item] {
+        self.deprecated_index
+            .as_deref()
+            .expect("no deprecated index present")
+    }
+
+    /// Return the public functions and methods with a generic bound on the given trait,
+    /// like `fn show<T: Display>(value: T)` or `fn show<T>(value: T) where T: Display`
+    /// for the `Display` trait. Functions not reachable from the crate's public API
+    /// are not included.
+    pub(crate) fn functions_bounded_by(&self, trait_id: &Id) -> &[&'a Item] {
+        self.bounded_function_index
+            .as_deref()
+            .expect("no bounded function index present")
+            .get(trait_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Return the impls of the given trait for types that aren't defined in the crate,
+    /// like `impl LocalTrait for Vec<T>`, which no type in the crate lists among its impls.
+    pub(crate) fn foreign_impls_of(&self, trait_id: &Id) -> &[&'a Item] {
+        self.foreign_impl_index
+            .as_deref()
+            .expect("no foreign impl index present")
+            .get(trait_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Return all items whose type in the schema is the given kind, like all `Struct` items.
+    pub(crate) fn items_of_kind(&self, kind: &str) -> &[&'a Item] {
+        self.kind_index
+            .as_deref()
+            .expect("no kind index present")
+            .get(kind)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// The ids of all items with the given name, of any kind and visibility, sorted by id.
+    ///
+    /// Looked up in an index, so it's much faster than searching the crate's items.
+    /// Items without names, like impl blocks, are never included.
+    pub fn item_ids_with_name(&self, name: &str) -> impl Iterator<Item = &'a Id> + '_ {
+        self.items_with_name(name).iter().map(|(_, item)| &item.id)
+    }
+
+    /// All items whose name matches the given name ignoring case, of any kind and visibility,
+    /// sorted by id. Names are compared after converting them to lowercase.
+    ///
+    /// Always empty if the case-insensitive name index wasn't enabled with
+    /// [`IndexedCrateBuilder::with_case_insensitive_name_index`].
+    pub fn items_with_name_ignoring_case(&self, name: &str) -> &[&'a Item] {
+        self.case_insensitive_name_index
+            .as_deref()
+            .and_then(|index| index.get(&name.to_lowercase()))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Return all items with the given name whose type in the schema is the given kind,
+    /// like all `Struct` items named `Foo`, sorted by id.
+    pub(crate) fn items_with_name_and_kind(&self, name: &str, kind: &str) -> Vec<&'a Item> {
+        // Few items share a name, so checking each one's kind is cheap.
+        self.items_with_name(name)
+            .iter()
+            .filter(|(_, item)| item_typename(item) == kind)
+            .map(|(_, item)| *item)
+            .collect()
+    }
+
+    /// Return all impls of the trait with the given name or full path,
+    /// like `Debug` or `core::fmt::Debug`.
+    pub(crate) fn impls_of_trait(&self, trait_: &str) -> &[&'a Item] {
+        self.trait_impl_index
+            .as_deref()
+            .expect("no trait impl index present")
+            .get(trait_)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Return the impls of the trait with the given name or full path
+    /// for the type with the given id, like `impls_of_trait()` restricted to one type.
+    pub(crate) fn owner_impls_of_trait(&self, owner_id: &Id, trait_: &str) -> &[&'a Item] {
+        self.owner_trait_impl_index
+            .as_deref()
+            .expect("no owner trait impl index present")
+            .get(owner_id)
+            .and_then(|impls_by_trait| impls_by_trait.get(trait_))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Return all items with exactly the given name, sorted by id.
+    fn items_with_name(&self, name: &str) -> &[(&'a str, &'a Item)] {
+        let sorted_name_index = self
+            .sorted_name_index
+            .as_deref()
+            .expect("no sorted name index present");
+
+        // Items with the same name are adjacent, and already sorted by id among themselves.
+        let start = sorted_name_index.partition_point(|(item_name, _)| *item_name < name);
+        let length =
+            sorted_name_index[start..].partition_point(|(item_name, _)| *item_name == name);
+        &sorted_name_index[start..start + length]
+    }
+
+    /// Return all items whose name starts with the given prefix, ordered by name.
+    pub(crate) fn items_with_name_prefix(&self, prefix: &str) -> &[(&'a str, &'a Item)] {
+        let sorted_name_index = self
+            .sorted_name_index
+            .as_deref()
+            .expect("no sorted name index present");
+
+        // All names with the given prefix sort at or after the prefix itself,
+        // and are adjacent to each other.
+        let start = sorted_name_index.partition_point(|(name, _)| *name < prefix);
+        let length =
+            sorted_name_index[start..].partition_point(|(name, _)| name.starts_with(prefix));
+        &sorted_name_index[start..start + length]
+    }
+
+    /// Return all items whose name contains the given substring, ordered by name.
+    pub(crate) fn items_with_name_containing(&self, substring: &str) -> Vec<&'a Item> {
+        self.sorted_name_index
+            .as_deref()
+            .expect("no sorted name index present")
+            .iter()
+            .filter(|(name, _)| name.contains(substring))
+            .map(|(_, item)| *item)
+            .collect()
+    }
+
+    /// Return all items whose name is within `max_distance` edits of the given name,
+    /// ordered by increasing edit distance and then by name.
+    #[cfg(feature = "fuzzy")]
+    pub(crate) fn items_with_similar_name(&self, name: &str, max_distance: usize) -> Vec<&'a Item> {
+        let sorted_name_index = self
+            .sorted_name_index
+            .as_deref()
+            .expect("no sorted name index present");
+
+        let name_length = name.chars().count();
+        let mut matches: Vec<_> = sorted_name_index
+            .iter()
+            .filter_map(|(item_name, item)| {
+                // Names whose lengths differ by more than the allowed distance
+                // can't possibly match, so skip computing their edit distance.
+                // Edits are counted in chars, so lengths must be too.
+                if item_name.chars().count().abs_diff(name_length) > max_distance {
+                    return None;
+                }
+                let distance = strsim::levenshtein(item_name, name);
+                (distance <= max_distance).then_some((distance, *item))
+            })
+            .collect();
+
+        // The sort is stable, so items at the same distance remain ordered by name.
+        matches.sort_by_key(|(distance, _)| *distance);
+        matches.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// Return all items whose span in the given source file includes the given 1-based line,
+    /// with enclosing items before the items they enclose.
+    ///
+    /// The file path must be spelled the same way as in the rustdoc's spans,
+    /// which are usually relative to the crate's workspace root, like `src/lib.rs`.
+    pub(crate) fn items_at_location(&self, file: &str, line: usize) -> Vec<&'a Item> {
+        let Some(items) = self.span_index().get(Path::new(file)) else {
+            return vec![];
+        };
+
+        // Only items starting at or before the line can include it.
+        let candidates = items.partition_point(|item| {
+            item.span
+                .as_ref()
+                .expect("item in span index without a span")
+                .begin
+                .0
+                <= line
+        });
+        items[..candidates]
+            .iter()
+            .filter(|item| {
+                item.span
+                    .as_ref()
+                    .expect("item in span index without a span")
+                    .end
+                    .0
+                    >= line
+            })
+            .copied()
+            .collect()
+    }
+
+    /// The source files the crate's items are defined in, in path order,
+    /// each with its items in the same order as [`IndexedCrate::items_at_location`].
+    pub(crate) fn source_files(&self) -> Vec<(&'a Path, &[&'a Item])> {
+        let mut files: Vec<_> = self
+            .span_index()
+            .iter()
+            .map(|(path, items)| (*path, items.as_slice()))
+            .collect();
+        files.sort_unstable_by_key(|(path, _)| *path);
+        files
+    }
+
+    /// Return the self type of the impl block that defines the item with the given id,
+    /// like `Foo<T>` for the methods in `impl<T> Trait for Foo<T>`.
+    pub(crate) fn impl_self_type(&self, item_id: &Id) -> Option<&'a Type> {
+        self.item_impl_index
+            .as_deref()
+            .expect("no item impl index present")
+            .get(item_id)
+            .and_then(|impl_item| match &impl_item.inner {
+                ItemEnum::Impl(impl_inner) => Some(&impl_inner.for_),
+                _ => None,
+            })
+    }
+
+    /// Return the methods callable with method-call syntax, like `value.method()`,
+    /// on values of the type with the given id, as `(provider, method)` pairs.
+    /// The provider is the impl the method comes from, or for supertrait methods, the supertrait.
+    ///
+    /// If `follow_deref` is set, this also includes the methods available through the type's
+    /// `Deref` impl, recursively, as long as the deref targets are defined in this crate.
+    /// Like in method resolution, methods found through `Deref` are shadowed by
+    /// same-named methods found before dereferencing.
+    ///
+    /// If `include_supertraits` is set, this also includes the methods of the supertraits
+    /// of the traits implemented by the type, as long as the supertraits are defined
+    /// in this crate. Implementing a trait requires implementing its supertraits too,
+    /// but those impls aren't always visible, e.g. when they are blanket impls in another crate.
+    pub(crate) fn available_methods(
+        &self,
+        owner_id: &'a Id,
+        follow_deref: bool,
+        include_supertraits: bool,
+    ) -> Vec<(&'a Item, &'a Item)> {
+        let index = &self.inner.index;
+        let mut result = vec![];
+        let mut visited_ids: HashSet<&'a Id> = HashSet::new();
+        let mut shadowed_names: HashSet<&'a str> = HashSet::new();
+
+        let mut next_owner_id = Some(owner_id);
+        while let Some(owner_id) = next_owner_id.take() {
+            if !visited_ids.insert(owner_id) {
+                // Deref cycle, every method along it has already been found.
+                break;
+            }
+            let Some(impl_ids) = index.get(owner_id).and_then(|owner| match &owner.inner {
+                ItemEnum::Struct(s) => Some(&s.impls),
+                ItemEnum::Enum(e) => Some(&e.impls),
+                ItemEnum::Union(u) => Some(&u.impls),
+                ItemEnum::Primitive(p) => Some(&p.impls),
+                _ => None,
+            }) else {
+                break;
+            };
+
+            let mut found_methods = vec![];
+            let mut implemented_trait_ids: Vec<&'a Id> = vec![];
+            for impl_item in impl_ids.iter().filter_map(|id| index.get(id)) {
+                let ItemEnum::Impl(impl_) = &impl_item.inner else {
+                    continue;
+                };
+                if follow_deref && is_deref_impl(&self.inner.paths, impl_) {
+                    next_owner_id = deref_target_id(index, impl_);
+                }
+                implemented_trait_ids.extend(impl_.trait_.as_ref().map(|path| &path.id));
+
+                found_methods.extend(
+                    self.impl_methods(impl_item)
+                        .filter(|method| is_method(method))
+                        .map(|method| (impl_item, method)),
+                );
+            }
+
+            if include_supertraits {
+                found_methods.extend(self.supertrait_methods(&implemented_trait_ids));
+            }
+
+            found_methods.retain(|(_, method)| {
+                !shadowed_names.contains(method.name.as_deref().unwrap_or_default())
+            });
+            shadowed_names.extend(
+                found_methods
+                    .iter()
+                    .filter_map(|(_, method)| method.name.as_deref()),
+            );
+            result.extend(found_methods);
+        }
+
+        result
+    }
+
+    /// Return the function names provided by more than one impl of the type with the given id,
+    /// together with the `(impl, function)` pairs providing them, sorted by name.
+    ///
+    /// Calling such functions may require disambiguating which impl is meant
+    /// with fully-qualified syntax, like `<Foo as Trait>::method(&value)`.
+    pub(crate) fn method_name_conflicts(
+        &self,
+        owner_id: &'a Id,
+    ) -> Vec<(&'a str, Vec<(&'a Item, &'a Item)>)> {
+        let index = &self.inner.index;
+        let Some(impl_ids) = index.get(owner_id).and_then(|owner| match &owner.inner {
+            ItemEnum::Struct(s) => Some(&s.impls),
+            ItemEnum::Enum(e) => Some(&e.impls),
+            ItemEnum::Union(u) => Some(&u.impls),
+            ItemEnum::Primitive(p) => Some(&p.impls),
+            _ => None,
+        }) else {
+            return vec![];
+        };
+
+        let mut providers_by_name: BTreeMap<&'a str, Vec<(&'a Item, &'a Item)>> = BTreeMap::new();
+        for impl_item in impl_ids.iter().filter_map(|id| index.get(id)) {
+            if !matches!(impl_item.inner, ItemEnum::Impl(..)) {
+                continue;
+            }
+            for function in self
+                .impl_methods(impl_item)
+                .filter(|item| matches!(item.inner, ItemEnum::Function(..)))
+            {
+                if let Some(name) = function.name.as_deref() {
+                    providers_by_name
+                        .entry(name)
+                        .or_default()
+                        .push((impl_item, function));
+                }
+            }
+        }
+
+        providers_by_name
+            .into_iter()
+            .filter(|(_, providers)| providers.len() > 1)
+            .collect()
+    }
+
+    /// The methods declared in the supertraits of the given traits, recursively,
+    /// as `(supertrait, method)` pairs. Supertraits that are among the given traits are skipped,
+    /// since their methods are found through their impls instead.
+    fn supertrait_methods(&self, trait_ids: &[&'a Id]) -> Vec<(&'a Item, &'a Item)> {
+        let index = &self.inner.index;
+        let mut visited_ids: HashSet<&'a Id> = trait_ids.iter().copied().collect();
+        let mut queue: Vec<&'a Id> = trait_ids.to_vec();
+        let mut result = vec![];
+
+        while let Some(trait_id) = queue.pop() {
+            let Some(ItemEnum::Trait(trait_)) = index.get(trait_id).map(|item| &item.inner) else {
+                continue;
+            };
+            for bound in &trait_.bounds {
+                let rustdoc_types::GenericBound::TraitBound { trait_: path, .. } = bound else {
+                    continue;
+                };
+                let Some(supertrait) = index.get(&path.id) else {
+                    continue;
+                };
+                let ItemEnum::Trait(supertrait_inner) = &supertrait.inner else {
+                    continue;
+                };
+                if !visited_ids.insert(&path.id) {
+                    continue;
+                }
+
+                queue.push(&path.id);
+                result.extend(
+                    supertrait_inner
+                        .items
+                        .iter()
+                        .filter_map(|id| index.get(id))
+                        .filter(|method| is_method(method))
+                        .map(|method| (supertrait, method)),
+                );
+            }
+        }
+
+        result
+    }
+
+    /// The items of the given impl, including the trait's provided items it doesn't override
+    /// as long as the trait is defined in this crate.
+    fn impl_methods(&self, impl_item: &'a Item) -> impl Iterator<Item = &'a Item> + 'a {
+        let index = &self.inner.index;
+        let ItemEnum::Impl(impl_) = &impl_item.inner else {
+            unreachable!("expected impl but got another item type: {impl_item:?}");
+        };
+
+        let provided_items = impl_
+            .trait_
+            .as_ref()
+            .and_then(|trait_path| index.get(&trait_path.id))
+            .and_then(|trait_item| match &trait_item.inner {
+                ItemEnum::Trait(trait_) => Some(trait_.items.iter()),
+                _ => None,
+            })
+            .into_iter()
+            .flatten()
+            .filter_map(|id| index.get(id))
+            .filter(|item| {
+                item.name
+                    .as_ref()
+                    .map(|name| impl_.provided_trait_methods.contains(name))
+                    .unwrap_or_default()
+            });
+
+        impl_
+            .items
+            .iter()
+            .filter_map(|id| index.get(id))
+            .chain(provided_items)
+    }
+
+    /// Return all function-like items in the crate as `(kind, function, owner)` tuples, where:
+    /// - kind is "function", "inherent_method", "trait_method" or "trait_impl_method",
+    /// - owner is the impl or trait the function is defined in, if any.
+    pub(crate) fn function_like_items(&self) -> Vec<(&'static str, &'a Item, Option<&'a Item>)> {
+        let index = &self.inner.index;
+        let mut result = vec![];
+        let mut owned_ids: HashSet<&'a Id> = HashSet::new();
+
+        for owner in index.values() {
+            let (kind, item_ids) = match &owner.inner {
+                ItemEnum::Impl(impl_) if impl_.trait_.is_some() => {
+                    ("trait_impl_method", &impl_.items)
+                }
+                ItemEnum::Impl(impl_) => ("inherent_method", &impl_.items),
+                ItemEnum::Trait(trait_) => ("trait_method", &trait_.items),
+                _ => continue,
+            };
+            for item in item_ids.iter().filter_map(|id| index.get(id)) {
+                if matches!(item.inner, ItemEnum::Function(..)) {
+                    owned_ids.insert(&item.id);
+                    result.push((kind, item, Some(owner)));
+                }
+            }
+        }
+
+        result.extend(
+            index
+                .values()
+                .filter(|item| {
+                    matches!(item.inner, ItemEnum::Function(..)) && !owned_ids.contains(&item.id)
+                })
+                .map(|item| ("function", item, None)),
+        );
+
+        result
+    }
+
+    /// Return the items that the given import resolves through, in order:
+    /// any intermediate imports and type aliases equivalent to re-exports,
+    /// followed by the final item the import refers to.
+    ///
+    /// The chain stops early if the next item isn't part of this crate's rustdoc,
+    /// for example because it's defined in another crate.
+    pub(crate) fn import_resolution_chain(&self, import: &'a Import) -> Vec<&'a Item> {
+        let mut chain = vec![];
+        let mut visited_ids: HashSet<&'a Id> = HashSet::new();
+
+        let mut next_item = import.id.as_ref().and_then(|id| self.inner.index.get(id));
+        while let Some(item) = next_item {
+            if !visited_ids.insert(&item.id) {
+                // The imports form a cycle, so there is no final item.
+                break;
+            }
+            chain.push(item);
+
+            next_item = match &item.inner {
+                // Glob imports refer to the contents of the named item, not to a single item.
+                rustdoc_types::ItemEnum::Import(next_import) if !next_import.glob => next_import
+                    .id
+                    .as_ref()
+                    .and_then(|id| self.inner.index.get(id)),
+                rustdoc_types::ItemEnum::Typedef(ty) => {
+                    get_typedef_equivalent_reexport_target(self.inner, ty)
+                }
+                _ => None,
+            };
+        }
+
+        chain
+    }
+
+    /// Return the items through which the given item is publicly visible:
+    /// the modules containing it, the imports re-exporting it,
+    /// or the item it's a member of, like the impl defining a method.
+    ///
+    /// Following these parents up to the crate root yields the item's importable paths,
+    /// so tools applying their own path policies can walk them instead of
+    /// redoing the reachability analysis.
+    ///
+    /// Returns `None` if the item isn't publicly visible, and no parents for the crate root.
+    pub fn publicly_visible_parents(&self, id: &Id) -> Option<Vec<&'a Item>> {
+        self.visibility_forest.get(id).map(|parent_ids| {
+            parent_ids
+                .iter()
+                .filter_map(|parent_id| self.inner.index.get(*parent_id))
+                .collect()
+        })
+    }
+
+    /// Return the `pub use` imports reachable from the crate root,
+    /// i.e. the imports that re-export items as part of the crate's public API.
+    ///
+    /// Imports that aren't `pub`, or that are inside modules not reachable from the root,
+    /// are not included. The order of the imports is unspecified.
+    pub fn public_reexports(&self) -> impl Iterator<Item = &'a Item> + '_ {
+        self.visibility_forest
+            .keys()
+            .filter_map(|id| self.inner.index.get(*id))
+            .filter(|item| matches!(item.inner, ItemEnum::Import(..)))
+    }
+
+    /// Like [`Self::publicly_importable_paths()`], but only computed once per item,
+    /// for items whose paths are looked up repeatedly, like by queries.
+    pub(crate) fn memoized_publicly_importable_paths(&self, id: &'a Id) -> Vec<ImportableName<'a>> {
+        self.importable_paths_memo
+            .get_or_compute(id, || self.publicly_importable_paths(id))
+    }
+
+    /// Return all the paths (as Vec<&'a str> of component names, joinable with "::")
+    /// with which the given item can be imported from this crate.
+    pub fn publicly_importable_names(&self, id: &'a Id) -> Vec<Vec<&'a str>> {
+        self.publicly_importable_paths(id)
+            .into_iter()
+            .map(|path| path.components)
+            .collect()
+    }
+
+    /// Like [`Self::publicly_importable_names()`], but also recording for each path
+    /// whether it's hidden with `#[doc(hidden)]`, by a re-export along it or by the item itself.
+    pub(crate) fn publicly_importable_paths(&self, id: &'a Id) -> Vec<ImportableName<'a>> {
+        let Some(item) = self.inner.index.get(id) else {
+            return vec![];
+        };
+
+        let mut result = vec![];
+        let mut already_visited_ids = Default::default();
+        self.collect_publicly_importable_names(
+            id,
+            &mut already_visited_ids,
+            &mut vec![],
+            0,
+            &mut result,
+        );
+
+        let hidden_target = is_doc_hidden(item);
+        let namespaces = Namespace::of(item);
+        result
+            .into_iter()
+            .map(|(components, hidden_reexport)| ImportableName {
+                components,
+                hidden_reexport,
+                hidden_target,
+                namespaces,
+            })
+            .collect()
+    }
+
+    /// The items importable with the given path that live in any of the given namespaces.
+    ///
+    /// Items of different kinds can be importable with the same path if they live
+    /// in different namespaces, like a struct and a function of the same name.
+    pub(crate) fn importable_items_in_namespaces<'b>(
+        &'b self,
+        path: &[&str],
+        namespaces: &'b [Namespace],
+    ) -> impl Iterator<Item = &'a Item> + 'b {
+        self.imports_index
+            .as_deref()
+            .expect("no imports index present")
+            .get(path)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|item| {
+                Namespace::of(item)
+                    .iter()
+                    .any(|namespace| namespaces.contains(namespace))
+            })
+    }
+
+    fn collect_publicly_importable_names(
+        &self,
+        next_id: &'a Id,
+        already_visited_ids: &mut HashSet<&'a Id>,
+        stack: &mut Vec<&'a str>,
+        hidden_reexports: usize,
+        output: &mut Vec<(Vec<&'a str>, bool)>,
+    ) {
+        if !already_visited_ids.insert(next_id) {
+            // We found a cycle, and we've already processed this item.
+            // Nothing more to do here.
+            return;
+        }
+
+        let item = &self.inner.index[next_id];
+        if !stack.is_empty()
+            && matches!(
+                item.inner,
+                ItemEnum::Impl(..) | ItemEnum::Struct(..) | ItemEnum::Union(..)
+            )
+        {
+            // Structs, unions, and impl blocks are not modules.
+            // They *themselves* can be imported, but the items they contain cannot be imported.
+            // Since the stack is non-empty, we must be trying to determine importable names
+            // for a descendant item of a struct / union / impl. There are none.
+            //
+            // We explicitly do *not* want to check for Enum here,
+            // since enum variants *are* importable.
+            return;
+        }
+
+        let (push_name, popped_name) = match &item.inner {
+            rustdoc_types::ItemEnum::Import(import_item) => {
+                if import_item.glob {
+                    // Glob imports refer to the *contents* of the named item, not the item itself.
+                    // Rust doesn't allow glob imports to rename items, so there's no name to add.
+                    (None, None)
+                } else {
+                    // Use the name of the imported item, since it might be renaming
+                    // the item being imported.
+                    let push_name = Some(import_item.name.as_str());
+
+                    // The imported item may be renamed here, so pop it from the stack.
+                    let popped_name = Some(stack.pop().expect("no name to pop"));
+
+                    (push_name, popped_name)
+                }
+            }
+            rustdoc_types::ItemEnum::Typedef(..) => {
+                // Use the typedef name instead of the underlying item's own name,
+                // since it might be renaming the underlying item.
+                let push_name = Some(item.name.as_deref().expect("typedef had no name"));
+
+                // If there is an underlying item, pop it from the stack
+                // since it may be renamed here.
+                let popped_name = stack.pop();
+
+                (push_name, popped_name)
+            }
+            rustdoc_types::ItemEnum::Module(..) if next_id == &self.inner.root => {
+                (self.crate_name.or(item.name.as_deref()), None)
+            }
+            _ => (item.name.as_deref(), None),
+        };
+
+        // Push the new name onto the stack, if there is one.
+        if let Some(pushed_name) = push_name {
+            stack.push(pushed_name);
+        }
+
+        // Count the `#[doc(hidden)]` re-exports the path goes through, so that paths
+        // hidden by a re-export can be told apart from paths to hidden items.
+        let hidden_reexports = if matches!(item.inner, ItemEnum::Import(..)) && is_doc_hidden(item)
+        {
+            hidden_reexports + 1
+        } else {
+            hidden_reexports
+        };
+
+        self.collect_publicly_importable_names_inner(
+            next_id,
+            already_visited_ids,
+            stack,
+            hidden_reexports,
+            output,
+        );
+
+        // Undo any changes made to the stack, returning it to its pre-recursion state.
+        if let Some(pushed_name) = push_name {
+            let recovered_name = stack.pop().expect("there was nothing to pop");
+            assert_eq!(pushed_name, recovered_name);
+        }
+        if let Some(popped_name) = popped_name {
+            stack.push(popped_name);
+        }
+
+        // We're leaving this item. Remove it from the visited set.
+        let removed = already_visited_ids.remove(next_id);
+        assert!(removed);
+    }
+
+    fn collect_publicly_importable_names_inner(
+        &self,
+        next_id: &'a Id,
+        already_visited_ids: &mut HashSet<&'a Id>,
+        stack: &mut Vec<&'a str>,
+        hidden_reexports: usize,
+        output: &mut Vec<(Vec<&'a str>, bool)>,
+    ) {
+        if next_id == &self.inner.root {
+            let final_name = stack.iter().rev().copied().collect();
+            output.push((final_name, hidden_reexports > 0));
+        } else if let Some(visible_parents) = self.visibility_forest.get(next_id) {
+            for parent_id in visible_parents.iter().copied() {
+                self.collect_publicly_importable_names(
+                    parent_id,
+                    already_visited_ids,
+                    stack,
+                    hidden_reexports,
+                    output,
+                );
+            }
+        }
+    }
+}
+
+/// Builds an [`IndexedCrate`], optionally skipping indexes the caller doesn't need.
+///
+/// All indexes are built by default, just like with [`IndexedCrate::new`].
+#[derive(Debug, Clone)]
+pub struct IndexedCrateBuilder<'a> {
+    crate_: &'a Crate,
+    impl_index: bool,
+    case_insensitive_name_index: bool,
+    span_index: bool,
+    glob_imported_hidden_items: bool,
+    crate_name: Option<&'a str>,
+    package_metadata: PackageMetadata,
+    progress_callback: Option<ProgressCallback<'a>>,
+    #[cfg(feature = "source-snippets")]
+    source_root: Option<std::path::PathBuf>,
+}
+
+impl<'a> IndexedCrateBuilder<'a> {
+    pub fn new(crate_: &'a Crate) -> Self {
+        Self {
+            crate_,
+            impl_index: true,
+            case_insensitive_name_index: false,
+            span_index: true,
+            glob_imported_hidden_items: true,
+            crate_name: None,
+            package_metadata: PackageMetadata::default(),
+            progress_callback: None,
+            #[cfg(feature = "source-snippets")]
+            source_root: None,
+        }
+    }
+
+    /// Whether to build the index of the items in each type's impls.
+    ///
+    /// On large crates, this index dominates the memory used by an [`IndexedCrate`].
+    /// Without it, queries looking up a type's impls or methods by name will panic,
+    /// so only disable it when those parts of the schema aren't used,
+    /// for example when only looking up importable paths.
+    pub fn with_impl_index(self, impl_index: bool) -> Self {
+        Self { impl_index, ..self }
+    }
+
+    /// Whether to build an index of item names ignoring case, for looking up items
+    /// with [`IndexedCrate::items_with_name_ignoring_case`] or the `Crate` vertex's
+    /// `item_case_insensitive_search` edge. Disabled by default, in which case
+    /// those lookups find no items.
+    ///
+    /// Useful for documentation search, where users rarely type names in the exact case.
+    pub fn with_case_insensitive_name_index(self, case_insensitive_name_index: bool) -> Self {
+        Self {
+            case_insensitive_name_index,
+            ..self
+        }
+    }
+
+    /// Whether to build the index of the items defined in each source file.
+    ///
+    /// Without it, the `Crate` vertex's `item_at_location` and `source_file` edges will panic,
+    /// so only disable it when those parts of the schema aren't used.
+    pub fn with_span_index(self, span_index: bool) -> Self {
+        Self { span_index, ..self }
+    }
+
+    /// Whether `#[doc(hidden)]` items are importable through glob imports
+    /// like `pub use inner::*;`, as they are in Rust. Enabled by default.
+    ///
+    /// Hidden items are conventionally not public API, and glob imports often re-export them
+    /// only incidentally. Disabling this omits such paths from the items' importable paths.
+    /// Paths that name hidden items explicitly are unaffected, and their `ImportablePath`
+    /// vertices still report `target_doc_hidden` as usual.
+    pub fn with_glob_imported_hidden_items(self, glob_imported_hidden_items: bool) -> Self {
+        Self {
+            glob_imported_hidden_items,
+            ..self
+        }
+    }
+
+    /// The name to use for the crate root in importable paths and module paths,
+    /// instead of the name of the crate's root module in the rustdoc.
+    ///
+    /// Use it when code refers to the crate by another name than its library name,
+    /// like when a dependency is renamed in `Cargo.toml`, so that paths match
+    /// what is written in `use` statements. Canonical paths are left as recorded
+    /// in the rustdoc.
+    pub fn with_crate_name(self, crate_name: &'a str) -> Self {
+        Self {
+            crate_name: Some(crate_name),
+            ..self
+        }
+    }
+
+    /// Metadata about the package the crate comes from, which rustdoc JSON doesn't record,
+    /// to expose on the `Crate` vertex.
+    pub fn with_package_metadata(self, package_metadata: PackageMetadata) -> Self {
+        Self {
+            package_metadata,
+            ..self
+        }
+    }
+
+    /// Call the given function with the progress of building the indexes,
+    /// so that building them for very large crates can be shown instead of appearing hung.
+    ///
+    /// The function is called as each index starts and finishes being built,
+    /// and periodically while indexes are built item by item.
+    pub fn with_progress_callback(
+        self,
+        callback: impl Fn(IndexProgress) + Send + Sync + 'a,
+    ) -> Self {
+        Self {
+            progress_callback: Some(ProgressCallback(Arc::new(callback))),
+            ..self
+        }
+    }
+
+    /// The directory the source file paths in the crate's rustdoc are relative to,
+    /// usually the package's directory, from which the `Span.source`, `Span.begin_byte_offset`,
+    /// and `Span.end_byte_offset` properties read source code.
+    #[cfg(feature = "source-snippets")]
+    pub fn with_source_root(self, source_root: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            source_root: Some(source_root.into()),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> IndexedCrate<'a> {
+        IndexedCrate::build(self)
+    }
+}
+
+/// The progress of building an [`IndexedCrate`]'s indexes, as reported to the callback
+/// registered with [`IndexedCrateBuilder::with_progress_callback`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexProgress {
+    /// The human-readable name of the index being built, like `"imports index"`.
+    /// The indexes and their names may change between versions of this crate.
+    pub phase: &'static str,
+
+    /// How many of the crate's items have been processed while building this index.
+    pub items_processed: usize,
+
+    /// How many items the crate has, which each index processes at most once.
+    pub total_items: usize,
+}
+
+/// How many items an index processes between two progress reports.
+const PROGRESS_INTERVAL: usize = 10_000;
+
+#[derive(Clone)]
+struct ProgressCallback<'a>(Arc<dyn Fn(IndexProgress) + Send + Sync + 'a>);
+
+impl std::fmt::Debug for ProgressCallback<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ProgressCallback")
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ProgressReporter<'b> {
+    callback: Option<&'b ProgressCallback<'b>>,
+    total_items: usize,
+}
+
+impl<'b> ProgressReporter<'b> {
+    fn report(self, phase: &'static str, items_processed: usize) {
+        if let Some(callback) = self.callback {
+            (callback.0)(IndexProgress {
+                phase,
+                items_processed,
+                total_items: self.total_items,
+            });
+        }
+    }
+
+    /// Report the progress of an index built by iterating over the given items:
+    /// once before the first item, periodically while iterating, and after the last item.
+    fn track<I: Iterator>(self, phase: &'static str, items: I) -> TrackedItems<'b, I> {
+        self.report(phase, 0);
+        TrackedItems {
+            items,
+            reporter: self,
+            phase,
+            processed: 0,
+            last_reported: 0,
+        }
+    }
+}
+
+struct TrackedItems<'b, I> {
+    items: I,
+    reporter: ProgressReporter<'b>,
+    phase: &'static str,
+    processed: usize,
+    last_reported: usize,
+}
+
+impl<I: Iterator> TrackedItems<'_, I> {
+    fn report(&mut self) {
+        self.reporter.report(self.phase, self.processed);
+        self.last_reported = self.processed;
+    }
+}
+
+impl<I: Iterator> Iterator for TrackedItems<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.items.next() {
+            Some(item) => {
+                self.processed += 1;
+                if self.processed - self.last_reported >= PROGRESS_INTERVAL {
+                    self.report();
+                }
+                Some(item)
+            }
+            None => {
+                if self.processed != self.last_reported {
+                    self.report();
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Metadata about the package a crate comes from, like its name and version
+/// from `Cargo.toml` or `cargo metadata`.
+///
+/// Rustdoc JSON doesn't record this, so callers attach it with
+/// [`IndexedCrateBuilder::with_package_metadata`] to make query outputs self-describing,
+/// for example when querying many crates in bulk. All of it is unset by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageMetadata {
+    pub(crate) name: Option<String>,
+    pub(crate) version: Option<String>,
+    pub(crate) repository: Option<String>,
+    pub(crate) license: Option<String>,
+    pub(crate) rust_version: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) features: BTreeMap<String, Vec<String>>,
+    pub(crate) optional_dependencies: BTreeSet<String>,
+}
+
+impl PackageMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The package's name, like `serde_json`.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    /// The package's version, like `1.0.96`.
+    pub fn with_version(self, version: impl Into<String>) -> Self {
+        Self {
+            version: Some(version.into()),
+            ..self
+        }
+    }
+
+    /// The URL of the package's source repository.
+    pub fn with_repository(self, repository: impl Into<String>) -> Self {
+        Self {
+            repository: Some(repository.into()),
+            ..self
+        }
+    }
+
+    /// The package's license, as an SPDX expression like `MIT OR Apache-2.0`.
+    pub fn with_license(self, license: impl Into<String>) -> Self {
+        Self {
+            license: Some(license.into()),
+            ..self
+        }
+    }
+
+    /// The package's minimum supported Rust version (MSRV), like `1.68`,
+    /// as declared in the `package.rust-version` field of its `Cargo.toml`.
+    pub fn with_rust_version(self, rust_version: impl Into<String>) -> Self {
+        Self {
+            rust_version: Some(rust_version.into()),
+            ..self
+        }
+    }
+
+    /// The package's one-line description, from the `package.description` field of its `Cargo.toml`.
+    pub fn with_description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// A feature from the `[features]` table of the package's `Cargo.toml`,
+    /// with the entries it enables, like `std` or `dep:serde`.
+    ///
+    /// `cargo metadata` also lists the implicit features of optional dependencies
+    /// not referred to with `dep:`, like `serde = ["dep:serde"]`. Include those as well.
+    pub fn with_feature(
+        mut self,
+        name: impl Into<String>,
+        enables: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.features
+            .insert(name.into(), enables.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// An optional dependency of the package, by the name its `Cargo.toml` refers to it by.
+    pub fn with_optional_dependency(mut self, name: impl Into<String>) -> Self {
+        self.optional_dependencies.insert(name.into());
+        self
+    }
+
+    /// The features directly enabled by the given feature entries, in name order.
+    pub(crate) fn enabled_features<'b>(
+        &'b self,
+        enables: &'b [String],
+    ) -> impl Iterator<Item = &'b str> + 'b {
+        enables
+            .iter()
+            .filter(|entry| self.features.contains_key(entry.as_str()))
+            .map(String::as_str)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+    }
+
+    /// The optional dependencies directly activated by the given feature entries, in name order.
+    ///
+    /// Both `dep:name` and `name/feature` entries activate the dependency,
+    /// while weak `name?/feature` entries only enable the feature if something else activates it.
+    /// Bare `name` entries activate it when there is no feature of that name,
+    /// as when the caller didn't provide the dependency's implicit feature.
+    pub(crate) fn activated_dependencies<'b>(
+        &'b self,
+        enables: &'b [String],
+    ) -> impl Iterator<Item = &'b str> + 'b {
+        enables
+            .iter()
+            .filter_map(|entry| {
+                if let Some(dependency) = entry.strip_prefix("dep:") {
+                    Some(dependency)
+                } else if let Some((dependency, _)) = entry.split_once('/') {
+                    (!dependency.ends_with('?')).then_some(dependency)
+                } else {
+                    (!self.features.contains_key(entry.as_str())).then_some(entry.as_str())
+                }
+            })
+            .filter(|dependency| self.optional_dependencies.contains(*dependency))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+    }
+}
+
+/// The estimated number of bytes used by each of an [`IndexedCrate`]'s indexes,
+/// as returned by [`IndexedCrate::memory_footprint`].
+///
+/// Indexes that weren't built, like a disabled impl index, use zero bytes.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryFootprint {
+    pub visibility_forest: usize,
+    pub imports_index: usize,
+    pub impl_index: usize,
+    pub trait_impl_index: usize,
+    pub owner_trait_impl_index: usize,
+    pub item_impl_index: usize,
+    pub sorted_name_index: usize,
+    pub case_insensitive_name_index: usize,
+    pub kind_index: usize,
+    pub span_index: usize,
+    pub parent_module_index: usize,
+    pub container_index: usize,
+    pub deprecated_index: usize,
+    pub bounded_function_index: usize,
+    pub foreign_impl_index: usize,
+    pub manually_inlined_builtin_traits: usize,
+}
+
+impl MemoryFootprint {
+    /// The estimated number of bytes used by all the indexes together.
+    pub fn total(&self) -> usize {
+        self.visibility_forest
+            + self.imports_index
+            + self.impl_index
+            + self.trait_impl_index
+            + self.owner_trait_impl_index
+            + self.item_impl_index
+            + self.sorted_name_index
+            + self.case_insensitive_name_index
+            + self.kind_index
+            + self.span_index
+            + self.parent_module_index
+            + self.container_index
+            + self.deprecated_index
+            + self.bounded_function_index
+            + self.foreign_impl_index
+            + self.manually_inlined_builtin_traits
+    }
+}
+
+/// How indexes and resolvers order, render and measure rustdoc item IDs.
+///
+/// The supported format represents IDs as strings like `"0:123"`, while newer formats use integers.
+/// Going through this trait keeps the index orders, the schema's `id` properties,
+/// and the memory footprint independent of that representation. Everything else still
+/// uses the format's `Id` type directly, and verification reports sort by its string.
+pub(crate) trait ItemId {
+    /// Compare two IDs in a stable order, for indexes whose order queries can observe.
+    fn stable_cmp(&self, other: &Self) -> Ordering;
+
+    /// The ID as a string, as exposed by the schema's `id` properties.
+    fn to_id_string(&self) -> String;
+
+    /// The number of bytes the ID allocates on the heap.
+    fn heap_bytes(&self) -> usize;
+}
+
+impl ItemId for Id {
+    fn stable_cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+
+    fn to_id_string(&self) -> String {
+        self.0.clone()
+    }
+
+    fn heap_bytes(&self) -> usize {
+        self.0.capacity()
+    }
+}
+
+/// The keys under which impls of the given trait are indexed: the trait's own name,
+/// and its full path if the crate's rustdoc records it, like `Debug` and `core::fmt::Debug`.
+fn trait_index_keys(crate_: &Crate, trait_path: &rustdoc_types::Path) -> Vec<String> {
+    // The path's name may be qualified, like `fmt::Debug`, depending on how
+    // the impl spelled out the trait. Only use the trait's own name here.
+    let trait_name = trait_path
+        .name
+        .rsplit("::")
+        .next()
+        .expect("rsplit always returns at least one element");
+    let mut keys = vec![trait_name.to_string()];
+
+    if let Some(summary) = crate_.paths.get(&trait_path.id) {
+        let full_path = summary.path.join("::");
+        if full_path != trait_name {
+            keys.push(full_path);
+        }
+    }
+    keys
+}
+
+/// The bytes allocated for a hash map's table, not counting any heap data owned by its entries.
+///
+/// Each slot holds a key-value pair plus a control byte.
+fn hash_map_bytes<K, V>(map: &HashMap<K, V>) -> usize {
+    map.capacity() * (std::mem::size_of::<(K, V)>() + 1)
+}
 
-                    // The imported item may be renamed here, so pop it from the stack.
-                    let popped_name = Some(stack.pop().expect("no name to pop"));
+/// The bytes allocated for a vector's buffer, not counting any heap data owned by its elements.
+fn vec_bytes<T>(vec: &Vec<T>) -> usize {
+    vec.capacity() * std::mem::size_of::<T>()
+}
 
-                    (push_name, popped_name)
-                }
-            }
-            rustdoc_types::ItemEnum::Typedef(..) => {
-                // Use the typedef name instead of the underlying item's own name,
-                // since it might be renaming the underlying item.
-                let push_name = Some(item.name.as_deref().expect("typedef had no name"));
+/// A path with which an item can be imported, as returned by
+/// [`IndexedCrate::publicly_importable_paths()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ImportableName<'a> {
+    pub(crate) components: Vec<&'a str>,
 
-                // If there is an underlying item, pop it from the stack
-                // since it may be renamed here.
-                let popped_name = stack.pop();
+    /// Whether the path goes through a `#[doc(hidden)]` import, like a hidden `pub use`.
+    pub(crate) hidden_reexport: bool,
 
-                (push_name, popped_name)
-            }
-            _ => (item.name.as_deref(), None),
-        };
+    /// Whether the item the path leads to is itself `#[doc(hidden)]`.
+    pub(crate) hidden_target: bool,
 
-        // Push the new name onto the stack, if there is one.
-        if let Some(pushed_name) = push_name {
-            stack.push(pushed_name);
-        }
+    /// The namespaces the path's name lives in, as determined by the item it leads to.
+    pub(crate) namespaces: &'static [Namespace],
+}
 
-        self.collect_publicly_importable_names_inner(next_id, already_visited_ids, stack, output);
+/// The namespaces in which Rust resolves names: a path can name one item in each of them,
+/// with the surrounding code determining which one is meant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Namespace {
+    /// Types, traits, and modules.
+    Type,
 
-        // Undo any changes made to the stack, returning it to its pre-recursion state.
-        if let Some(pushed_name) = push_name {
-            let recovered_name = stack.pop().expect("there was nothing to pop");
-            assert_eq!(pushed_name, recovered_name);
-        }
-        if let Some(popped_name) = popped_name {
-            stack.push(popped_name);
-        }
+    /// Functions, constants, and statics, as well as the constructors
+    /// of unit and tuple structs and variants.
+    Value,
 
-        // We're leaving this item. Remove it from the visited set.
-        let removed = already_visited_ids.remove(next_id);
-        assert!(removed);
-    }
+    /// Declarative and procedural macros.
+    Macro,
+}
 
-    fn collect_publicly_importable_names_inner(
-        &self,
-        next_id: &'a Id,
-        already_visited_ids: &mut HashSet<&'a Id>,
-        stack: &mut Vec<&'a str>,
-        output: &mut Vec<Vec<&'a str>>,
-    ) {
-        if next_id == &self.inner.root {
-            let final_name = stack.iter().rev().copied().collect();
-            output.push(final_name);
-        } else if let Some(visible_parents) = self.visibility_forest.get(next_id) {
-            for parent_id in visible_parents.iter().copied() {
-                self.collect_publicly_importable_names(
-                    parent_id,
-                    already_visited_ids,
-                    stack,
-                    output,
-                );
+impl Namespace {
+    /// The namespaces an item's name lives in: unit and tuple structs and variants
+    /// live in both the type and value namespaces, all other named items in one of them.
+    /// Items that can't be named in paths, like impls, live in none.
+    pub(crate) fn of(item: &Item) -> &'static [Namespace] {
+        match &item.inner {
+            ItemEnum::Struct(struct_)
+                if !matches!(struct_.kind, rustdoc_types::StructKind::Plain { .. }) =>
+            {
+                &[Namespace::Type, Namespace::Value]
+            }
+            ItemEnum::Variant(variant)
+                if !matches!(variant.kind, rustdoc_types::VariantKind::Struct { .. }) =>
+            {
+                &[Namespace::Type, Namespace::Value]
             }
+            ItemEnum::Module(..)
+            | ItemEnum::ExternCrate { .. }
+            | ItemEnum::Union(..)
+            | ItemEnum::Struct(..)
+            | ItemEnum::Enum(..)
+            | ItemEnum::Variant(..)
+            | ItemEnum::Trait(..)
+            | ItemEnum::TraitAlias(..)
+            | ItemEnum::Typedef(..)
+            | ItemEnum::OpaqueTy(..)
+            | ItemEnum::ForeignType
+            | ItemEnum::Primitive(..)
+            | ItemEnum::AssocType { .. } => &[Namespace::Type],
+            ItemEnum::Function(..)
+            | ItemEnum::Constant(..)
+            | ItemEnum::Static(..)
+            | ItemEnum::AssocConst { .. } => &[Namespace::Value],
+            ItemEnum::Macro(..) | ItemEnum::ProcMacro(..) => &[Namespace::Macro],
+            ItemEnum::Import(..) | ItemEnum::Impl(..) | ItemEnum::StructField(..) => &[],
+        }
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Namespace::Type => "type",
+            Namespace::Value => "value",
+            Namespace::Macro => "macro",
         }
     }
 }
@@ -296,6 +2190,55 @@ impl<'a: 'b, 'b> Borrow<[&'b str]> for ImportablePath<'a> {
     }
 }
 
+/// Whether the item is a function with a `self` parameter, callable with method-call syntax.
+fn is_method(item: &Item) -> bool {
+    match &item.inner {
+        ItemEnum::Function(function) => function
+            .decl
+            .inputs
+            .first()
+            .map(|(name, _)| name == "self")
+            .unwrap_or_default(),
+        _ => false,
+    }
+}
+
+/// Whether the given impl is of the standard library's `Deref` trait,
+/// as opposed to some other trait that happens to share its name.
+///
+/// `DerefMut` impls don't count: they don't name a `Target` type,
+/// which is always the same as in the type's `Deref` impl.
+fn is_deref_impl(paths: &HashMap<Id, ItemSummary>, impl_: &rustdoc_types::Impl) -> bool {
+    impl_
+        .trait_
+        .as_ref()
+        .and_then(|trait_path| paths.get(&trait_path.id))
+        .map(|summary| {
+            let path: Vec<_> = summary.path.iter().map(String::as_str).collect();
+            matches!(path.as_slice(), ["core" | "std", "ops", .., "Deref"])
+        })
+        .unwrap_or_default()
+}
+
+/// The id of the `Target` type of the given `Deref` impl, if it's a type in this crate.
+fn deref_target_id<'a>(
+    index: &'a HashMap<Id, Item>,
+    impl_: &'a rustdoc_types::Impl,
+) -> Option<&'a Id> {
+    impl_
+        .items
+        .iter()
+        .filter_map(|id| index.get(id))
+        .find_map(|item| match &item.inner {
+            ItemEnum::AssocType {
+                default: Some(rustdoc_types::Type::ResolvedPath(path)),
+                ..
+            } if item.name.as_deref() == Some("Target") => Some(&path.id),
+            _ => None,
+        })
+        .filter(|id| index.contains_key(id))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct ImplEntry<'a> {
     /// Tuple of:
@@ -333,7 +2276,10 @@ impl<'a: 'b, 'b> Borrow<(&'b Id, &'b str)> for ImplEntry<'a> {
     }
 }
 
-fn compute_parent_ids_for_public_items(crate_: &Crate) -> HashMap<&Id, HashSet<&Id>> {
+fn compute_parent_ids_for_public_items(
+    crate_: &Crate,
+    glob_imported_hidden_items: bool,
+) -> HashMap<&Id, HashSet<&Id>> {
     let mut result = Default::default();
     let root_id = &crate_.root;
     if let Some(root_module) = crate_.index.get(root_id) {
@@ -341,20 +2287,55 @@ fn compute_parent_ids_for_public_items(crate_: &Crate) -> HashMap<&Id, HashSet<&
             let mut currently_visited_items = Default::default();
             visit_root_reachable_public_items(
                 crate_,
+                glob_imported_hidden_items,
                 &mut result,
                 &mut currently_visited_items,
                 root_module,
                 None,
             );
+
+            // `#[macro_export]` macros are importable at the crate root,
+            // regardless of which module defines them, even a private one.
+            for item in crate_.index.values() {
+                if item.crate_id == 0 && is_exported_macro(item) {
+                    visit_root_reachable_public_items(
+                        crate_,
+                        glob_imported_hidden_items,
+                        &mut result,
+                        &mut currently_visited_items,
+                        item,
+                        Some(root_id),
+                    );
+                }
+            }
         }
     }
 
     result
 }
 
+fn is_exported_macro(item: &Item) -> bool {
+    matches!(item.inner, ItemEnum::Macro(..))
+        && parse_attributes(&item.attrs).any(|attr| attr.is_macro_export())
+}
+
+/// Whether the item is marked `#[deprecated]`. Rustdoc records the deprecation itself,
+/// but not when the attribute is applied by a `cfg_attr` it didn't expand.
+fn is_deprecated(item: &Item) -> bool {
+    item.deprecation.is_some() || parse_attributes(&item.attrs).any(|attr| attr.is_deprecated())
+}
+
+fn is_doc_hidden(item: &Item) -> bool {
+    parse_attributes(&item.attrs).any(|attr| attr.is_doc_hidden())
+}
+
 /// Collect all public items that are reachable from the crate root and record their parent Ids.
+///
+/// Unless `glob_imported_hidden_items` is set, `#[doc(hidden)]` items are not considered
+/// reachable through glob imports, though they remain reachable through other paths.
 fn visit_root_reachable_public_items<'a>(
     crate_: &'a Crate,
+    glob_imported_hidden_items: bool,
     parents: &mut HashMap<&'a Id, HashSet<&'a Id>>,
     currently_visited_items: &mut HashSet<&'a Id>,
     item: &'a Item,
@@ -388,6 +2369,12 @@ fn visit_root_reachable_public_items<'a>(
         }
     }
 
+    if matches!(item.inner, ItemEnum::Macro(..)) && !is_exported_macro(item) {
+        // `macro_rules!` macros without `#[macro_export]` are only usable within their crate,
+        // even though rustdoc gives them public visibility.
+        return;
+    }
+
     let item_parents = parents.entry(&item.id).or_default();
     if let Some(parent_id) = parent_id {
         item_parents.insert(parent_id);
@@ -403,8 +2390,14 @@ fn visit_root_reachable_public_items<'a>(
     match &item.inner {
         rustdoc_types::ItemEnum::Module(m) => {
             for inner in m.items.iter().filter_map(|id| crate_.index.get(id)) {
+                if !m.is_crate && is_exported_macro(inner) {
+                    // Exported macros aren't importable through the module defining them.
+                    // They are visited from the crate root instead.
+                    continue;
+                }
                 visit_root_reachable_public_items(
                     crate_,
+                    glob_imported_hidden_items,
                     parents,
                     currently_visited_items,
                     inner,
@@ -421,18 +2414,21 @@ fn visit_root_reachable_public_items<'a>(
                     // For each item in that module, the import's parent becomes its parent as well.
                     let next_parent_id = parent_id;
 
-                    let inner_ids = match &imported_item.inner {
-                        rustdoc_types::ItemEnum::Module(mod_item) => &mod_item.items,
-                        rustdoc_types::ItemEnum::Enum(enum_item) => &enum_item.variants,
-                        _ => unreachable!(
-                            "found a glob import of an unexpected kind of item: \
-                            {imp:?} {imported_item:?}"
-                        ),
-                    };
+                    let inner_ids =
+                        glob_import_contents(crate_, imported_item).unwrap_or_else(|| {
+                            unreachable!(
+                                "found a glob import of an unexpected kind of item: \
+                                {imp:?} {imported_item:?}"
+                            )
+                        });
                     for inner_id in inner_ids {
                         if let Some(item) = crate_.index.get(inner_id) {
+                            if !glob_imported_hidden_items && is_doc_hidden(item) {
+                                continue;
+                            }
                             visit_root_reachable_public_items(
                                 crate_,
+                                glob_imported_hidden_items,
                                 parents,
                                 currently_visited_items,
                                 item,
@@ -443,6 +2439,7 @@ fn visit_root_reachable_public_items<'a>(
                 } else {
                     visit_root_reachable_public_items(
                         crate_,
+                        glob_imported_hidden_items,
                         parents,
                         currently_visited_items,
                         imported_item,
@@ -466,6 +2463,7 @@ fn visit_root_reachable_public_items<'a>(
             {
                 visit_root_reachable_public_items(
                     crate_,
+                    glob_imported_hidden_items,
                     parents,
                     currently_visited_items,
                     inner,
@@ -482,6 +2480,7 @@ fn visit_root_reachable_public_items<'a>(
             {
                 visit_root_reachable_public_items(
                     crate_,
+                    glob_imported_hidden_items,
                     parents,
                     currently_visited_items,
                     inner,
@@ -498,6 +2497,7 @@ fn visit_root_reachable_public_items<'a>(
             {
                 visit_root_reachable_public_items(
                     crate_,
+                    glob_imported_hidden_items,
                     parents,
                     currently_visited_items,
                     inner,
@@ -509,6 +2509,7 @@ fn visit_root_reachable_public_items<'a>(
             for inner in trait_.items.iter().filter_map(|id| crate_.index.get(id)) {
                 visit_root_reachable_public_items(
                     crate_,
+                    glob_imported_hidden_items,
                     parents,
                     currently_visited_items,
                     inner,
@@ -520,6 +2521,7 @@ fn visit_root_reachable_public_items<'a>(
             for inner in impl_.items.iter().filter_map(|id| crate_.index.get(id)) {
                 visit_root_reachable_public_items(
                     crate_,
+                    glob_imported_hidden_items,
                     parents,
                     currently_visited_items,
                     inner,
@@ -537,6 +2539,7 @@ fn visit_root_reachable_public_items<'a>(
             if let Some(reexport_target) = get_typedef_equivalent_reexport_target(crate_, ty) {
                 visit_root_reachable_public_items(
                     crate_,
+                    glob_imported_hidden_items,
                     parents,
                     currently_visited_items,
                     reexport_target,
@@ -554,6 +2557,47 @@ fn visit_root_reachable_public_items<'a>(
     assert!(removed);
 }
 
+/// The ids of the items a glob import of the given item imports:
+/// the items in a module, or the variants of an enum.
+///
+/// Type aliases equivalent to re-exporting an enum, as decided by
+/// [`get_typedef_equivalent_reexport_target`], are resolved to that enum first.
+/// `rustc` currently rejects glob imports through type aliases, so rustdoc JSON
+/// shouldn't contain them, but we'd rather resolve them than panic if it ever does.
+/// Aliases that aren't equivalent to their enum import nothing.
+///
+/// Imports, as when globbing through a re-exported module, are followed to the item
+/// they ultimately import. Chains that leave the crate or loop back on themselves import nothing.
+///
+/// Returns `None` for items that can't be glob-imported.
+fn glob_import_contents<'a>(crate_: &'a Crate, imported_item: &'a Item) -> Option<&'a [Id]> {
+    let mut imported_item = imported_item;
+    let mut followed_imports: HashSet<&Id> = HashSet::new();
+    while let ItemEnum::Import(import) = &imported_item.inner {
+        if import.glob || !followed_imports.insert(&imported_item.id) {
+            // Glob imports don't name a single item whose contents could be imported,
+            // and cycles never reach one.
+            return Some(&[]);
+        }
+        match import.id.as_ref().and_then(|id| crate_.index.get(id)) {
+            Some(target) => imported_item = target,
+            None => return Some(&[]),
+        }
+    }
+
+    match &imported_item.inner {
+        ItemEnum::Module(mod_item) => Some(&mod_item.items),
+        ItemEnum::Enum(enum_item) => Some(&enum_item.variants),
+        ItemEnum::Typedef(ty) => match get_typedef_equivalent_reexport_target(crate_, ty) {
+            Some(target) if matches!(target.inner, ItemEnum::Enum(..)) => {
+                glob_import_contents(crate_, target)
+            }
+            _ => Some(&[]),
+        },
+        _ => None,
+    }
+}
+
 /// Type aliases can sometimes be equivalent to a regular `pub use` re-export:
 /// `pub type Foo = crate::Bar` is an example, equivalent to `pub use crate::Bar`.
 ///
@@ -842,7 +2886,7 @@ fn create_manually_inlined_builtin_traits(crate_: &Crate) -> HashMap<Id, Item> {
 #[cfg(test)]
 mod tests {
     use itertools::Itertools;
-    use rustdoc_types::{Crate, Id};
+    use rustdoc_types::{Crate, Id, ItemEnum};
 
     use crate::{test_util::load_pregenerated_rustdoc, IndexedCrate};
 
@@ -855,6 +2899,250 @@ mod tests {
             .expect("exactly one matching name")
     }
 
+    #[test]
+    fn item_id_order_and_rendering() {
+        use std::cmp::Ordering;
+
+        use super::ItemId;
+
+        let first = Id("0:1".to_string());
+        let second = Id("0:2".to_string());
+        assert_eq!(Ordering::Less, first.stable_cmp(&second));
+        assert_eq!(Ordering::Equal, first.stable_cmp(&first.clone()));
+        assert_eq!("0:1", first.to_id_string());
+    }
+
+    #[test]
+    fn memory_footprint_counts_only_built_indexes() {
+        let rustdoc = load_pregenerated_rustdoc("impl_for_ref");
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+        let without_impl_index = IndexedCrate::builder(&rustdoc)
+            .with_impl_index(false)
+            .build();
+
+        let footprint = indexed_crate.memory_footprint();
+        assert!(footprint.impl_index > 0);
+        assert!(footprint.imports_index > 0);
+        assert!(footprint.total() > footprint.impl_index);
+
+        let reduced_footprint = without_impl_index.memory_footprint();
+        assert_eq!(0, reduced_footprint.impl_index);
+        assert_eq!(
+            footprint.total() - footprint.impl_index,
+            reduced_footprint.total()
+        );
+    }
+
+    #[test]
+    fn item_ids_with_name() {
+        use super::ItemId;
+
+        let rustdoc = load_pregenerated_rustdoc("type_and_value_with_matching_names");
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+
+        let mut expected: Vec<&Id> = rustdoc
+            .index
+            .iter()
+            .filter_map(|(id, item)| (item.name.as_deref() == Some("Foo")).then_some(id))
+            .collect();
+        expected.sort_unstable_by(|a, b| a.stable_cmp(b));
+
+        // The struct `Foo` and the function `Foo`.
+        assert_eq!(2, expected.len());
+        assert_eq!(
+            expected,
+            indexed_crate.item_ids_with_name("Foo").collect::<Vec<_>>()
+        );
+        assert_eq!(None, indexed_crate.item_ids_with_name("Missing").next());
+    }
+
+    #[test]
+    fn items_with_name_ignoring_case() {
+        let rustdoc = load_pregenerated_rustdoc("type_and_value_with_matching_names");
+        let indexed_crate = IndexedCrate::builder(&rustdoc)
+            .with_case_insensitive_name_index(true)
+            .build();
+
+        let expected: Vec<_> = indexed_crate.item_ids_with_name("Foo").collect();
+
+        // The struct `Foo` and the function `Foo`.
+        assert_eq!(2, expected.len());
+        for name in ["Foo", "foo", "FOO"] {
+            let actual: Vec<_> = indexed_crate
+                .items_with_name_ignoring_case(name)
+                .iter()
+                .map(|item| &item.id)
+                .collect();
+            assert_eq!(expected, actual, "{name}");
+        }
+        assert!(indexed_crate
+            .items_with_name_ignoring_case("Missing")
+            .is_empty());
+    }
+
+    #[test]
+    fn case_insensitive_name_index_is_disabled_by_default() {
+        let rustdoc = load_pregenerated_rustdoc("type_and_value_with_matching_names");
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+
+        assert!(indexed_crate.case_insensitive_name_index.is_none());
+        assert!(indexed_crate
+            .items_with_name_ignoring_case("Foo")
+            .is_empty());
+    }
+
+    #[test]
+    fn importable_items_in_namespaces() {
+        use super::Namespace;
+
+        let rustdoc = load_pregenerated_rustdoc("type_and_value_with_matching_names");
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+        let path = ["type_and_value_with_matching_names", "Foo"];
+
+        let kinds_in = |namespaces: &[Namespace]| -> Vec<_> {
+            indexed_crate
+                .importable_items_in_namespaces(&path, namespaces)
+                .map(|item| match &item.inner {
+                    ItemEnum::Struct(..) => "struct",
+                    ItemEnum::Function(..) => "function",
+                    _ => unreachable!("unexpected item {item:?}"),
+                })
+                .sorted()
+                .collect()
+        };
+        assert_eq!(vec!["struct"], kinds_in(&[Namespace::Type]));
+        assert_eq!(vec!["function"], kinds_in(&[Namespace::Value]));
+        assert_eq!(
+            vec!["function", "struct"],
+            kinds_in(&[Namespace::Type, Namespace::Value])
+        );
+        assert!(kinds_in(&[Namespace::Macro]).is_empty());
+    }
+
+    #[test]
+    fn progress_callback() {
+        use std::sync::Mutex;
+
+        let rustdoc = load_pregenerated_rustdoc("trait_impls");
+        let reports = Mutex::new(vec![]);
+        IndexedCrate::builder(&rustdoc)
+            .with_progress_callback(|progress| reports.lock().expect("poisoned").push(progress))
+            .build();
+        let reports = reports.into_inner().expect("poisoned");
+
+        let total_items = rustdoc.index.len();
+        assert!(reports
+            .iter()
+            .all(|report| report.total_items == total_items));
+
+        // Every phase starts from zero and ends having processed every item.
+        let phases: Vec<_> = reports.iter().map(|report| report.phase).dedup().collect();
+        assert_eq!(phases.iter().unique().count(), phases.len(), "{reports:?}");
+        assert!(phases.contains(&"visibility forest"));
+        assert!(phases.contains(&"imports index"));
+        assert!(phases.contains(&"impl index"));
+        for phase in phases {
+            let phase_reports: Vec<_> = reports
+                .iter()
+                .filter(|report| report.phase == phase)
+                .map(|report| report.items_processed)
+                .collect();
+            assert_eq!(vec![0, total_items], phase_reports, "{phase}");
+        }
+    }
+
+    #[test]
+    fn clones_share_indexes() {
+        use std::sync::Arc;
+
+        let rustdoc = load_pregenerated_rustdoc("impl_for_ref");
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+        let cloned = indexed_crate.clone();
+
+        assert!(Arc::ptr_eq(
+            &indexed_crate.manually_inlined_builtin_traits,
+            &cloned.manually_inlined_builtin_traits
+        ));
+        assert!(Arc::ptr_eq(
+            &indexed_crate.visibility_forest,
+            &cloned.visibility_forest
+        ));
+        assert!(Arc::ptr_eq(
+            indexed_crate.impl_index.as_ref().expect("no impl index"),
+            cloned.impl_index.as_ref().expect("no impl index")
+        ));
+    }
+
+    #[test]
+    fn publicly_visible_parents() {
+        let rustdoc = load_pregenerated_rustdoc("effective_visibility");
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+
+        let root_parents = indexed_crate
+            .publicly_visible_parents(&rustdoc.root)
+            .expect("crate root is not visible");
+        assert!(root_parents.is_empty());
+
+        let reexported = find_item_id(&rustdoc, "Reexported");
+        let reexported_parents = indexed_crate
+            .publicly_visible_parents(reexported)
+            .expect("re-exported item is not visible");
+        assert_eq!(1, reexported_parents.len());
+        assert!(matches!(reexported_parents[0].inner, ItemEnum::Import(..)));
+
+        let stranded = find_item_id(&rustdoc, "Stranded");
+        assert_eq!(None, indexed_crate.publicly_visible_parents(stranded));
+    }
+
+    #[test]
+    fn crate_name_override() {
+        let rustdoc = load_pregenerated_rustdoc("effective_visibility");
+        let indexed_crate = IndexedCrate::builder(&rustdoc)
+            .with_crate_name("renamed")
+            .build();
+
+        let reexported = find_item_id(&rustdoc, "Reexported");
+        assert_eq!(
+            vec![vec!["renamed", "Reexported"]],
+            indexed_crate.publicly_importable_names(reexported),
+        );
+        assert!(indexed_crate
+            .imports_index
+            .as_deref()
+            .expect("no imports index present")
+            .contains_key(["renamed", "Reexported"].as_slice()));
+        assert_eq!(
+            Some(vec!["renamed", "private"]),
+            indexed_crate.module_path(reexported),
+        );
+    }
+
+    #[test]
+    fn imports_index_covers_importable_item_kinds() {
+        let rustdoc = load_pregenerated_rustdoc("importable_item_kinds");
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+        let imports_index = indexed_crate
+            .imports_index
+            .as_deref()
+            .expect("no imports index present");
+
+        for name in [
+            "CONSTANT",
+            "STATIC",
+            "Union",
+            "exported_macro",
+            "TraitAlias",
+        ] {
+            let items = imports_index
+                .get(["importable_item_kinds", name].as_slice())
+                .unwrap_or_else(|| panic!("{name} is missing from the imports index"));
+            assert_eq!(
+                vec![find_item_id(&rustdoc, name)],
+                items.iter().map(|item| &item.id).collect::<Vec<_>>(),
+            );
+        }
+    }
+
     /// Ensure that methods, consts, and fields within structs are not importable.
     #[test]
     fn structs_are_not_modules() {
@@ -994,11 +3282,147 @@ mod tests {
         );
     }
 
+    /// A crate with the enum `Shape<T>` and the type alias `Alias`, defined as the enum
+    /// with the given generic arguments, and taking the given generic parameters itself.
+    ///
+    /// Rust doesn't allow glob imports of type aliases, so no test crate can contain one.
+    fn crate_with_enum_alias(
+        alias_params: Vec<rustdoc_types::GenericParamDef>,
+        alias_args: Vec<rustdoc_types::GenericArg>,
+    ) -> Crate {
+        use rustdoc_types::{
+            Enum, GenericArgs, Generics, Module, Path, Type, Typedef, Variant, VariantKind,
+            Visibility,
+        };
+
+        let item = |id: &str, name: &str, inner: ItemEnum| rustdoc_types::Item {
+            id: Id(id.to_string()),
+            crate_id: 0,
+            name: Some(name.to_string()),
+            span: None,
+            visibility: Visibility::Public,
+            docs: None,
+            links: Default::default(),
+            attrs: vec![],
+            deprecation: None,
+            inner,
+        };
+        let items = [
+            item(
+                "0:0",
+                "shapes",
+                ItemEnum::Module(Module {
+                    is_crate: true,
+                    items: vec![Id("0:1".to_string()), Id("0:3".to_string())],
+                    is_stripped: false,
+                }),
+            ),
+            item(
+                "0:1",
+                "Shape",
+                ItemEnum::Enum(Enum {
+                    generics: Generics {
+                        params: vec![type_param("T")],
+                        where_predicates: vec![],
+                    },
+                    variants_stripped: false,
+                    variants: vec![Id("0:2".to_string())],
+                    impls: vec![],
+                }),
+            ),
+            item(
+                "0:2",
+                "Circle",
+                ItemEnum::Variant(Variant {
+                    kind: VariantKind::Plain,
+                    discriminant: None,
+                }),
+            ),
+            item(
+                "0:3",
+                "Alias",
+                ItemEnum::Typedef(Typedef {
+                    type_: Type::ResolvedPath(Path {
+                        name: "Shape".to_string(),
+                        id: Id("0:1".to_string()),
+                        args: Some(Box::new(GenericArgs::AngleBracketed {
+                            args: alias_args,
+                            bindings: vec![],
+                        })),
+                    }),
+                    generics: Generics {
+                        params: alias_params,
+                        where_predicates: vec![],
+                    },
+                }),
+            ),
+        ];
+
+        Crate {
+            root: Id("0:0".to_string()),
+            crate_version: None,
+            includes_private: false,
+            index: items
+                .into_iter()
+                .map(|item| (item.id.clone(), item))
+                .collect(),
+            paths: Default::default(),
+            external_crates: Default::default(),
+            format_version: rustdoc_types::FORMAT_VERSION,
+        }
+    }
+
+    fn type_param(name: &str) -> rustdoc_types::GenericParamDef {
+        rustdoc_types::GenericParamDef {
+            name: name.to_string(),
+            kind: rustdoc_types::GenericParamDefKind::Type {
+                bounds: vec![],
+                default: None,
+                synthetic: false,
+            },
+        }
+    }
+
+    #[test]
+    fn glob_import_of_equivalent_type_alias() {
+        use rustdoc_types::{GenericArg, Type};
+
+        // `pub type Alias<T> = Shape<T>;` is equivalent to `pub use Shape as Alias;`,
+        // so its glob contents are the enum's variants.
+        let rustdoc = crate_with_enum_alias(
+            vec![type_param("T")],
+            vec![GenericArg::Type(Type::Generic("T".to_string()))],
+        );
+        let alias = &rustdoc.index[find_item_id(&rustdoc, "Alias")];
+        let variant = find_item_id(&rustdoc, "Circle");
+
+        assert_eq!(
+            Some(std::slice::from_ref(variant)),
+            super::glob_import_contents(&rustdoc, alias)
+        );
+    }
+
+    #[test]
+    fn glob_import_of_inequivalent_type_alias() {
+        use rustdoc_types::{GenericArg, Type};
+
+        // `pub type Alias = Shape<i64>;` fixes the enum's generic parameter,
+        // so it isn't equivalent to a re-export of the enum and has no glob contents.
+        let rustdoc = crate_with_enum_alias(
+            vec![],
+            vec![GenericArg::Type(Type::Primitive("i64".to_string()))],
+        );
+        let alias = &rustdoc.index[find_item_id(&rustdoc, "Alias")];
+
+        assert_eq!(Some(&[][..]), super::glob_import_contents(&rustdoc, alias));
+    }
+
     mod reexports {
         use std::collections::{BTreeMap, BTreeSet};
 
         use itertools::Itertools;
         use maplit::{btreemap, btreeset};
+        use rustdoc_types::Crate;
 
         use crate::{test_util::load_pregenerated_rustdoc, IndexedCrate};
 
@@ -1009,6 +3433,14 @@ mod tests {
             let rustdoc = load_pregenerated_rustdoc(test_crate);
             let indexed_crate = IndexedCrate::new(&rustdoc);
 
+            assert_indexed_exported_items_match(&rustdoc, &indexed_crate, expected_items);
+        }
+
+        fn assert_indexed_exported_items_match<'a>(
+            rustdoc: &'a Crate,
+            indexed_crate: &IndexedCrate<'a>,
+            expected_items: &BTreeMap<&str, BTreeSet<&str>>,
+        ) {
             for (&expected_item_name, expected_importable_paths) in expected_items {
                 assert!(
                     !expected_item_name.contains(':'),
@@ -1249,6 +3681,19 @@ mod tests {
             assert_exported_items_match(test_crate, &expected_items);
         }
 
+        #[test]
+        fn glob_of_reexported_module() {
+            let test_crate = "glob_of_reexported_module";
+            let expected_items = btreemap! {
+                "foo" => btreeset![
+                    "glob_of_reexported_module::foo",
+                    "glob_of_reexported_module::inner::foo",
+                ],
+            };
+
+            assert_exported_items_match(test_crate, &expected_items);
+        }
+
         #[test]
         fn glob_reexport_enum_variants() {
             let test_crate = "glob_reexport_enum_variants";
@@ -1638,5 +4083,86 @@ mod tests {
                 .expect("write failed");
             }
         }
+
+        #[test]
+        fn doc_hidden() {
+            let test_crate = "doc_hidden";
+            let expected_items = btreemap! {
+                "Visible" => btreeset![
+                    "doc_hidden::Visible",
+                ],
+                "Hidden" => btreeset![
+                    "doc_hidden::Hidden",
+                ],
+                "macro_support" => btreeset![
+                    "doc_hidden::__private::macro_support",
+                ],
+            };
+
+            assert_exported_items_match(test_crate, &expected_items);
+        }
+
+        #[test]
+        fn glob_reexport_doc_hidden() {
+            let test_crate = "glob_reexport_doc_hidden";
+            let expected_items = btreemap! {
+                "Visible" => btreeset![
+                    "glob_reexport_doc_hidden::Visible",
+                    "glob_reexport_doc_hidden::inner::Visible",
+                ],
+                "Hidden" => btreeset![
+                    "glob_reexport_doc_hidden::Hidden",
+                    "glob_reexport_doc_hidden::inner::Hidden",
+                ],
+                "Named" => btreeset![
+                    "glob_reexport_doc_hidden::Named",
+                    "glob_reexport_doc_hidden::inner::Named",
+                ],
+            };
+
+            assert_exported_items_match(test_crate, &expected_items);
+        }
+
+        #[test]
+        fn nested_exported_macros() {
+            let test_crate = "nested_exported_macros";
+            let expected_items = btreemap! {
+                "in_public_module" => btreeset![
+                    "nested_exported_macros::in_public_module",
+                ],
+                "in_private_module" => btreeset![
+                    "nested_exported_macros::in_private_module",
+                ],
+                "reexported" => btreeset![
+                    "nested_exported_macros::reexported",
+                    "nested_exported_macros::outer::reexported",
+                ],
+            };
+
+            assert_exported_items_match(test_crate, &expected_items);
+        }
+
+        #[test]
+        fn glob_reexport_doc_hidden_excluded() {
+            let rustdoc = load_pregenerated_rustdoc("glob_reexport_doc_hidden");
+            let indexed_crate = IndexedCrate::builder(&rustdoc)
+                .with_glob_imported_hidden_items(false)
+                .build();
+            let expected_items = btreemap! {
+                "Visible" => btreeset![
+                    "glob_reexport_doc_hidden::Visible",
+                    "glob_reexport_doc_hidden::inner::Visible",
+                ],
+                "Hidden" => btreeset![
+                    "glob_reexport_doc_hidden::inner::Hidden",
+                ],
+                "Named" => btreeset![
+                    "glob_reexport_doc_hidden::Named",
+                    "glob_reexport_doc_hidden::inner::Named",
+                ],
+            };
+
+            assert_indexed_exported_items_match(&rustdoc, &indexed_crate, &expected_items);
+        }
     }
 }
@@ -1,11 +1,37 @@
 mod adapter;
 mod attributes;
+mod const_value;
+#[cfg(feature = "docs-rs")]
+mod docs_rs;
 mod indexed_crate;
+mod loading;
+mod memo;
+mod public_api;
+mod query_cache;
+#[cfg(feature = "source-snippets")]
+mod source_snippet;
+mod type_display;
+mod type_equivalence;
+mod verification;
 
-#[cfg(test)]
-pub(crate) mod test_util;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+
+#[cfg(feature = "docs-rs")]
+pub use docs_rs::DocsRsFetcher;
 
 // Re-export the Crate type so we can deserialize it.
 pub use rustdoc_types::Crate;
 
-pub use {adapter::RustdocAdapter, indexed_crate::IndexedCrate};
+pub use {
+    adapter::{AdapterExtension, ExecutionCounters, RustdocAdapter, Vertex},
+    indexed_crate::{
+        IndexProgress, IndexedCrate, IndexedCrateBuilder, MemoryFootprint, PackageMetadata,
+    },
+    loading::{load_rustdoc, parse_rustdoc, parse_rustdoc_bytes},
+    public_api::public_api_listing,
+    query_cache::{QueryCache, QueryError},
+    type_display::PathNormalization,
+    type_equivalence::TypeEquivalence,
+    verification::{verify, Inconsistency, VerificationReport},
+};
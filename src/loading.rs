@@ -0,0 +1,191 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, Read},
+    path::Path,
+};
+
+use rustdoc_types::Crate;
+
+/// Load the rustdoc JSON file at the given path.
+///
+/// Files whose names end in `.gz` or `.zst` are transparently decompressed,
+/// which requires the `gzip` or `zstd` feature of this crate, respectively.
+/// Without the corresponding feature, loading such files returns an error
+/// of kind [`io::ErrorKind::Unsupported`].
+///
+/// JSON that doesn't match the expected rustdoc format results in
+/// an error of kind [`io::ErrorKind::InvalidData`].
+pub fn load_rustdoc(path: impl AsRef<Path>) -> io::Result<Crate> {
+    let path = path.as_ref();
+    let file = BufReader::new(File::open(path)?);
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("gz") => load_gzip_rustdoc(file),
+        Some("zst") => load_zstd_rustdoc(file),
+        _ => parse_rustdoc(file),
+    }
+}
+
+/// Parse uncompressed rustdoc JSON data from the given reader.
+pub fn parse_rustdoc(mut reader: impl Read) -> io::Result<Crate> {
+    // Parsing from an in-memory buffer is much faster than parsing from a reader,
+    // and rustdoc JSON files are read in their entirety anyway.
+    let mut content = vec![];
+    reader.read_to_end(&mut content)?;
+    Ok(serde_json::from_slice(&content)?)
+}
+
+/// Parse rustdoc JSON data held in memory, like a file uploaded to a web page.
+///
+/// Unlike [`load_rustdoc`], this doesn't need a filesystem, so it works on targets
+/// without one like `wasm32-unknown-unknown`. Since there is no file name to go by,
+/// gzip- and zstd-compressed data is recognized by its leading magic number instead,
+/// and decompressed with the same feature requirements and errors as in [`load_rustdoc`].
+pub fn parse_rustdoc_bytes(bytes: &[u8]) -> io::Result<Crate> {
+    if bytes.starts_with(GZIP_MAGIC) {
+        load_gzip_rustdoc(bytes)
+    } else if bytes.starts_with(ZSTD_MAGIC) {
+        load_zstd_rustdoc(bytes)
+    } else {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+#[cfg(feature = "gzip")]
+fn load_gzip_rustdoc(reader: impl Read) -> io::Result<Crate> {
+    parse_rustdoc(flate2::read::GzDecoder::new(reader))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn load_gzip_rustdoc(_reader: impl Read) -> io::Result<Crate> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "loading gzip-compressed rustdoc JSON requires the `gzip` feature",
+    ))
+}
+
+#[cfg(feature = "zstd")]
+fn load_zstd_rustdoc(reader: impl Read) -> io::Result<Crate> {
+    parse_rustdoc(zstd::stream::read::Decoder::new(reader)?)
+}
+
+#[cfg(not(feature = "zstd"))]
+fn load_zstd_rustdoc(_reader: impl Read) -> io::Result<Crate> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "loading zstd-compressed rustdoc JSON requires the `zstd` feature",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::test_util::load_pregenerated_rustdoc;
+
+    use super::{load_rustdoc, parse_rustdoc_bytes};
+
+    const RUSTDOC_PATH: &str = "./localdata/test_data/reexport/rustdoc.json";
+
+    /// A path in the temporary directory that is unique to the given test.
+    fn temp_path(file_name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "trustfall-rustdoc-adapter-{}-{file_name}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn load_uncompressed() {
+        let crate_ = load_rustdoc(RUSTDOC_PATH).expect("failed to load rustdoc");
+        assert_eq!(load_pregenerated_rustdoc("reexport"), crate_);
+    }
+
+    #[test]
+    fn load_invalid_json() {
+        let path = temp_path("invalid.json");
+        std::fs::write(&path, "{}").expect("failed to write file");
+        let error = load_rustdoc(&path).expect_err("loaded invalid rustdoc");
+        std::fs::remove_file(&path).expect("failed to remove file");
+
+        assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    }
+
+    #[test]
+    fn parse_bytes() {
+        let content = std::fs::read(RUSTDOC_PATH).expect("failed to read rustdoc");
+        let crate_ = parse_rustdoc_bytes(&content).expect("failed to parse rustdoc");
+        assert_eq!(load_pregenerated_rustdoc("reexport"), crate_);
+
+        let error = parse_rustdoc_bytes(b"{}").expect_err("parsed invalid rustdoc");
+        assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn parse_gzip_bytes() {
+        use std::io::Write;
+
+        let content = std::fs::read(RUSTDOC_PATH).expect("failed to read rustdoc");
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&content).expect("failed to compress");
+        let compressed = encoder.finish().expect("failed to compress");
+
+        let crate_ = parse_rustdoc_bytes(&compressed).expect("failed to parse rustdoc");
+        assert_eq!(load_pregenerated_rustdoc("reexport"), crate_);
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    #[test]
+    fn parse_zstd_bytes_without_feature() {
+        let error = parse_rustdoc_bytes(&[0x28, 0xb5, 0x2f, 0xfd, 0x00])
+            .expect_err("parsed zstd without the feature");
+        assert_eq!(std::io::ErrorKind::Unsupported, error.kind());
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn load_gzip() {
+        use std::io::Write;
+
+        let content = std::fs::read(RUSTDOC_PATH).expect("failed to read rustdoc");
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&content).expect("failed to compress");
+        let compressed = encoder.finish().expect("failed to compress");
+
+        let path = temp_path("rustdoc.json.gz");
+        std::fs::write(&path, compressed).expect("failed to write file");
+        let crate_ = load_rustdoc(&path).expect("failed to load rustdoc");
+        std::fs::remove_file(&path).expect("failed to remove file");
+
+        assert_eq!(load_pregenerated_rustdoc("reexport"), crate_);
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn load_gzip_without_feature() {
+        let path = temp_path("unsupported.json.gz");
+        std::fs::write(&path, "").expect("failed to write file");
+        let error = load_rustdoc(&path).expect_err("loaded gzip without the feature");
+        std::fs::remove_file(&path).expect("failed to remove file");
+
+        assert_eq!(std::io::ErrorKind::Unsupported, error.kind());
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn load_zstd() {
+        let content = std::fs::read(RUSTDOC_PATH).expect("failed to read rustdoc");
+        let compressed = zstd::encode_all(content.as_slice(), 0).expect("failed to compress");
+
+        let path = temp_path("rustdoc.json.zst");
+        std::fs::write(&path, compressed).expect("failed to write file");
+        let crate_ = load_rustdoc(&path).expect("failed to load rustdoc");
+        std::fs::remove_file(&path).expect("failed to remove file");
+
+        assert_eq!(load_pregenerated_rustdoc("reexport"), crate_);
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::{BuildHasher, Hash, Hasher},
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use rustdoc_types::Id;
+
+/// How many independently-locked shards each memo is split into.
+///
+/// Concurrent queries only contend when they access items in the same shard,
+/// and reads of already-memoized values don't contend at all.
+const SHARD_COUNT: usize = 32;
+
+/// Values derived from a crate's items, memoized by item ID so that each value
+/// is computed at most once, no matter how many queries ask for it.
+///
+/// Safe to share across threads running queries on the same crate concurrently.
+#[derive(Debug)]
+pub(crate) struct ItemMemo<'a, V> {
+    hasher: RandomState,
+    shards: Box<[RwLock<HashMap<&'a Id, V>>]>,
+}
+
+impl<'a, V: Clone> ItemMemo<'a, V> {
+    pub(crate) fn new() -> Self {
+        Self {
+            hasher: RandomState::new(),
+            shards: (0..SHARD_COUNT)
+                .map(|_| RwLock::new(HashMap::new()))
+                .collect(),
+        }
+    }
+
+    /// The memoized value for the item, computing it first if this is the first request for it.
+    ///
+    /// No lock is held while computing, so computing a value may use this memo as well.
+    /// If several threads compute the same value at once, the first one to finish is kept.
+    pub(crate) fn get_or_compute(&self, id: &'a Id, compute: impl FnOnce() -> V) -> V {
+        let shard = self.shard_index(id);
+        if let Some(value) = self.read(shard).get(id) {
+            return value.clone();
+        }
+
+        let value = compute();
+        self.write(shard).entry(id).or_insert(value).clone()
+    }
+
+    #[allow(clippy::manual_hash_one)] // `BuildHasher::hash_one()` is newer than our MSRV
+    fn shard_index(&self, id: &Id) -> usize {
+        let mut hasher = self.hasher.build_hasher();
+        id.hash(&mut hasher);
+        (hasher.finish() as usize) % SHARD_COUNT
+    }
+
+    fn read(&self, shard: usize) -> RwLockReadGuard<'_, HashMap<&'a Id, V>> {
+        // Computing values happens outside the locks, so a panic there can't poison them.
+        self.shards[shard].read().expect("memo lock was poisoned")
+    }
+
+    fn write(&self, shard: usize) -> RwLockWriteGuard<'_, HashMap<&'a Id, V>> {
+        self.shards[shard].write().expect("memo lock was poisoned")
+    }
+}
+
+impl<'a, V: Clone> Clone for ItemMemo<'a, V> {
+    fn clone(&self) -> Self {
+        Self {
+            hasher: self.hasher.clone(),
+            shards: (0..SHARD_COUNT)
+                .map(|shard| RwLock::new(self.read(shard).clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<'a, V: Clone> Default for ItemMemo<'a, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use rustdoc_types::Id;
+
+    use super::ItemMemo;
+
+    #[test]
+    fn values_are_computed_once_per_item() {
+        let first = Id("0:1".to_string());
+        let second = Id("0:2".to_string());
+        let memo = ItemMemo::new();
+        let computations = Cell::new(0);
+        let compute = |value| {
+            computations.set(computations.get() + 1);
+            value
+        };
+
+        assert_eq!(1, memo.get_or_compute(&first, || compute(1)));
+        assert_eq!(1, memo.get_or_compute(&first, || compute(100)));
+        assert_eq!(2, memo.get_or_compute(&second, || compute(2)));
+        assert_eq!(2, computations.get());
+    }
+
+    #[test]
+    fn computing_a_value_can_use_the_memo() {
+        let outer = Id("0:1".to_string());
+        let inner = Id("0:2".to_string());
+        let memo = ItemMemo::new();
+
+        let value = memo.get_or_compute(&outer, || memo.get_or_compute(&inner, || 2) + 1);
+        assert_eq!(3, value);
+        assert_eq!(2, memo.get_or_compute(&inner, || 100));
+    }
+
+    #[test]
+    fn memo_is_shared_across_threads() {
+        let ids: Vec<_> = (0..1000).map(|index| Id(format!("0:{index}"))).collect();
+        let memo = ItemMemo::new();
+        let computations = AtomicUsize::new(0);
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for (index, id) in ids.iter().enumerate() {
+                        let value = memo.get_or_compute(id, || {
+                            computations.fetch_add(1, Ordering::Relaxed);
+                            index
+                        });
+                        assert_eq!(index, value);
+                    }
+                });
+            }
+        });
+
+        // Threads may race to compute the same value, but every value is computed at least once
+        // and at most once per thread.
+        let computations = computations.load(Ordering::Relaxed);
+        assert!((ids.len()..=4 * ids.len()).contains(&computations));
+        for (index, id) in ids.iter().enumerate() {
+            assert_eq!(index, memo.get_or_compute(id, || unreachable!()));
+        }
+    }
+}
@@ -0,0 +1,442 @@
+use rustdoc_types::{
+    Abi, FnDecl, Function, GenericBound, Generics, Id, Item, ItemEnum, StructKind, Type,
+    VariantKind, Visibility,
+};
+
+use crate::{
+    type_display::{abi_parts, render_reference, TypeRenderer},
+    IndexedCrate, PathNormalization,
+};
+
+/// Render the crate's public API as a sorted list of lines, one per public item,
+/// in a format similar to that of `cargo public-api`. For example:
+/// ```text
+/// impl core::clone::Clone for my_crate::shapes::Circle
+/// pub fn my_crate::Circle::area(&self) -> f64
+/// pub mod my_crate
+/// pub mod my_crate::shapes
+/// pub struct my_crate::Circle
+/// pub field my_crate::Circle::radius: f64
+/// ```
+///
+/// Items importable under multiple paths get one line per path.
+/// Paths within types and bounds are resolved to the canonical paths of the items they
+/// point to, so a type renders the same way no matter how the code refers to it.
+///
+/// The lines are meant for `diff`-based review of API changes, so their format
+/// normalizes away details that don't affect the API, like parameter patterns.
+/// Trait impls that the compiler generates, like those of auto traits,
+/// and blanket impls are left out.
+pub fn public_api_listing(crate_: &IndexedCrate<'_>) -> Vec<String> {
+    let imports_index = crate_
+        .imports_index
+        .as_deref()
+        .expect("no imports index present");
+
+    let listing = Listing::new(crate_);
+    let mut lines = vec![];
+
+    // The crate root has no importable path, but is the first module of all the others.
+    let root = crate_.inner.index.get(&crate_.inner.root);
+    if let Some(name) = root.and_then(|root| crate_.crate_name.or(root.name.as_deref())) {
+        lines.push(format!("pub mod {name}"));
+    }
+
+    for (importable_path, items) in imports_index {
+        let path = importable_path.components.join("::");
+        for item in items {
+            listing.collect_item_lines(&path, item, &mut lines);
+        }
+    }
+
+    lines.sort_unstable();
+    lines.dedup();
+    lines
+}
+
+/// Renders the listing's lines for items, resolving the paths within their types.
+struct Listing<'c, 'a> {
+    crate_: &'c IndexedCrate<'a>,
+    renderer: TypeRenderer<'a>,
+}
+
+impl<'c, 'a> Listing<'c, 'a> {
+    fn new(crate_: &'c IndexedCrate<'a>) -> Self {
+        let renderer = TypeRenderer::default().with_path_normalization(
+            PathNormalization::new().with_resolved_paths(true),
+            &crate_.inner.paths,
+        );
+        Self { crate_, renderer }
+    }
+
+    fn items(&self, ids: impl IntoIterator<Item = &'a Id>) -> impl Iterator<Item = &'a Item> {
+        let index = &self.crate_.inner.index;
+        ids.into_iter().filter_map(move |id| index.get(id))
+    }
+
+    fn collect_item_lines(&self, path: &str, item: &'a Item, lines: &mut Vec<String>) {
+        match &item.inner {
+            ItemEnum::Struct(struct_) => {
+                lines.push(format!(
+                    "pub struct {path}{}",
+                    self.render_generics(&struct_.generics)
+                ));
+
+                let field_ids: Vec<_> = match &struct_.kind {
+                    StructKind::Unit => vec![],
+                    StructKind::Tuple(fields) => fields.iter().flatten().collect(),
+                    StructKind::Plain { fields, .. } => fields.iter().collect(),
+                };
+                self.collect_field_lines(path, field_ids, true, lines);
+                self.collect_impl_lines(path, &struct_.impls, lines);
+            }
+            ItemEnum::Union(union_) => {
+                lines.push(format!(
+                    "pub union {path}{}",
+                    self.render_generics(&union_.generics)
+                ));
+                self.collect_field_lines(path, &union_.fields, true, lines);
+                self.collect_impl_lines(path, &union_.impls, lines);
+            }
+            ItemEnum::Enum(enum_) => {
+                lines.push(format!(
+                    "pub enum {path}{}",
+                    self.render_generics(&enum_.generics)
+                ));
+
+                for variant in self.items(&enum_.variants) {
+                    let (ItemEnum::Variant(variant_), Some(name)) = (&variant.inner, &variant.name)
+                    else {
+                        continue;
+                    };
+                    let variant_path = format!("{path}::{name}");
+                    lines.push(format!("pub variant {variant_path}"));
+
+                    // Variant fields are public whenever the enum is, regardless of their visibility.
+                    let field_ids: Vec<_> = match &variant_.kind {
+                        VariantKind::Plain => vec![],
+                        VariantKind::Tuple(fields) => fields.iter().flatten().collect(),
+                        VariantKind::Struct { fields, .. } => fields.iter().collect(),
+                    };
+                    self.collect_field_lines(&variant_path, field_ids, false, lines);
+                }
+
+                self.collect_impl_lines(path, &enum_.impls, lines);
+            }
+            ItemEnum::Trait(trait_) => {
+                let mut line = String::from("pub ");
+                if trait_.is_unsafe {
+                    line.push_str("unsafe ");
+                }
+                if trait_.is_auto {
+                    line.push_str("auto ");
+                }
+                line.push_str("trait ");
+                line.push_str(path);
+                line.push_str(&self.renderer.render_generic_params(&trait_.generics.params));
+                line.push_str(&self.render_declared_bounds(&trait_.bounds));
+                line.push_str(
+                    &self
+                        .renderer
+                        .render_where_clause(&trait_.generics.where_predicates),
+                );
+                lines.push(line);
+
+                // Trait items are public whenever the trait is, regardless of their visibility.
+                for trait_item in self.items(&trait_.items) {
+                    if let Some(name) = &trait_item.name {
+                        self.collect_trait_item_line(&format!("{path}::{name}"), trait_item, lines);
+                    }
+                }
+            }
+            ItemEnum::TraitAlias(alias) => {
+                lines.push(format!(
+                    "pub trait {path}{} = {}{}",
+                    self.renderer.render_generic_params(&alias.generics.params),
+                    self.renderer.render_bounds(&alias.params),
+                    self.renderer
+                        .render_where_clause(&alias.generics.where_predicates),
+                ));
+            }
+            ItemEnum::Function(function) => {
+                lines.push(self.render_function(path, function, None));
+            }
+            ItemEnum::Typedef(typedef) => {
+                lines.push(format!(
+                    "pub type {path}{} = {}{}",
+                    self.renderer
+                        .render_generic_params(&typedef.generics.params),
+                    self.renderer.render(&typedef.type_),
+                    self.renderer
+                        .render_where_clause(&typedef.generics.where_predicates),
+                ));
+            }
+            ItemEnum::Constant(constant) => {
+                lines.push(format!(
+                    "pub const {path}: {}",
+                    self.renderer.render(&constant.type_)
+                ));
+            }
+            ItemEnum::Static(static_) => {
+                let qualifier = if static_.mutable { "mut " } else { "" };
+                lines.push(format!(
+                    "pub static {qualifier}{path}: {}",
+                    self.renderer.render(&static_.type_)
+                ));
+            }
+            ItemEnum::Macro(..) => {
+                lines.push(format!("pub macro {path}!"));
+            }
+            ItemEnum::ProcMacro(..) => {
+                lines.push(format!("pub proc_macro {path}"));
+            }
+            ItemEnum::Module(..) => {
+                lines.push(format!("pub mod {path}"));
+            }
+            _ => {}
+        }
+    }
+
+    /// Collects the lines of the fields with the given ids, which are owned by the item
+    /// at the given path. Private fields are only left out if `only_public` is set,
+    /// since enum variants' fields are public whenever their enum is.
+    fn collect_field_lines(
+        &self,
+        owner_path: &str,
+        field_ids: impl IntoIterator<Item = &'a Id>,
+        only_public: bool,
+        lines: &mut Vec<String>,
+    ) {
+        for field in self.items(field_ids) {
+            if only_public && field.visibility != Visibility::Public {
+                continue;
+            }
+            if let (ItemEnum::StructField(ty), Some(name)) = (&field.inner, &field.name) {
+                lines.push(format!(
+                    "pub field {owner_path}::{name}: {}",
+                    self.renderer.render(ty)
+                ));
+            }
+        }
+    }
+
+    fn collect_trait_item_line(&self, path: &str, trait_item: &Item, lines: &mut Vec<String>) {
+        match &trait_item.inner {
+            ItemEnum::Function(function) => {
+                lines.push(self.render_function(path, function, None));
+            }
+            ItemEnum::AssocType {
+                generics,
+                bounds,
+                default,
+            } => {
+                let mut line = format!(
+                    "pub type {path}{}{}",
+                    self.renderer.render_generic_params(&generics.params),
+                    self.render_declared_bounds(bounds),
+                );
+                line.push_str(
+                    &self
+                        .renderer
+                        .render_where_clause(&generics.where_predicates),
+                );
+                if let Some(default) = default {
+                    line.push_str(" = ");
+                    line.push_str(&self.renderer.render(default));
+                }
+                lines.push(line);
+            }
+            ItemEnum::AssocConst { type_, .. } => {
+                lines.push(format!("pub const {path}: {}", self.renderer.render(type_)));
+            }
+            _ => {}
+        }
+    }
+
+    /// Collects the lines of the public methods of the inherent impls with the given ids,
+    /// and one line for each of the trait impls written in the code.
+    fn collect_impl_lines(&self, owner_path: &str, impl_ids: &'a [Id], lines: &mut Vec<String>) {
+        for impl_item in self.items(impl_ids) {
+            let ItemEnum::Impl(impl_) = &impl_item.inner else {
+                continue;
+            };
+
+            let Some(trait_) = &impl_.trait_ else {
+                for method in self
+                    .items(&impl_.items)
+                    .filter(|method| method.visibility == Visibility::Public)
+                {
+                    if let (ItemEnum::Function(function), Some(name)) =
+                        (&method.inner, &method.name)
+                    {
+                        lines.push(self.render_function(
+                            &format!("{owner_path}::{name}"),
+                            function,
+                            Some(&impl_.for_),
+                        ));
+                    }
+                }
+                continue;
+            };
+            if impl_.synthetic || impl_.blanket_impl.is_some() {
+                continue;
+            }
+
+            let mut line = String::new();
+            if impl_.is_unsafe {
+                line.push_str("unsafe ");
+            }
+            line.push_str("impl");
+            line.push_str(&self.renderer.render_generic_params(&impl_.generics.params));
+            line.push(' ');
+            if impl_.negative {
+                line.push('!');
+            }
+            line.push_str(&self.renderer.render_path(trait_));
+            line.push_str(" for ");
+            line.push_str(&self.renderer.render(&impl_.for_));
+            line.push_str(
+                &self
+                    .renderer
+                    .render_where_clause(&impl_.generics.where_predicates),
+            );
+            lines.push(line);
+        }
+    }
+
+    /// Renders a function's signature. Within the methods of impls, `Self` is rendered as
+    /// the impl's self type.
+    fn render_function(&self, path: &str, function: &Function, self_type: Option<&Type>) -> String {
+        let header = &function.header;
+        let mut line = String::from("pub ");
+        if header.const_ {
+            line.push_str("const ");
+        }
+        if header.async_ {
+            line.push_str("async ");
+        }
+        if header.unsafe_ {
+            line.push_str("unsafe ");
+        }
+        if header.abi != Abi::Rust {
+            let (abi, unwind) = abi_parts(&header.abi);
+            let suffix = if unwind { "-unwind" } else { "" };
+            line.push_str(&format!("extern \"{abi}{suffix}\" "));
+        }
+        line.push_str("fn ");
+        line.push_str(path);
+
+        let renderer = self.renderer.with_self_type(self_type);
+        line.push_str(&renderer.render_generic_params(&function.generics.params));
+        line.push_str(&render_fn_decl(&renderer, &function.decl));
+        line.push_str(&renderer.render_where_clause(&function.generics.where_predicates));
+        line
+    }
+
+    /// Renders generic parameters followed by the where clause, like `<T> where T: Clone`.
+    fn render_generics(&self, generics: &Generics) -> String {
+        format!(
+            "{}{}",
+            self.renderer.render_generic_params(&generics.params),
+            self.renderer
+                .render_where_clause(&generics.where_predicates)
+        )
+    }
+
+    /// Renders the bounds of a trait's supertraits or of an associated type,
+    /// like `: Clone + Send`, or nothing if there are none.
+    fn render_declared_bounds(&self, bounds: &[GenericBound]) -> String {
+        if bounds.is_empty() {
+            String::new()
+        } else {
+            format!(": {}", self.renderer.render_bounds(bounds))
+        }
+    }
+}
+
+/// Renders the parameters and return type of a function, like `(&self, x: u64) -> bool`.
+fn render_fn_decl(renderer: &TypeRenderer<'_>, decl: &FnDecl) -> String {
+    let mut inputs: Vec<_> = decl
+        .inputs
+        .iter()
+        .map(|(name, ty)| match (name.as_str(), ty) {
+            ("self", Type::Generic(self_type)) if self_type == "Self" => "self".to_string(),
+            (
+                "self",
+                Type::BorrowedRef {
+                    lifetime,
+                    mutable,
+                    type_,
+                },
+            ) if matches!(type_.as_ref(), Type::Generic(self_type) if self_type == "Self") => {
+                render_reference(lifetime.as_deref(), *mutable, "self")
+            }
+            _ => format!("{name}: {}", renderer.render(ty)),
+        })
+        .collect();
+    if decl.c_variadic {
+        inputs.push("...".to_string());
+    }
+
+    let mut rendered = format!("({})", inputs.join(", "));
+    if let Some(output) = &decl.output {
+        rendered.push_str(" -> ");
+        rendered.push_str(&renderer.render(output));
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_util::load_pregenerated_rustdoc, IndexedCrate};
+
+    use super::public_api_listing;
+
+    #[test]
+    fn public_api_lines() {
+        let rustdoc = load_pregenerated_rustdoc("public_api");
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+
+        let expected = vec![
+            "impl core::clone::Clone for public_api::shapes::Circle",
+            "impl public_api::Draw for public_api::shapes::Circle",
+            "pub const public_api::Measure::SCALE: u32",
+            "pub const public_api::UNIT: f64",
+            "pub enum public_api::shapes::Shape",
+            "pub extern \"C\" fn public_api::ffi_area(radius: f64) -> f64",
+            "pub field public_api::Bits::float: f64",
+            "pub field public_api::Bits::int: u64",
+            "pub field public_api::Circle::radius: f64",
+            "pub field public_api::Wrapper::fallback: U",
+            "pub field public_api::Wrapper::inner: &'a T",
+            "pub field public_api::shapes::Circle::radius: f64",
+            "pub field public_api::shapes::Shape::Round::0: public_api::shapes::Circle",
+            "pub field public_api::shapes::Shape::Square::side: f64",
+            "pub fn public_api::Circle::area(&self) -> f64",
+            "pub fn public_api::Circle::new(radius: f64) -> public_api::shapes::Circle",
+            "pub fn public_api::Draw::draw(&self, canvas: &mut alloc::string::String)",
+            "pub fn public_api::Measure::measure(&self) -> Unit",
+            "pub fn public_api::largest<T>(items: &[T]) -> core::option::Option<&T> where T: core::cmp::PartialOrd",
+            "pub fn public_api::shapes::Circle::area(&self) -> f64",
+            "pub fn public_api::shapes::Circle::new(radius: f64) -> public_api::shapes::Circle",
+            "pub fn public_api::total_area(shapes: &[public_api::shapes::Shape]) -> f64",
+            "pub macro public_api::circle!",
+            "pub mod public_api",
+            "pub mod public_api::shapes",
+            "pub static mut public_api::COUNTER: u64",
+            "pub struct public_api::Circle",
+            "pub struct public_api::Wrapper<'a, T: core::clone::Clone + 'a, U> where U: core::default::Default",
+            "pub struct public_api::shapes::Circle",
+            "pub trait public_api::Draw",
+            "pub trait public_api::Measure<Unit = f64>: public_api::Draw",
+            "pub trait public_api::Shaped = public_api::Draw + core::clone::Clone",
+            "pub type public_api::Measure::Output: core::marker::Copy",
+            "pub type public_api::Shapes = alloc::vec::Vec<public_api::shapes::Shape>",
+            "pub union public_api::Bits",
+            "pub variant public_api::shapes::Shape::Empty",
+            "pub variant public_api::shapes::Shape::Round",
+            "pub variant public_api::shapes::Shape::Square",
+        ];
+        assert_eq!(expected, public_api_listing(&indexed_crate));
+    }
+}
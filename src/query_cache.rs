@@ -0,0 +1,199 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use trustfall::{FieldValue, Schema};
+use trustfall_core::{
+    frontend::{error::FrontendError, parse},
+    interpreter::{error::QueryArgumentsError, execution::interpret_ir},
+    ir::IndexedQuery,
+};
+
+use crate::RustdocAdapter;
+
+/// Parses queries against the adapter's schema once, then reuses the parsed queries,
+/// keyed by query text.
+///
+/// Tools running many queries over many crates can share one cache across all the crates'
+/// adapters, instead of parsing every query again for every crate.
+#[derive(Debug)]
+pub struct QueryCache {
+    schema: Schema,
+    queries: Mutex<HashMap<String, Arc<IndexedQuery>>>,
+}
+
+impl QueryCache {
+    /// A cache for queries against [`RustdocAdapter::schema()`].
+    pub fn new() -> Self {
+        Self::with_schema(RustdocAdapter::schema())
+    }
+
+    /// A cache for queries against the given schema, like the
+    /// [`RustdocAdapter::extended_schema()`] of adapters with extensions.
+    pub fn with_schema(schema: Schema) -> Self {
+        Self {
+            schema,
+            queries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Parse the query, or return the result of parsing it before.
+    ///
+    /// Queries that fail to parse aren't cached.
+    pub fn parse(&self, query: &str) -> Result<Arc<IndexedQuery>, QueryError> {
+        if let Some(parsed) = self.lock().get(query) {
+            return Ok(parsed.clone());
+        }
+
+        let parsed = parse(&self.schema, query)?;
+        self.lock().insert(query.to_string(), parsed.clone());
+        Ok(parsed)
+    }
+
+    /// Run the query with the given adapter, like [`trustfall::execute_query`],
+    /// but only parsing the query if it isn't cached yet.
+    #[allow(clippy::type_complexity)]
+    pub fn execute<'a>(
+        &self,
+        adapter: Rc<RustdocAdapter<'a>>,
+        query: &str,
+        variables: BTreeMap<impl Into<Arc<str>>, impl Into<FieldValue>>,
+    ) -> Result<Box<dyn Iterator<Item = BTreeMap<Arc<str>, FieldValue>> + 'a>, QueryError> {
+        let parsed = self.parse(query)?;
+        let arguments = variables
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .collect();
+        Ok(interpret_ir(adapter, parsed, Arc::new(arguments))?)
+    }
+
+    /// The number of distinct queries parsed so far.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<IndexedQuery>>> {
+        // Parsing doesn't panic while holding the lock, so it can't be poisoned.
+        self.queries.lock().expect("query cache lock was poisoned")
+    }
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Why [`QueryCache::execute()`] couldn't run a query.
+#[derive(Debug)]
+pub enum QueryError {
+    /// The query isn't valid against the cache's schema.
+    Parse(Box<FrontendError>),
+
+    /// The variables don't match the ones the query uses.
+    Arguments(QueryArgumentsError),
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(error) => write!(f, "invalid query: {error}"),
+            Self::Arguments(error) => write!(f, "invalid query arguments: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for QueryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(error) => Some(error.as_ref()),
+            Self::Arguments(error) => Some(error),
+        }
+    }
+}
+
+impl From<FrontendError> for QueryError {
+    fn from(error: FrontendError) -> Self {
+        Self::Parse(Box::new(error))
+    }
+}
+
+impl From<QueryArgumentsError> for QueryError {
+    fn from(error: QueryArgumentsError) -> Self {
+        Self::Arguments(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, rc::Rc, sync::Arc};
+
+    use trustfall::FieldValue;
+
+    use crate::{test_util::load_pregenerated_rustdoc, IndexedCrate, RustdocAdapter};
+
+    use super::QueryCache;
+
+    const QUERY: &str = r#"
+{
+    Crate {
+        item {
+            ... on Struct {
+                name @output
+            }
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn queries_are_parsed_once() {
+        let cache = QueryCache::new();
+        let first = cache.parse(QUERY).expect("query failed to parse");
+        let second = cache.parse(QUERY).expect("query failed to parse");
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(1, cache.len());
+
+        cache
+            .parse("{ Crate { no_such_edge { name @output } } }")
+            .expect_err("invalid query parsed");
+        assert_eq!(1, cache.len());
+    }
+
+    #[test]
+    fn cached_queries_run_against_many_crates() {
+        let cache = QueryCache::new();
+        for crate_name in ["effective_visibility", "transparent_structs"] {
+            let rustdoc = load_pregenerated_rustdoc(crate_name);
+            let indexed_crate = IndexedCrate::new(&rustdoc);
+            let adapter = Rc::new(RustdocAdapter::new(&indexed_crate, None));
+
+            let variables: BTreeMap<&str, &str> = BTreeMap::new();
+            let mut cached_results: Vec<_> = cache
+                .execute(adapter.clone(), QUERY, variables.clone())
+                .expect("failed to run query")
+                .collect();
+            let mut uncached_results: Vec<_> =
+                trustfall::execute_query(cache.schema(), adapter, QUERY, variables)
+                    .expect("failed to run query")
+                    .collect();
+            cached_results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+            uncached_results.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+            assert_ne!(Vec::<BTreeMap<Arc<str>, FieldValue>>::new(), cached_results);
+            assert_eq!(uncached_results, cached_results);
+        }
+        assert_eq!(1, cache.len());
+    }
+}
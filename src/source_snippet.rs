@@ -0,0 +1,243 @@
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use rustdoc_types::Span;
+
+/// The crate's source files, read from the source directory the first time a span needs them.
+///
+/// Span file names are relative to the directory rustdoc was run in,
+/// so the root is usually the package's directory.
+/// Each file is read and scanned for line breaks at most once, so resolving source-derived
+/// properties of many spans, or several such properties of one span, doesn't re-read it.
+/// Safe to share across threads running queries on the same crate concurrently.
+#[derive(Debug)]
+pub(crate) struct SourceFiles {
+    root: PathBuf,
+    files: RwLock<HashMap<PathBuf, Option<Arc<SourceFile>>>>,
+}
+
+impl SourceFiles {
+    pub(crate) fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            files: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// The source code covered by the span.
+    ///
+    /// Returns `None` if the file can't be read or doesn't contain the span.
+    pub(crate) fn span_source(&self, span: &Span) -> Option<String> {
+        let file = self.file(&span.filename)?;
+        extract_span(&file.text, span.begin, span.end)
+    }
+
+    /// The byte offsets in the span's source file of the span's begin and end,
+    /// for tools that slice source files by bytes rather than by lines and characters.
+    ///
+    /// Returns `None` if the file can't be read or doesn't contain the span.
+    pub(crate) fn span_byte_offsets(&self, span: &Span) -> Option<(usize, usize)> {
+        let file = self.file(&span.filename)?;
+        let begin = file.byte_offset(span.begin)?;
+        let end = file.byte_offset(span.end)?;
+        (begin <= end).then_some((begin, end))
+    }
+
+    fn file(&self, filename: &Path) -> Option<Arc<SourceFile>> {
+        if let Some(file) = self
+            .files
+            .read()
+            .expect("source file cache lock was poisoned")
+            .get(filename)
+        {
+            return file.clone();
+        }
+
+        // No lock is held while reading, so concurrent first reads of a file may both read it.
+        let file = source_file_path(&self.root, filename)
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .map(|text| Arc::new(SourceFile::new(text)));
+        self.files
+            .write()
+            .expect("source file cache lock was poisoned")
+            .entry(filename.to_path_buf())
+            .or_insert(file)
+            .clone()
+    }
+}
+
+/// The path of the source file with the given name under the root, or `None` if the name
+/// is absolute or uses `..`, since such a file could be outside the root.
+fn source_file_path(root: &Path, filename: &Path) -> Option<PathBuf> {
+    filename
+        .components()
+        .all(|component| matches!(component, Component::Normal(..) | Component::CurDir))
+        .then(|| root.join(filename))
+}
+
+/// A source file's text, with the byte offsets at which its lines start.
+#[derive(Debug)]
+struct SourceFile {
+    text: String,
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    fn new(text: String) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect();
+        Self { text, line_starts }
+    }
+
+    /// The byte offset of the given (line, column) position, where the line is 1-based
+    /// and the column is a 0-based character offset that may point just past the line's end.
+    fn byte_offset(&self, (line, column): (usize, usize)) -> Option<usize> {
+        // A position just past a trailing line break is on an empty last line.
+        let line_start = *self.line_starts.get(line.checked_sub(1)?)?;
+        let line_end = self
+            .line_starts
+            .get(line)
+            .copied()
+            .unwrap_or(self.text.len());
+        let line_text = &self.text[line_start..line_end];
+        let content = line_text
+            .strip_suffix('\n')
+            .map(|content| content.strip_suffix('\r').unwrap_or(content))
+            .unwrap_or(line_text);
+
+        let offset = content
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain(std::iter::once(content.len()))
+            .nth(column)?;
+        Some(line_start + offset)
+    }
+}
+
+/// The text between the given (line, column) positions, where lines are 1-based
+/// and columns are 0-based character offsets, with the end being exclusive.
+fn extract_span(text: &str, begin: (usize, usize), end: (usize, usize)) -> Option<String> {
+    let (begin_line, begin_column) = begin;
+    let (end_line, end_column) = end;
+    if begin_line == 0 || end < begin {
+        return None;
+    }
+
+    let lines: Vec<&str> = text
+        .lines()
+        .skip(begin_line - 1)
+        .take(end_line - begin_line + 1)
+        .collect();
+    if lines.len() != end_line - begin_line + 1 {
+        return None;
+    }
+
+    let last = lines.len() - 1;
+    let mut snippet = String::new();
+    for (index, line) in lines.into_iter().enumerate() {
+        let start = if index == 0 { begin_column } else { 0 };
+        let chars = line.chars().skip(start);
+        if index == last {
+            let length = end_column.checked_sub(start)?;
+            snippet.extend(chars.take(length));
+        } else {
+            snippet.extend(chars);
+            snippet.push('\n');
+        }
+    }
+    Some(snippet)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{extract_span, source_file_path, SourceFile};
+
+    const TEXT: &str = "\
+/// Docs.
+pub struct Unit;
+
+pub fn answer() -> i32 {
+    42
+}
+";
+
+    #[test]
+    fn single_line_span() {
+        assert_eq!(
+            Some("pub struct Unit;".to_string()),
+            extract_span(TEXT, (2, 0), (2, 16))
+        );
+        assert_eq!(
+            Some("Unit".to_string()),
+            extract_span(TEXT, (2, 11), (2, 15))
+        );
+    }
+
+    #[test]
+    fn multi_line_span() {
+        assert_eq!(
+            Some("pub fn answer() -> i32 {\n    42\n}".to_string()),
+            extract_span(TEXT, (4, 0), (6, 1))
+        );
+    }
+
+    #[test]
+    fn span_outside_text() {
+        assert_eq!(None, extract_span(TEXT, (6, 0), (9, 1)));
+        assert_eq!(None, extract_span(TEXT, (0, 0), (1, 1)));
+        assert_eq!(None, extract_span(TEXT, (4, 5), (4, 2)));
+    }
+
+    #[test]
+    fn byte_offsets() {
+        let file = SourceFile::new(TEXT.to_string());
+        assert_eq!(Some(0), file.byte_offset((1, 0)));
+        assert_eq!(Some(10), file.byte_offset((2, 0)));
+        assert_eq!(Some(26), file.byte_offset((2, 16)));
+        assert_eq!(Some(57), file.byte_offset((5, 4)));
+        assert_eq!(Some(TEXT.len()), file.byte_offset((7, 0)));
+
+        // Columns count characters, not bytes.
+        let file = SourceFile::new("let s = \"é\";".to_string());
+        assert_eq!(Some(9), file.byte_offset((1, 9)));
+        assert_eq!(Some(11), file.byte_offset((1, 10)));
+
+        // Line breaks may be `\r\n`, which doesn't change the columns.
+        let file = SourceFile::new("fn a() {}\r\nfn b() {}\r\n".to_string());
+        assert_eq!(Some(14), file.byte_offset((2, 3)));
+    }
+
+    #[test]
+    fn byte_offsets_outside_text() {
+        let file = SourceFile::new(TEXT.to_string());
+        assert_eq!(None, file.byte_offset((0, 0)));
+        assert_eq!(None, file.byte_offset((2, 17)));
+        assert_eq!(None, file.byte_offset((8, 0)));
+        assert_eq!(None, file.byte_offset((9, 0)));
+    }
+
+    #[test]
+    fn source_file_paths_stay_under_root() {
+        let root = Path::new("/checkout");
+        assert_eq!(
+            Some(Path::new("/checkout/src/lib.rs").to_path_buf()),
+            source_file_path(root, Path::new("src/lib.rs"))
+        );
+        assert_eq!(
+            Some(Path::new("/checkout/./src/lib.rs").to_path_buf()),
+            source_file_path(root, Path::new("./src/lib.rs"))
+        );
+        assert_eq!(None, source_file_path(root, Path::new("/etc/passwd")));
+        assert_eq!(None, source_file_path(root, Path::new("../outside.rs")));
+        assert_eq!(
+            None,
+            source_file_path(root, Path::new("src/../../outside.rs"))
+        );
+    }
+}
@@ -1,21 +1,28 @@
-use std::fs::read_to_string;
+//! Utilities for loading and generating rustdoc JSON in tests,
+//! like the test suites of lints written as queries against this crate's schema.
+//!
+//! Fixtures follow the same layout as this crate's own test crates: each fixture is a crate
+//! whose rustdoc JSON is stored at `./localdata/test_data/<crate name>/rustdoc.json`.
+use std::{ffi::OsString, fs::read_to_string, io, path::Path, process::Command};
 
 use anyhow::Context;
 use rustdoc_types::Crate;
 
-#[derive(serde::Deserialize)]
-struct RustdocFormatVersion {
-    format_version: u32,
+/// The rustdoc JSON format version of the given file data,
+/// even if it's a version this crate can't parse.
+pub fn detect_rustdoc_format_version(file_data: &str) -> anyhow::Result<u32> {
+    serde_json::from_str::<serde_json::Value>(file_data)
+        .ok()
+        .and_then(|value| value.get("format_version")?.as_u64())
+        .and_then(|version| u32::try_from(version).ok())
+        .with_context(|| "file does not appear to be a rustdoc JSON format".to_string())
 }
 
-pub(crate) fn detect_rustdoc_format_version(file_data: &str) -> anyhow::Result<u32> {
-    let version = serde_json::from_str::<RustdocFormatVersion>(file_data)
-        .with_context(|| "file does not appear to be a rustdoc JSON format".to_string())?;
-
-    Ok(version.format_version)
-}
-
-pub(crate) fn load_pregenerated_rustdoc(crate_name: &str) -> Crate {
+/// Load the pregenerated rustdoc JSON of the given test crate,
+/// from `./localdata/test_data/<crate_name>/rustdoc.json`.
+///
+/// Panics with instructions for regenerating the file if it's missing or outdated.
+pub fn load_pregenerated_rustdoc(crate_name: &str) -> Crate {
     let path = format!("./localdata/test_data/{crate_name}/rustdoc.json");
     let content = read_to_string(&path)
         .with_context(|| format!("Could not load {path} file, did you forget to run ./scripts/regenerate_test_rustdocs.sh ?"))
@@ -40,3 +47,73 @@ pub(crate) fn load_pregenerated_rustdoc(crate_name: &str) -> Crate {
             }
         }).expect("failed to parse rustdoc JSON")
 }
+
+/// Generate and load the rustdoc JSON of the crate in the given directory, with `cargo rustdoc`
+/// building into the given target directory.
+///
+/// Like `./scripts/regenerate_test_rustdocs.sh`, this includes private and hidden items.
+/// The crate's library must be named after its directory, as with this crate's test crates.
+/// Generating rustdoc JSON requires a nightly toolchain, or `RUSTC_BOOTSTRAP=1`
+/// which is set here; the toolchain must produce the format version this crate supports.
+pub fn generate_rustdoc(
+    crate_dir: impl AsRef<Path>,
+    target_dir: impl AsRef<Path>,
+) -> io::Result<Crate> {
+    let crate_dir = crate_dir.as_ref();
+    let target_dir = target_dir.as_ref();
+    let crate_name = crate_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("crate directory {} has no name", crate_dir.display()),
+            )
+        })?;
+
+    // Use the same cargo that's running the tests, if any.
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let status = Command::new(cargo)
+        .arg("rustdoc")
+        .arg("--manifest-path")
+        .arg(crate_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_dir)
+        .args([
+            "--",
+            "-Zunstable-options",
+            "--document-private-items",
+            "--document-hidden-items",
+            "--output-format=json",
+        ])
+        .env("RUSTC_BOOTSTRAP", "1")
+        .status()?;
+    if !status.success() {
+        #[allow(clippy::io_other_error)] // `io::Error::other()` is newer than our MSRV
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("cargo rustdoc failed for {}: {status}", crate_dir.display()),
+        ));
+    }
+
+    crate::load_rustdoc(
+        target_dir
+            .join("doc")
+            .join(format!("{}.json", crate_name.replace('-', "_"))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::detect_rustdoc_format_version;
+
+    #[test]
+    fn format_version_detection() {
+        let version = detect_rustdoc_format_version(r#"{"format_version": 123, "index": {}}"#)
+            .expect("failed to detect format version");
+        assert_eq!(123, version);
+
+        detect_rustdoc_format_version(r#"{"index": {}}"#).expect_err("detected missing version");
+        detect_rustdoc_format_version("not JSON").expect_err("detected version of non-JSON");
+    }
+}
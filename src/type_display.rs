@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+
+use rustdoc_types::{
+    Abi, DynTrait, GenericArg, GenericArgs, GenericBound, GenericParamDef, GenericParamDefKind, Id,
+    ItemSummary, Path, Term, TraitBoundModifier, Type, TypeBindingKind, WherePredicate,
+};
+
+/// How to normalize the paths in types when rendering or comparing them,
+/// so that the same type written in different ways renders the same.
+///
+/// By default, paths are left as written in the code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PathNormalization {
+    resolve_paths: bool,
+    strip_std_prefixes: bool,
+}
+
+impl PathNormalization {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to replace paths with the canonical paths of the items they point to,
+    /// as recorded in the rustdoc, like `alloc::vec::Vec` for `Vec`
+    /// or `my_crate::shapes::Circle` for a re-exported `my_crate::Circle`.
+    ///
+    /// Paths to items the rustdoc doesn't know about are left as written.
+    pub fn with_resolved_paths(self, resolve_paths: bool) -> Self {
+        Self {
+            resolve_paths,
+            ..self
+        }
+    }
+
+    /// Whether to strip the `std::`, `core::`, and `alloc::` prefixes of paths,
+    /// since which of them a path starts with depends on which crate re-exports the item:
+    /// `std::vec::Vec` and `alloc::vec::Vec` both become `vec::Vec`.
+    pub fn with_stripped_std_prefixes(self, strip_std_prefixes: bool) -> Self {
+        Self {
+            strip_std_prefixes,
+            ..self
+        }
+    }
+
+    /// The normalized name of the given path, without its generic arguments.
+    pub(crate) fn normalize(&self, path: &Path, paths: &HashMap<Id, ItemSummary>) -> String {
+        let name = if self.resolve_paths {
+            paths.get(&path.id).map(|summary| summary.path.join("::"))
+        } else {
+            None
+        }
+        .unwrap_or_else(|| path.name.clone());
+
+        if self.strip_std_prefixes {
+            let unrooted = name.strip_prefix("::").unwrap_or(&name);
+            ["std::", "core::", "alloc::"]
+                .into_iter()
+                .find_map(|prefix| unrooted.strip_prefix(prefix))
+                .unwrap_or(unrooted)
+                .to_string()
+        } else {
+            name
+        }
+    }
+}
+
+/// Renders types the way they would be written in code, with canonical spacing.
+/// By default, paths are rendered as written in the code.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TypeRenderer<'a> {
+    /// The type to render in place of `Self`, like the self type of the impl
+    /// whose items' types are being rendered.
+    self_type: Option<&'a Type>,
+
+    /// How to normalize paths, and the crate's path summaries to normalize them with.
+    normalization: Option<(PathNormalization, &'a HashMap<Id, ItemSummary>)>,
+}
+
+impl<'a> TypeRenderer<'a> {
+    pub(crate) fn with_self_type(self, self_type: Option<&'a Type>) -> Self {
+        Self { self_type, ..self }
+    }
+
+    /// Normalize paths as configured, looking up the items they point to in the given summaries.
+    pub(crate) fn with_path_normalization(
+        self,
+        normalization: PathNormalization,
+        paths: &'a HashMap<Id, ItemSummary>,
+    ) -> Self {
+        Self {
+            normalization: Some((normalization, paths)),
+            ..self
+        }
+    }
+
+    pub(crate) fn render(&self, ty: &Type) -> String {
+        match ty {
+            Type::Generic(name) if name == "Self" => match self.self_type {
+                // The self type itself can't contain `Self`, so render it without substitution.
+                Some(self_type) => self.with_self_type(None).render(self_type),
+                None => name.clone(),
+            },
+            Type::ResolvedPath(path) => self.render_path(path),
+            Type::DynTrait(dyn_trait) => self.render_dyn_trait(dyn_trait),
+            Type::Generic(name) | Type::Primitive(name) => name.clone(),
+            Type::FunctionPointer(fn_pointer) => {
+                let mut rendered = String::new();
+                if fn_pointer.header.unsafe_ {
+                    rendered.push_str("unsafe ");
+                }
+                let inputs: Vec<_> = fn_pointer
+                    .decl
+                    .inputs
+                    .iter()
+                    .map(|(_, ty)| self.render(ty))
+                    .collect();
+                rendered.push_str(&format!("fn({})", inputs.join(", ")));
+                if let Some(output) = &fn_pointer.decl.output {
+                    rendered.push_str(" -> ");
+                    rendered.push_str(&self.render(output));
+                }
+                rendered
+            }
+            Type::Tuple(types) => match types.as_slice() {
+                [single] => format!("({},)", self.render(single)),
+                _ => format!(
+                    "({})",
+                    types
+                        .iter()
+                        .map(|ty| self.render(ty))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+            Type::Slice(ty) => format!("[{}]", self.render(ty)),
+            Type::Array { type_, len } => format!("[{}; {len}]", self.render(type_)),
+            Type::ImplTrait(bounds) => format!("impl {}", self.render_bounds(bounds)),
+            Type::Infer => "_".to_string(),
+            Type::RawPointer { mutable, type_ } => {
+                let qualifier = if *mutable { "mut" } else { "const" };
+                format!("*{qualifier} {}", self.render(type_))
+            }
+            Type::BorrowedRef {
+                lifetime,
+                mutable,
+                type_,
+            } => render_reference(lifetime.as_deref(), *mutable, &self.render(type_)),
+            Type::QualifiedPath {
+                name,
+                args,
+                self_type,
+                trait_,
+            } => format!(
+                "<{} as {}>::{name}{}",
+                self.render(self_type),
+                self.render_path(trait_),
+                self.render_generic_args(args)
+            ),
+        }
+    }
+
+    pub(crate) fn render_bounds(&self, bounds: &[GenericBound]) -> String {
+        bounds
+            .iter()
+            .map(|bound| match bound {
+                GenericBound::TraitBound {
+                    trait_, modifier, ..
+                } => {
+                    let prefix = match modifier {
+                        TraitBoundModifier::None => "",
+                        TraitBoundModifier::Maybe => "?",
+                        TraitBoundModifier::MaybeConst => "~const ",
+                    };
+                    format!("{prefix}{}", self.render_path(trait_))
+                }
+                GenericBound::Outlives(lifetime) => lifetime.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+
+    /// Renders generic parameter declarations with their inline bounds and defaults,
+    /// like `<'a, T: Clone + 'a, const N: usize>`, or nothing if there are none.
+    ///
+    /// Synthetic parameters, which stand for `impl Trait` arguments, are left out
+    /// since the arguments' types already mention them.
+    pub(crate) fn render_generic_params(&self, params: &[GenericParamDef]) -> String {
+        let rendered: Vec<_> = params
+            .iter()
+            .filter_map(|param| match &param.kind {
+                GenericParamDefKind::Lifetime { outlives } => Some(if outlives.is_empty() {
+                    param.name.clone()
+                } else {
+                    format!("{}: {}", param.name, outlives.join(" + "))
+                }),
+                GenericParamDefKind::Type {
+                    synthetic: true, ..
+                } => None,
+                GenericParamDefKind::Type {
+                    bounds, default, ..
+                } => {
+                    let mut rendered = param.name.clone();
+                    if !bounds.is_empty() {
+                        rendered.push_str(": ");
+                        rendered.push_str(&self.render_bounds(bounds));
+                    }
+                    if let Some(default) = default {
+                        rendered.push_str(" = ");
+                        rendered.push_str(&self.render(default));
+                    }
+                    Some(rendered)
+                }
+                GenericParamDefKind::Const { type_, default } => {
+                    let mut rendered = format!("const {}: {}", param.name, self.render(type_));
+                    if let Some(default) = default {
+                        rendered.push_str(" = ");
+                        rendered.push_str(default);
+                    }
+                    Some(rendered)
+                }
+            })
+            .collect();
+        if rendered.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", rendered.join(", "))
+        }
+    }
+
+    /// Renders a where clause with a leading space, like ` where T: Clone`,
+    /// or nothing if there are no predicates.
+    pub(crate) fn render_where_clause(&self, predicates: &[WherePredicate]) -> String {
+        let rendered: Vec<_> = predicates
+            .iter()
+            .map(|predicate| match predicate {
+                WherePredicate::BoundPredicate {
+                    type_,
+                    bounds,
+                    generic_params,
+                } => {
+                    let binder = if generic_params.is_empty() {
+                        String::new()
+                    } else {
+                        format!("for{} ", self.render_generic_params(generic_params))
+                    };
+                    format!(
+                        "{binder}{}: {}",
+                        self.render(type_),
+                        self.render_bounds(bounds)
+                    )
+                }
+                WherePredicate::RegionPredicate { lifetime, bounds } => {
+                    format!("{lifetime}: {}", self.render_bounds(bounds))
+                }
+                WherePredicate::EqPredicate { lhs, rhs } => {
+                    let rhs = match rhs {
+                        Term::Type(ty) => self.render(ty),
+                        Term::Constant(constant) => constant.expr.clone(),
+                    };
+                    format!("{} = {rhs}", self.render(lhs))
+                }
+            })
+            .collect();
+        if rendered.is_empty() {
+            String::new()
+        } else {
+            format!(" where {}", rendered.join(", "))
+        }
+    }
+
+    pub(crate) fn render_path(&self, path: &Path) -> String {
+        let name = match self.normalization {
+            Some((normalization, paths)) => normalization.normalize(path, paths),
+            None => path.name.clone(),
+        };
+        match &path.args {
+            Some(args) => format!("{name}{}", self.render_generic_args(args)),
+            None => name,
+        }
+    }
+
+    fn render_dyn_trait(&self, dyn_trait: &DynTrait) -> String {
+        let components: Vec<_> = dyn_trait
+            .traits
+            .iter()
+            .map(|poly_trait| self.render_path(&poly_trait.trait_))
+            .chain(dyn_trait.lifetime.clone())
+            .collect();
+        format!("dyn {}", components.join(" + "))
+    }
+
+    fn render_generic_args(&self, args: &GenericArgs) -> String {
+        match args {
+            GenericArgs::AngleBracketed { args, bindings } => {
+                let rendered: Vec<_> = args
+                    .iter()
+                    .map(|arg| match arg {
+                        GenericArg::Lifetime(lifetime) => lifetime.clone(),
+                        GenericArg::Type(ty) => self.render(ty),
+                        GenericArg::Const(constant) => constant.expr.clone(),
+                        GenericArg::Infer => "_".to_string(),
+                    })
+                    .chain(bindings.iter().map(|binding| {
+                        let name = format!(
+                            "{}{}",
+                            binding.name,
+                            self.render_generic_args(&binding.args)
+                        );
+                        match &binding.binding {
+                            TypeBindingKind::Equality(Term::Type(ty)) => {
+                                format!("{name} = {}", self.render(ty))
+                            }
+                            TypeBindingKind::Equality(Term::Constant(constant)) => {
+                                format!("{name} = {}", constant.expr)
+                            }
+                            TypeBindingKind::Constraint(bounds) => {
+                                format!("{name}: {}", self.render_bounds(bounds))
+                            }
+                        }
+                    }))
+                    .collect();
+                if rendered.is_empty() {
+                    String::new()
+                } else {
+                    format!("<{}>", rendered.join(", "))
+                }
+            }
+            GenericArgs::Parenthesized { inputs, output } => {
+                let inputs: Vec<_> = inputs.iter().map(|ty| self.render(ty)).collect();
+                match output {
+                    Some(output) => format!("({}) -> {}", inputs.join(", "), self.render(output)),
+                    None => format!("({})", inputs.join(", ")),
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn render_reference(lifetime: Option<&str>, mutable: bool, referent: &str) -> String {
+    let mut rendered = String::from("&");
+    if let Some(lifetime) = lifetime {
+        rendered.push_str(lifetime);
+        rendered.push(' ');
+    }
+    if mutable {
+        rendered.push_str("mut ");
+    }
+    rendered.push_str(referent);
+    rendered
+}
+
+/// The name of the ABI as written in `extern "..."`, without any `-unwind` suffix,
+/// and whether it had that suffix.
+pub(crate) fn abi_parts(abi: &Abi) -> (&str, bool) {
+    match abi {
+        Abi::Rust => ("Rust", false),
+        Abi::C { unwind } => ("C", *unwind),
+        Abi::Cdecl { unwind } => ("cdecl", *unwind),
+        Abi::Stdcall { unwind } => ("stdcall", *unwind),
+        Abi::Fastcall { unwind } => ("fastcall", *unwind),
+        Abi::Aapcs { unwind } => ("aapcs", *unwind),
+        Abi::Win64 { unwind } => ("win64", *unwind),
+        Abi::SysV64 { unwind } => ("sysv64", *unwind),
+        Abi::System { unwind } => ("system", *unwind),
+        Abi::Other(name) => {
+            // Rustdoc quotes the names of the ABIs it doesn't model, like `"vectorcall"`.
+            let name = name.trim_matches('"');
+            match name.strip_suffix("-unwind") {
+                Some(base) => (base, true),
+                None => (name, false),
+            }
+        }
+    }
+}
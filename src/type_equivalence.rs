@@ -0,0 +1,472 @@
+use rustdoc_types::{
+    GenericArg, GenericArgs, GenericBound, GenericParamDef, Path, PolyTrait, Term, Type,
+    TypeBinding, TypeBindingKind,
+};
+
+use crate::{type_display::PathNormalization, IndexedCrate};
+
+/// Structural comparison of types across two versions of the same crate.
+///
+/// Two types are equivalent if they have the same structure and their parts compare equal,
+/// subject to the configured normalization:
+/// - [`with_ignored_lifetimes()`](Self::with_ignored_lifetimes) makes lifetimes irrelevant,
+///   so that `&'a str` and `&str` compare equal, and so do `Foo<'a, T>` and `Foo<T>`.
+/// - [`with_resolved_paths()`](Self::with_resolved_paths) compares paths by the items
+///   they point to instead of by how they are written in the code,
+///   so that `Vec<T>` and `std::vec::Vec<T>` compare equal.
+/// - [`with_path_normalization()`](Self::with_path_normalization) configures path normalization
+///   in full, including stripping the `std::`, `core::`, and `alloc::` prefixes of paths.
+///
+/// ```no_run
+/// # use trustfall_rustdoc_adapter::{IndexedCrate, TypeEquivalence};
+/// # fn compare(current: &IndexedCrate<'_>, previous: &IndexedCrate<'_>, current_type: &rustdoc_types::Type, previous_type: &rustdoc_types::Type) {
+/// let equivalence = TypeEquivalence::new(current, previous)
+///     .with_ignored_lifetimes(true)
+///     .with_resolved_paths(true);
+/// if !equivalence.equivalent(current_type, previous_type) {
+///     println!("the type changed");
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TypeEquivalence<'a> {
+    current_crate: &'a IndexedCrate<'a>,
+    previous_crate: &'a IndexedCrate<'a>,
+    ignore_lifetimes: bool,
+    normalization: PathNormalization,
+}
+
+impl<'a> TypeEquivalence<'a> {
+    /// Compare types from `current_crate` to types from `previous_crate`, without normalization.
+    pub fn new(current_crate: &'a IndexedCrate<'a>, previous_crate: &'a IndexedCrate<'a>) -> Self {
+        Self {
+            current_crate,
+            previous_crate,
+            ignore_lifetimes: false,
+            normalization: PathNormalization::default(),
+        }
+    }
+
+    /// Whether to consider types equivalent regardless of their lifetimes.
+    pub fn with_ignored_lifetimes(self, ignore_lifetimes: bool) -> Self {
+        Self {
+            ignore_lifetimes,
+            ..self
+        }
+    }
+
+    /// Whether to compare paths by the canonical paths of the items they point to,
+    /// as recorded in each crate's rustdoc, instead of by the paths as written in the code.
+    ///
+    /// Paths to items the rustdoc doesn't know about are still compared as written.
+    pub fn with_resolved_paths(self, resolve_paths: bool) -> Self {
+        self.with_path_normalization(self.normalization.with_resolved_paths(resolve_paths))
+    }
+
+    /// Compare paths after normalizing them as configured,
+    /// e.g. so that `std::vec::Vec<T>` and `alloc::vec::Vec<T>` compare equal.
+    pub fn with_path_normalization(self, normalization: PathNormalization) -> Self {
+        Self {
+            normalization,
+            ..self
+        }
+    }
+
+    /// Whether `current` from the current crate is equivalent to `previous` from the previous one.
+    pub fn equivalent(&self, current: &Type, previous: &Type) -> bool {
+        match (current, previous) {
+            (Type::ResolvedPath(current), Type::ResolvedPath(previous)) => {
+                self.paths_equivalent(current, previous)
+            }
+            (Type::DynTrait(current), Type::DynTrait(previous)) => {
+                all_equivalent(&current.traits, &previous.traits, |current, previous| {
+                    self.poly_traits_equivalent(current, previous)
+                }) && self.lifetimes_equivalent(&current.lifetime, &previous.lifetime)
+            }
+            (Type::Generic(current), Type::Generic(previous))
+            | (Type::Primitive(current), Type::Primitive(previous)) => current == previous,
+            (Type::FunctionPointer(current), Type::FunctionPointer(previous)) => {
+                current.header == previous.header
+                    && current.decl.c_variadic == previous.decl.c_variadic
+                    && self.generic_params_equivalent(
+                        &current.generic_params,
+                        &previous.generic_params,
+                    )
+                    && all_equivalent(
+                        &current.decl.inputs,
+                        &previous.decl.inputs,
+                        |(_, current), (_, previous)| self.equivalent(current, previous),
+                    )
+                    && options_equivalent(
+                        &current.decl.output,
+                        &previous.decl.output,
+                        |current, previous| self.equivalent(current, previous),
+                    )
+            }
+            (Type::Tuple(current), Type::Tuple(previous)) => {
+                all_equivalent(current, previous, |current, previous| {
+                    self.equivalent(current, previous)
+                })
+            }
+            (Type::Slice(current), Type::Slice(previous)) => self.equivalent(current, previous),
+            (
+                Type::Array {
+                    type_: current_type,
+                    len: current_len,
+                },
+                Type::Array {
+                    type_: previous_type,
+                    len: previous_len,
+                },
+            ) => current_len == previous_len && self.equivalent(current_type, previous_type),
+            (Type::ImplTrait(current), Type::ImplTrait(previous)) => {
+                self.bounds_equivalent(current, previous)
+            }
+            (Type::Infer, Type::Infer) => true,
+            (
+                Type::RawPointer {
+                    mutable: current_mutable,
+                    type_: current_type,
+                },
+                Type::RawPointer {
+                    mutable: previous_mutable,
+                    type_: previous_type,
+                },
+            ) => {
+                current_mutable == previous_mutable && self.equivalent(current_type, previous_type)
+            }
+            (
+                Type::BorrowedRef {
+                    lifetime: current_lifetime,
+                    mutable: current_mutable,
+                    type_: current_type,
+                },
+                Type::BorrowedRef {
+                    lifetime: previous_lifetime,
+                    mutable: previous_mutable,
+                    type_: previous_type,
+                },
+            ) => {
+                current_mutable == previous_mutable
+                    && self.lifetimes_equivalent(current_lifetime, previous_lifetime)
+                    && self.equivalent(current_type, previous_type)
+            }
+            (
+                Type::QualifiedPath {
+                    name: current_name,
+                    args: current_args,
+                    self_type: current_self_type,
+                    trait_: current_trait,
+                },
+                Type::QualifiedPath {
+                    name: previous_name,
+                    args: previous_args,
+                    self_type: previous_self_type,
+                    trait_: previous_trait,
+                },
+            ) => {
+                current_name == previous_name
+                    && self.generic_args_equivalent(current_args, previous_args)
+                    && self.equivalent(current_self_type, previous_self_type)
+                    && self.paths_equivalent(current_trait, previous_trait)
+            }
+            _ => false,
+        }
+    }
+
+    fn paths_equivalent(&self, current: &Path, previous: &Path) -> bool {
+        let names_match = self
+            .normalization
+            .normalize(current, &self.current_crate.inner.paths)
+            == self
+                .normalization
+                .normalize(previous, &self.previous_crate.inner.paths);
+
+        names_match
+            && match (&current.args, &previous.args) {
+                (Some(current), Some(previous)) => self.generic_args_equivalent(current, previous),
+                (Some(args), None) | (None, Some(args)) => self.generic_args_equivalent(
+                    args,
+                    &GenericArgs::AngleBracketed {
+                        args: vec![],
+                        bindings: vec![],
+                    },
+                ),
+                (None, None) => true,
+            }
+    }
+
+    fn generic_args_equivalent(&self, current: &GenericArgs, previous: &GenericArgs) -> bool {
+        match (current, previous) {
+            (
+                GenericArgs::AngleBracketed {
+                    args: current_args,
+                    bindings: current_bindings,
+                },
+                GenericArgs::AngleBracketed {
+                    args: previous_args,
+                    bindings: previous_bindings,
+                },
+            ) => {
+                let current_args: Vec<_> = self.relevant_generic_args(current_args).collect();
+                let previous_args: Vec<_> = self.relevant_generic_args(previous_args).collect();
+                all_equivalent(&current_args, &previous_args, |current, previous| {
+                    self.generic_arg_equivalent(current, previous)
+                }) && all_equivalent(current_bindings, previous_bindings, |current, previous| {
+                    self.type_bindings_equivalent(current, previous)
+                })
+            }
+            (
+                GenericArgs::Parenthesized {
+                    inputs: current_inputs,
+                    output: current_output,
+                },
+                GenericArgs::Parenthesized {
+                    inputs: previous_inputs,
+                    output: previous_output,
+                },
+            ) => {
+                all_equivalent(current_inputs, previous_inputs, |current, previous| {
+                    self.equivalent(current, previous)
+                }) && options_equivalent(current_output, previous_output, |current, previous| {
+                    self.equivalent(current, previous)
+                })
+            }
+            _ => false,
+        }
+    }
+
+    fn relevant_generic_args<'b>(
+        &self,
+        args: &'b [GenericArg],
+    ) -> impl Iterator<Item = &'b GenericArg> {
+        let ignore_lifetimes = self.ignore_lifetimes;
+        args.iter()
+            .filter(move |arg| !(ignore_lifetimes && matches!(arg, GenericArg::Lifetime(..))))
+    }
+
+    fn generic_arg_equivalent(&self, current: &GenericArg, previous: &GenericArg) -> bool {
+        match (current, previous) {
+            (GenericArg::Lifetime(current), GenericArg::Lifetime(previous)) => {
+                self.ignore_lifetimes || current == previous
+            }
+            (GenericArg::Type(current), GenericArg::Type(previous)) => {
+                self.equivalent(current, previous)
+            }
+            (GenericArg::Const(current), GenericArg::Const(previous)) => {
+                current.expr == previous.expr
+            }
+            (GenericArg::Infer, GenericArg::Infer) => true,
+            _ => false,
+        }
+    }
+
+    fn type_bindings_equivalent(&self, current: &TypeBinding, previous: &TypeBinding) -> bool {
+        current.name == previous.name
+            && self.generic_args_equivalent(&current.args, &previous.args)
+            && match (&current.binding, &previous.binding) {
+                (
+                    TypeBindingKind::Equality(Term::Type(current)),
+                    TypeBindingKind::Equality(Term::Type(previous)),
+                ) => self.equivalent(current, previous),
+                (
+                    TypeBindingKind::Equality(Term::Constant(current)),
+                    TypeBindingKind::Equality(Term::Constant(previous)),
+                ) => current.expr == previous.expr,
+                (TypeBindingKind::Constraint(current), TypeBindingKind::Constraint(previous)) => {
+                    self.bounds_equivalent(current, previous)
+                }
+                _ => false,
+            }
+    }
+
+    fn bounds_equivalent(&self, current: &[GenericBound], previous: &[GenericBound]) -> bool {
+        all_equivalent(
+            &self.relevant_bounds(current),
+            &self.relevant_bounds(previous),
+            |current, previous| match (current, previous) {
+                (
+                    GenericBound::TraitBound {
+                        trait_: current_trait,
+                        generic_params: current_params,
+                        modifier: current_modifier,
+                    },
+                    GenericBound::TraitBound {
+                        trait_: previous_trait,
+                        generic_params: previous_params,
+                        modifier: previous_modifier,
+                    },
+                ) => {
+                    current_modifier == previous_modifier
+                        && self.generic_params_equivalent(current_params, previous_params)
+                        && self.paths_equivalent(current_trait, previous_trait)
+                }
+                (GenericBound::Outlives(current), GenericBound::Outlives(previous)) => {
+                    current == previous
+                }
+                _ => false,
+            },
+        )
+    }
+
+    fn relevant_bounds<'b>(&self, bounds: &'b [GenericBound]) -> Vec<&'b GenericBound> {
+        bounds
+            .iter()
+            .filter(|bound| !(self.ignore_lifetimes && matches!(bound, GenericBound::Outlives(..))))
+            .collect()
+    }
+
+    fn poly_traits_equivalent(&self, current: &PolyTrait, previous: &PolyTrait) -> bool {
+        self.generic_params_equivalent(&current.generic_params, &previous.generic_params)
+            && self.paths_equivalent(&current.trait_, &previous.trait_)
+    }
+
+    /// Compares higher-ranked generic parameters, like the `'a` in `for<'a> Fn(&'a str)`.
+    fn generic_params_equivalent(
+        &self,
+        current: &[GenericParamDef],
+        previous: &[GenericParamDef],
+    ) -> bool {
+        self.ignore_lifetimes || current == previous
+    }
+
+    fn lifetimes_equivalent(&self, current: &Option<String>, previous: &Option<String>) -> bool {
+        self.ignore_lifetimes || current == previous
+    }
+}
+
+fn options_equivalent<T>(
+    current: &Option<T>,
+    previous: &Option<T>,
+    equivalent: impl Fn(&T, &T) -> bool,
+) -> bool {
+    match (current, previous) {
+        (Some(current), Some(previous)) => equivalent(current, previous),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn all_equivalent<T>(current: &[T], previous: &[T], equivalent: impl Fn(&T, &T) -> bool) -> bool {
+    current.len() == previous.len()
+        && current
+            .iter()
+            .zip(previous)
+            .all(|(current, previous)| equivalent(current, previous))
+}
+
+#[cfg(test)]
+mod tests {
+    use rustdoc_types::{Crate, Id, ItemEnum, Type};
+
+    use crate::{test_util::load_pregenerated_rustdoc, IndexedCrate, PathNormalization};
+
+    use super::TypeEquivalence;
+
+    fn field_type<'a>(crate_: &'a Crate, field_name: &str) -> &'a Type {
+        crate_
+            .index
+            .values()
+            .find_map(|item| match &item.inner {
+                ItemEnum::StructField(ty) if item.name.as_deref() == Some(field_name) => Some(ty),
+                _ => None,
+            })
+            .expect("no such field")
+    }
+
+    #[test]
+    fn types_are_equivalent_to_themselves() {
+        let current = load_pregenerated_rustdoc("type_display_names");
+        let previous = load_pregenerated_rustdoc("type_display_names");
+        let current_crate = IndexedCrate::new(&current);
+        let previous_crate = IndexedCrate::new(&previous);
+        let equivalence = TypeEquivalence::new(&current_crate, &previous_crate);
+
+        let fields = ["names", "pair", "callback", "square", "bytes"];
+        for current_field in fields {
+            for previous_field in fields {
+                assert_eq!(
+                    current_field == previous_field,
+                    equivalence.equivalent(
+                        field_type(&current, current_field),
+                        field_type(&previous, previous_field)
+                    ),
+                    "{current_field} vs {previous_field}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ignored_lifetimes() {
+        let current = load_pregenerated_rustdoc("type_display_names");
+        let current_crate = IndexedCrate::new(&current);
+        let equivalence = TypeEquivalence::new(&current_crate, &current_crate);
+
+        // `&'a str` vs `&str`
+        let str_type = Type::Primitive("str".to_string());
+        let with_lifetime = Type::BorrowedRef {
+            lifetime: Some("'a".to_string()),
+            mutable: false,
+            type_: Box::new(str_type.clone()),
+        };
+        let elided = Type::BorrowedRef {
+            lifetime: None,
+            mutable: false,
+            type_: Box::new(str_type),
+        };
+
+        assert!(!equivalence.equivalent(&with_lifetime, &elided));
+        assert!(equivalence
+            .with_ignored_lifetimes(true)
+            .equivalent(&with_lifetime, &elided));
+    }
+
+    #[test]
+    fn resolved_paths() {
+        let current = load_pregenerated_rustdoc("type_display_names");
+        let current_crate = IndexedCrate::new(&current);
+        let equivalence = TypeEquivalence::new(&current_crate, &current_crate);
+
+        // `shapes::Square` vs `Square`, both pointing to the same item.
+        let as_written = field_type(&current, "square");
+        let Type::ResolvedPath(path) = as_written else {
+            panic!("unexpected type: {as_written:?}");
+        };
+        let mut renamed_path = path.clone();
+        renamed_path.name = "Square".to_string();
+        let renamed = Type::ResolvedPath(renamed_path);
+
+        assert!(!equivalence.equivalent(as_written, &renamed));
+        assert!(equivalence
+            .with_resolved_paths(true)
+            .equivalent(as_written, &renamed));
+    }
+
+    #[test]
+    fn stripped_std_prefixes() {
+        let current = load_pregenerated_rustdoc("type_display_names");
+        let current_crate = IndexedCrate::new(&current);
+        let equivalence = TypeEquivalence::new(&current_crate, &current_crate);
+
+        // `Vec<&'a str>` as written, vs `std::vec::Vec<&'a str>` with an unknown item id.
+        let as_written = field_type(&current, "names");
+        let Type::ResolvedPath(path) = as_written else {
+            panic!("unexpected type: {as_written:?}");
+        };
+        let mut std_path = path.clone();
+        std_path.name = "std::vec::Vec".to_string();
+        std_path.id = Id("not-in-the-index".to_string());
+        let std_type = Type::ResolvedPath(std_path);
+
+        let resolved = equivalence.with_resolved_paths(true);
+        assert!(!resolved.equivalent(as_written, &std_type));
+        assert!(resolved
+            .with_path_normalization(
+                PathNormalization::new()
+                    .with_resolved_paths(true)
+                    .with_stripped_std_prefixes(true)
+            )
+            .equivalent(as_written, &std_type));
+    }
+}
@@ -0,0 +1,379 @@
+use std::fmt;
+
+use rustdoc_types::{Crate, Id, Item, ItemEnum, StructKind, VariantKind};
+
+use crate::IndexedCrate;
+
+/// The problems found by [`verify()`] in a crate's rustdoc JSON,
+/// or by [`IndexedCrate::verify()`] in its indexes,
+/// sorted so that reports of the same data are identical.
+///
+/// Rustdoc JSON that isn't internally consistent, for example because it was
+/// edited by hand or produced by a buggy rustdoc, can make indexing and queries panic
+/// in ways that are hard to trace back to the data. Checking it first
+/// turns those panics into a list of the offending items.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerificationReport {
+    pub problems: Vec<Inconsistency>,
+}
+
+impl VerificationReport {
+    /// Whether no problems were found.
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+impl fmt::Display for VerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.problems.is_empty() {
+            return write!(f, "no problems found");
+        }
+        write!(f, "{} problem(s) found:", self.problems.len())?;
+        for problem in &self.problems {
+            write!(f, "\n- {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A problem with a crate's rustdoc JSON, as found by [`verify()`],
+/// or with its indexes, as found by [`IndexedCrate::verify()`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// The crate's root module isn't in the crate's index.
+    MissingRoot { root: Id },
+
+    /// The crate's root is in the index, but isn't a module.
+    RootNotModule { root: Id },
+
+    /// An item contains or lists an item that isn't in the crate's index,
+    /// like a module listing a missing item or a struct listing a missing field.
+    DanglingId { item: Id, missing: Id },
+
+    /// A type or trait lists an item among its impls that isn't an impl.
+    NotAnImpl { item: Id, listed: Id },
+
+    /// An item of a kind that always has a name, like a struct or a function, has none.
+    MissingName { item: Id },
+
+    /// An item is listed by more than one module, impl, or other containing item,
+    /// but the crate's indexes record only `recorded` as the item containing it,
+    /// so queries never see it as part of `other`.
+    ConflictingParents { item: Id, recorded: Id, other: Id },
+}
+
+impl Inconsistency {
+    /// Orders problems by their kind, then by the ids of the items involved.
+    fn sort_key(&self) -> (u8, &str, &str) {
+        match self {
+            Self::MissingRoot { root } => (0, &root.0, ""),
+            Self::RootNotModule { root } => (1, &root.0, ""),
+            Self::DanglingId { item, missing } => (2, &item.0, &missing.0),
+            Self::NotAnImpl { item, listed } => (3, &item.0, &listed.0),
+            Self::MissingName { item } => (4, &item.0, ""),
+            Self::ConflictingParents { item, other, .. } => (5, &item.0, &other.0),
+        }
+    }
+}
+
+impl fmt::Display for Inconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRoot { root } => {
+                write!(f, "root module {root:?} isn't in the crate's index")
+            }
+            Self::RootNotModule { root } => write!(f, "root item {root:?} isn't a module"),
+            Self::DanglingId { item, missing } => write!(
+                f,
+                "item {item:?} refers to item {missing:?}, which isn't in the crate's index"
+            ),
+            Self::NotAnImpl { item, listed } => {
+                write!(
+                    f,
+                    "item {item:?} lists item {listed:?} as an impl, but it isn't one"
+                )
+            }
+            Self::MissingName { item } => write!(f, "item {item:?} has no name"),
+            Self::ConflictingParents {
+                item,
+                recorded,
+                other,
+            } => write!(
+                f,
+                "item {item:?} is listed by both {recorded:?} and {other:?}, \
+                but is only indexed as part of {recorded:?}"
+            ),
+        }
+    }
+}
+
+/// Check a crate's rustdoc JSON for inconsistencies that would otherwise surface as panics
+/// while indexing it or deep inside queries, like items referring to items missing
+/// from the index, types listing items that aren't impls among their impls,
+/// or items missing their names.
+///
+/// Unlike [`IndexedCrate::verify()`], this runs before the crate is indexed,
+/// so it can check data that [`IndexedCrate::new()`] would panic on.
+pub fn verify(crate_: &Crate) -> VerificationReport {
+    let mut problems = crate_problems(crate_);
+    problems.sort_unstable_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+    VerificationReport { problems }
+}
+
+/// Check both the crate's rustdoc JSON, as in [`verify()`], and the indexes built from it.
+pub(crate) fn verify_indexed_crate(indexed_crate: &IndexedCrate<'_>) -> VerificationReport {
+    let mut problems = crate_problems(indexed_crate.inner);
+    problems.extend(index_problems(indexed_crate));
+    problems.sort_unstable_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+    VerificationReport { problems }
+}
+
+fn crate_problems(crate_: &Crate) -> Vec<Inconsistency> {
+    let mut problems = vec![];
+
+    match crate_.index.get(&crate_.root) {
+        Some(root) if !matches!(root.inner, ItemEnum::Module(..)) => {
+            problems.push(Inconsistency::RootNotModule {
+                root: crate_.root.clone(),
+            });
+        }
+        Some(..) => {}
+        None => problems.push(Inconsistency::MissingRoot {
+            root: crate_.root.clone(),
+        }),
+    }
+
+    for item in crate_.index.values() {
+        if item.name.is_none() && requires_name(&item.inner) {
+            problems.push(Inconsistency::MissingName {
+                item: item.id.clone(),
+            });
+        }
+
+        for contained_id in contained_ids(&item.inner) {
+            if !crate_.index.contains_key(contained_id) {
+                problems.push(Inconsistency::DanglingId {
+                    item: item.id.clone(),
+                    missing: contained_id.clone(),
+                });
+            }
+        }
+
+        for impl_id in listed_impls(&item.inner) {
+            match crate_.index.get(impl_id) {
+                Some(Item {
+                    inner: ItemEnum::Impl(..),
+                    ..
+                }) => {}
+                Some(..) => problems.push(Inconsistency::NotAnImpl {
+                    item: item.id.clone(),
+                    listed: impl_id.clone(),
+                }),
+                None => problems.push(Inconsistency::DanglingId {
+                    item: item.id.clone(),
+                    missing: impl_id.clone(),
+                }),
+            }
+        }
+    }
+
+    problems
+}
+
+/// Check that the indexes mapping items to the item containing them agree
+/// with every container listing the item.
+fn index_problems(indexed_crate: &IndexedCrate<'_>) -> Vec<Inconsistency> {
+    let mut problems = vec![];
+    for container in indexed_crate.inner.index.values() {
+        let parent_index = match &container.inner {
+            ItemEnum::Module(..) => indexed_crate.parent_module_index.as_deref(),
+            ItemEnum::Impl(..) => indexed_crate.item_impl_index.as_deref(),
+            _ => indexed_crate.container_index.as_deref(),
+        };
+        let Some(parent_index) = parent_index else {
+            continue;
+        };
+
+        for contained_id in contained_ids(&container.inner) {
+            match parent_index.get(contained_id) {
+                Some(recorded) if recorded.id != container.id => {
+                    problems.push(Inconsistency::ConflictingParents {
+                        item: contained_id.clone(),
+                        recorded: recorded.id.clone(),
+                        other: container.id.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+    problems
+}
+
+fn requires_name(inner: &ItemEnum) -> bool {
+    !matches!(
+        inner,
+        ItemEnum::Impl(..) | ItemEnum::Import(..) | ItemEnum::ExternCrate { .. }
+    )
+}
+
+/// The ids of the items defined within the given item, which rustdoc includes in the index.
+fn contained_ids(inner: &ItemEnum) -> Vec<&Id> {
+    match inner {
+        ItemEnum::Module(module) => module.items.iter().collect(),
+        ItemEnum::Struct(struct_) => match &struct_.kind {
+            StructKind::Unit => vec![],
+            StructKind::Tuple(fields) => fields.iter().flatten().collect(),
+            StructKind::Plain { fields, .. } => fields.iter().collect(),
+        },
+        ItemEnum::Union(union_) => union_.fields.iter().collect(),
+        ItemEnum::Enum(enum_) => enum_.variants.iter().collect(),
+        ItemEnum::Variant(variant) => match &variant.kind {
+            VariantKind::Plain => vec![],
+            VariantKind::Tuple(fields) => fields.iter().flatten().collect(),
+            VariantKind::Struct { fields, .. } => fields.iter().collect(),
+        },
+        ItemEnum::Trait(trait_) => trait_.items.iter().collect(),
+        ItemEnum::Impl(impl_) => impl_.items.iter().collect(),
+        _ => vec![],
+    }
+}
+
+/// The ids the given item lists as its impls, or as the impls of a trait.
+fn listed_impls(inner: &ItemEnum) -> &[Id] {
+    match inner {
+        ItemEnum::Struct(struct_) => &struct_.impls,
+        ItemEnum::Enum(enum_) => &enum_.impls,
+        ItemEnum::Union(union_) => &union_.impls,
+        ItemEnum::Primitive(primitive) => &primitive.impls,
+        ItemEnum::Trait(trait_) => &trait_.implementations,
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustdoc_types::{Id, ItemEnum};
+
+    use crate::{test_util::load_pregenerated_rustdoc, IndexedCrate};
+
+    use super::{verify, Inconsistency};
+
+    #[test]
+    fn consistent_crate() {
+        let rustdoc = load_pregenerated_rustdoc("trait_impls");
+        let report = verify(&rustdoc);
+        assert!(report.is_ok(), "{report}");
+
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+        let report = indexed_crate.verify();
+        assert!(report.is_ok(), "{report}");
+    }
+
+    #[test]
+    fn inconsistent_crate() {
+        let mut rustdoc = load_pregenerated_rustdoc("trait_impls");
+        let find_item = |name: &str| -> Id {
+            rustdoc
+                .index
+                .values()
+                .find(|item| item.name.as_deref() == Some(name))
+                .map(|item| item.id.clone())
+                .expect("no such item")
+        };
+        let foo = find_item("Foo");
+        let new = find_item("new");
+        let marker = find_item("Marker");
+
+        // Remove the `Foo::new` method, leaving its impl pointing at it.
+        rustdoc.index.remove(&new);
+        // Drop the name of the `Marker` trait.
+        rustdoc
+            .index
+            .get_mut(&marker)
+            .expect("no Marker trait")
+            .name = None;
+        // List the `Marker` trait as one of the `Foo` struct's impls.
+        let ItemEnum::Struct(foo_struct) = &mut rustdoc.index.get_mut(&foo).expect("no Foo").inner
+        else {
+            panic!("Foo is not a struct");
+        };
+        foo_struct.impls.push(marker.clone());
+
+        // Indexing this crate would panic on the non-impl listed among `Foo`'s impls.
+        let report = verify(&rustdoc);
+
+        let inherent_impl = rustdoc
+            .index
+            .values()
+            .find(|item| matches!(&item.inner, ItemEnum::Impl(impl_) if impl_.items.contains(&new)))
+            .map(|item| item.id.clone())
+            .expect("no impl containing Foo::new");
+        let mut expected = vec![
+            Inconsistency::DanglingId {
+                item: inherent_impl,
+                missing: new,
+            },
+            Inconsistency::NotAnImpl {
+                item: foo,
+                listed: marker.clone(),
+            },
+            Inconsistency::MissingName { item: marker },
+        ];
+        expected.sort_unstable_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+        assert_eq!(expected, report.problems);
+    }
+
+    #[test]
+    fn conflicting_parents() {
+        let mut rustdoc = load_pregenerated_rustdoc("trait_impls");
+        let new = rustdoc
+            .index
+            .values()
+            .find(|item| item.name.as_deref() == Some("new"))
+            .map(|item| item.id.clone())
+            .expect("no item named new");
+        let mut impl_ids = rustdoc
+            .index
+            .values()
+            .filter(|item| matches!(item.inner, ItemEnum::Impl(..)))
+            .map(|item| item.id.clone());
+        let inherent_impl = impl_ids.clone().find(|id| {
+            matches!(&rustdoc.index[id].inner, ItemEnum::Impl(impl_) if impl_.items.contains(&new))
+        });
+        let inherent_impl = inherent_impl.expect("no impl containing Foo::new");
+        let other_impl = impl_ids
+            .find(|id| id != &inherent_impl)
+            .expect("no other impl");
+
+        // List the `Foo::new` method in a second impl as well.
+        let ItemEnum::Impl(impl_) = &mut rustdoc.index.get_mut(&other_impl).expect("no impl").inner
+        else {
+            panic!("not an impl");
+        };
+        impl_.items.push(new.clone());
+
+        // The rustdoc JSON itself only refers to items that exist.
+        assert!(verify(&rustdoc).is_ok());
+
+        // Each item is indexed as part of only one impl, so one of the two impls is ignored.
+        let indexed_crate = IndexedCrate::new(&rustdoc);
+        let report = indexed_crate.verify();
+        let [Inconsistency::ConflictingParents {
+            item,
+            recorded,
+            other,
+        }] = report.problems.as_slice()
+        else {
+            panic!("unexpected problems: {report}");
+        };
+        assert_eq!(&new, item);
+        let mut parents = [recorded.0.as_str(), other.0.as_str()];
+        parents.sort_unstable();
+        let mut expected = [inherent_impl.0.as_str(), other_impl.0.as_str()];
+        expected.sort_unstable();
+        assert_eq!(expected, parents);
+    }
+}
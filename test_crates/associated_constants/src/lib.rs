@@ -0,0 +1,26 @@
+//! Associated constants with values of varying complexity:
+//! - in the `Limits` trait, `MAX_ITEMS` without a default and `ENABLED` with one
+//! - in the impl for `Config`, constants set to literals, integer arithmetic,
+//!   and an expression referring to another item, which can't be evaluated
+
+pub trait Limits {
+    const MAX_ITEMS: usize;
+
+    const ENABLED: bool = true;
+}
+
+pub struct Config;
+
+impl Limits for Config {
+    const MAX_ITEMS: usize = 4 * 1024;
+}
+
+impl Config {
+    pub const RATIO: f64 = 1.5;
+
+    pub const NAME: &'static str = "config";
+
+    pub const OFFSET: i32 = -3;
+
+    pub const LARGEST: u8 = u8::MAX;
+}
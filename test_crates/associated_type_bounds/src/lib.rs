@@ -0,0 +1,12 @@
+//! Associated types with trait and lifetime bounds:
+//! - `Container::Item` has trait bounds on a built-in trait and a local trait,
+//!   and a lifetime bound
+//! - `Container::Error` has no bounds at all
+
+pub trait Marker {}
+
+pub trait Container {
+    type Item: Clone + Marker + 'static;
+
+    type Error;
+}
@@ -0,0 +1,11 @@
+//! Foreign functions with and without variadic arguments:
+//! - `printf` is variadic
+//! - `puts` is not
+
+use std::os::raw::{c_char, c_int};
+
+extern "C" {
+    pub fn printf(format: *const c_char, ...) -> c_int;
+
+    pub fn puts(s: *const c_char) -> c_int;
+}
@@ -0,0 +1,14 @@
+//! Free and associated constants:
+//! - `SIZE` is a free constant whose value is computed from an expression
+//! - `GREETING` is a free constant set to a string literal
+//! - `Limits::MAX` is an associated constant in an inherent impl
+
+pub const SIZE: usize = 4 * 1024;
+
+pub const GREETING: &'static str = "hello";
+
+pub struct Limits;
+
+impl Limits {
+    pub const MAX: u32 = 10;
+}
@@ -0,0 +1,14 @@
+//! The baseline version of the crate in `counterparts_current`:
+//! - `Moved` is defined at the crate root
+//! - `Kind` is a struct
+//! - `Removed` is removed in the current version
+
+pub struct Moved;
+
+pub struct Kind;
+
+pub struct Removed;
+
+pub mod shapes {
+    pub struct Circle;
+}
@@ -0,0 +1,20 @@
+//! The current version of the crate in `counterparts_baseline`:
+//! - `Moved` is defined in a module, and re-exported at the crate root
+//! - `Kind` became an enum
+//! - `Added` is new in this version
+
+pub mod inner {
+    pub struct Moved;
+}
+
+pub use inner::Moved;
+
+pub enum Kind {
+    Only,
+}
+
+pub struct Added;
+
+pub mod shapes {
+    pub struct Circle;
+}
@@ -0,0 +1,10 @@
+//! A crate with crate-level doc attributes, used for cross-linking documentation.
+
+#![doc(html_root_url = "https://docs.rs/crate_doc_attributes/0.1.0")]
+#![doc(
+    html_logo_url = "https://example.com/logo.png",
+    html_playground_url = "https://play.rust-lang.org/"
+)]
+#![no_std]
+
+pub struct Foo;
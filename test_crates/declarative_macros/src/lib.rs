@@ -0,0 +1,26 @@
+//! Declarative macros with and without `#[macro_export]`:
+//! - `exported`, usable from other crates as `declarative_macros::exported!`
+//! - `exported_local_inner`, exported with `local_inner_macros`
+//! - `local_helper`, only usable within this crate despite being in a `pub mod`
+
+#[macro_export]
+macro_rules! exported {
+    () => {};
+}
+
+#[macro_export(local_inner_macros)]
+macro_rules! exported_local_inner {
+    () => {
+        exported!()
+    };
+}
+
+pub mod helpers {
+    macro_rules! local_helper {
+        () => {};
+    }
+
+    pub(crate) use local_helper;
+}
+
+helpers::local_helper!();
@@ -0,0 +1,20 @@
+//! Deprecated and non-deprecated items:
+//! - `old_function`, `OldStruct`, and `Current::old_method` are `#[deprecated]`
+//! - `new_function`, `Current`, and `Current::new_method` aren't
+
+#[deprecated]
+pub fn old_function() {}
+
+pub fn new_function() {}
+
+#[deprecated(since = "1.2.0", note = "use `Current` instead")]
+pub struct OldStruct;
+
+pub struct Current;
+
+impl Current {
+    #[deprecated(note = "use `new_method` instead")]
+    pub fn old_method(&self) {}
+
+    pub fn new_method(&self) {}
+}
@@ -0,0 +1,18 @@
+//! Items marked `#[doc(hidden)]` are only present in rustdoc JSON
+//! generated with `--document-hidden-items`.
+//!
+//! This package exports the following:
+//! - the struct `Visible`, which is not hidden
+//! - the struct `Hidden`, which is hidden
+//! - the function `__private::macro_support`, which isn't hidden itself
+//!   but is inside a hidden module
+
+pub struct Visible;
+
+#[doc(hidden)]
+pub struct Hidden;
+
+#[doc(hidden)]
+pub mod __private {
+    pub fn macro_support() {}
+}
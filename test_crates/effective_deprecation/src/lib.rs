@@ -0,0 +1,39 @@
+//! Items deprecated through the items enclosing them:
+//! - `inside` and `InOld` are in the deprecated module `old`
+//! - `OldType::method` is in an impl of the deprecated struct `OldType`,
+//!   and `field` is one of its fields
+//! - `x` is a field of a variant of the deprecated enum `OldEnum`
+//! - `fine` and `NewType::new_method` aren't deprecated at all
+
+#![allow(deprecated)]
+
+#[deprecated]
+pub mod old {
+    pub fn inside() {}
+
+    pub struct InOld;
+}
+
+pub mod current {
+    pub fn fine() {}
+
+    #[deprecated]
+    pub struct OldType {
+        pub field: i64,
+    }
+
+    impl OldType {
+        pub fn method(&self) {}
+    }
+
+    pub struct NewType;
+
+    impl NewType {
+        pub fn new_method(&self) {}
+    }
+
+    #[deprecated]
+    pub enum OldEnum {
+        Variant { x: i64 },
+    }
+}
@@ -0,0 +1,27 @@
+//! Items whose effective visibility differs from their declared visibility:
+//! - `Visible` and its method `visible_method` are public and reachable
+//! - `Reexported` is declared in a private module, but is re-exported
+//! - `Stranded` and its method `stranded_method` are `pub`, but not reachable
+//! - `crate_function` is `pub(crate)`, and `private_function` is private
+
+mod private {
+    pub struct Stranded;
+
+    impl Stranded {
+        pub fn stranded_method(&self) {}
+    }
+
+    pub struct Reexported;
+
+    pub(crate) fn crate_function() {}
+
+    fn private_function() {}
+}
+
+pub use private::Reexported;
+
+pub struct Visible;
+
+impl Visible {
+    pub fn visible_method(&self) {}
+}
@@ -0,0 +1,25 @@
+//! Explicit enum discriminants written in different ways.
+
+#[repr(u8)]
+pub enum Flags {
+    Literal = 16,
+    Hex = 0x20,
+    Shift = 1 << 6,
+    Implicit,
+}
+
+#[repr(i64)]
+pub enum Signed {
+    Negative = -1,
+    Expression = i64::MAX - 1,
+}
+
+#[repr(u64)]
+pub enum Unsigned {
+    Max = u64::MAX,
+}
+
+#[repr(u128)]
+pub enum Wide {
+    TooLarge = u128::MAX,
+}
@@ -0,0 +1,22 @@
+//! Enums that downstream users can and can't match on exhaustively:
+//! - `Exhaustive` has only public variants
+//! - `NonExhaustive` is `#[non_exhaustive]`
+//! - `WithHiddenVariant` has a `#[doc(hidden)]` variant, which isn't matchable
+
+pub enum Exhaustive {
+    First,
+    Second(i64),
+}
+
+#[non_exhaustive]
+pub enum NonExhaustive {
+    First,
+    Second { value: i64 },
+}
+
+pub enum WithHiddenVariant {
+    First,
+    #[doc(hidden)]
+    __Hidden,
+    Second,
+}
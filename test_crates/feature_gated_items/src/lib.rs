@@ -0,0 +1,19 @@
+//! Items documented as requiring features with `#[doc(cfg(...))]`:
+//! - `serde_support`, requiring `serde`, and `Serializer` inside it, inheriting the requirement
+//! - `StdAndAlloc`, requiring both `std` and `alloc`
+//! - `StdOrAlloc`, requiring `std` or `alloc`, so neither of them on its own
+//! - `Ungated`, requiring no features
+#![feature(doc_cfg)]
+
+#[doc(cfg(feature = "serde"))]
+pub mod serde_support {
+    pub struct Serializer;
+}
+
+#[doc(cfg(all(feature = "std", feature = "alloc")))]
+pub struct StdAndAlloc;
+
+#[doc(cfg(any(feature = "std", feature = "alloc")))]
+pub struct StdOrAlloc;
+
+pub struct Ungated;
@@ -0,0 +1,32 @@
+//! Items that are and aren't part of the crate's FFI surface:
+//! - `add` is `extern "C"` and `#[no_mangle]`
+//! - `callback` is `extern "C"` but mangled
+//! - `renamed` is a Rust-ABI function exported as `ffi_renamed`
+//! - `COUNTER` is a `#[no_mangle]` static, while `INTERNAL` isn't exported
+//! - `puts` is imported from an `extern` block, so it isn't part of the FFI surface
+//! - `rust_only` is a plain Rust function
+
+use std::os::raw::{c_char, c_int};
+
+#[no_mangle]
+pub extern "C" fn add(left: c_int, right: c_int) -> c_int {
+    left + right
+}
+
+pub extern "C" fn callback(value: c_int) -> c_int {
+    value
+}
+
+#[export_name = "ffi_renamed"]
+pub fn renamed() {}
+
+#[no_mangle]
+pub static COUNTER: c_int = 0;
+
+pub static INTERNAL: c_int = 0;
+
+extern "C" {
+    pub fn puts(s: *const c_char) -> c_int;
+}
+
+pub fn rust_only() {}
@@ -0,0 +1,31 @@
+//! Impls of local traits for types defined outside this crate:
+//! - `Describe` is implemented for the local `Local`, and for the foreign `Vec<T>` and `String`
+//! - `Marker` is only implemented through a blanket impl
+
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+pub struct Local;
+
+impl Describe for Local {
+    fn describe(&self) -> String {
+        "local".to_string()
+    }
+}
+
+impl<T> Describe for Vec<T> {
+    fn describe(&self) -> String {
+        format!("{} items", self.len())
+    }
+}
+
+impl Describe for String {
+    fn describe(&self) -> String {
+        self.clone()
+    }
+}
+
+pub trait Marker {}
+
+impl<T> Marker for T {}
@@ -0,0 +1,19 @@
+//! Functions with different ABIs:
+//! - `rust_abi` uses the default Rust ABI
+//! - `c_abi` is `extern "C"`, and `c_unwind_abi` is `extern "C-unwind"`
+//! - `system_unwind_abi` is `extern "system-unwind"`
+//! - `foreign_c_unwind` is declared in an `extern "C-unwind"` block
+
+#![feature(c_unwind)]
+
+pub fn rust_abi() {}
+
+pub extern "C" fn c_abi() {}
+
+pub extern "C-unwind" fn c_unwind_abi() {}
+
+pub extern "system-unwind" fn system_unwind_abi() {}
+
+extern "C-unwind" {
+    pub fn foreign_c_unwind();
+}
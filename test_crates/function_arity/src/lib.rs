@@ -0,0 +1,27 @@
+//! Functions and methods with varying numbers of parameters and generic parameters:
+//! - `nullary` has none of either
+//! - `longest` has two parameters and an explicit lifetime
+//! - `convert` has one explicit type parameter and one synthetic one from `impl Into<T>`
+//! - `Counter::add` takes `self` plus one parameter, and its impl has no generics
+
+pub fn nullary() {}
+
+pub fn longest<'a>(first: &'a str, second: &'a str) -> &'a str {
+    if first.len() >= second.len() {
+        first
+    } else {
+        second
+    }
+}
+
+pub fn convert<T>(value: impl Into<T>) -> T {
+    value.into()
+}
+
+pub struct Counter(pub u64);
+
+impl Counter {
+    pub fn add(&mut self, amount: u64) {
+        self.0 += amount;
+    }
+}
@@ -0,0 +1,22 @@
+//! Generic type parameters with and without lifetime bounds:
+//! - `Inline::T` has a lifetime bound declared inline
+//! - `WhereClause::T` has a lifetime bound declared in a `where` clause
+//! - `spawn::F` has both a `'static` bound and a trait bound
+//! - `Unbounded::T` has no lifetime bounds
+
+pub struct Inline<'a, T: 'a> {
+    pub value: &'a T,
+}
+
+pub struct WhereClause<'a, T>
+where
+    T: 'a,
+{
+    pub value: &'a T,
+}
+
+pub fn spawn<F: FnOnce() + 'static>(_f: F) {}
+
+pub struct Unbounded<T> {
+    pub value: T,
+}
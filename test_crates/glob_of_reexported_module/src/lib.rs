@@ -0,0 +1,13 @@
+//! This package exports the following:
+//! - `inner::foo()`
+//! - `foo()`, through a glob import of a re-export of `inner`
+
+pub mod inner {
+    pub fn foo() {}
+}
+
+mod private {
+    pub use crate::inner as renamed;
+}
+
+pub use private::renamed::*;
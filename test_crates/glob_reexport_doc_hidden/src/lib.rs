@@ -0,0 +1,19 @@
+//! Glob re-exports of modules containing `#[doc(hidden)]` items.
+//!
+//! This package exports the following:
+//! - the struct `Visible`, as `inner::Visible` and through the glob as `Visible`
+//! - the hidden struct `Hidden`, as `inner::Hidden` and through the glob as `Hidden`
+//! - the hidden struct `Named`, as `inner::Named` and through an explicit re-export as `Named`
+
+pub mod inner {
+    pub struct Visible;
+
+    #[doc(hidden)]
+    pub struct Hidden;
+
+    #[doc(hidden)]
+    pub struct Named;
+}
+
+pub use inner::*;
+pub use inner::Named;
@@ -0,0 +1,17 @@
+//! Re-exports combining `#[doc(hidden)]` on the import and on the imported item:
+//! - `Visible`, re-exported both as is and by a hidden `pub use` as `HiddenAlias`
+//! - `Hidden`, a hidden struct re-exported by a visible `pub use`
+
+mod inner {
+    pub struct Visible;
+
+    #[doc(hidden)]
+    pub struct Hidden;
+}
+
+pub use inner::Visible;
+
+#[doc(hidden)]
+pub use inner::Visible as HiddenAlias;
+
+pub use inner::Hidden;
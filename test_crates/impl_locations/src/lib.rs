@@ -0,0 +1,36 @@
+//! Impl blocks for the same type, defined in different modules:
+//! - the inherent impl of `Circle` is next to it, in `shapes`
+//! - the impl of `traits::Area` for `Circle` is in `traits`
+//! - the impl of `Display` for `Circle` is in the crate's root module
+
+use std::fmt;
+
+pub mod shapes {
+    pub struct Circle {
+        pub radius: f64,
+    }
+
+    impl Circle {
+        pub fn diameter(&self) -> f64 {
+            self.radius * 2.0
+        }
+    }
+}
+
+pub mod traits {
+    pub trait Area {
+        fn area(&self) -> f64;
+    }
+
+    impl Area for crate::shapes::Circle {
+        fn area(&self) -> f64 {
+            std::f64::consts::PI * self.radius * self.radius
+        }
+    }
+}
+
+impl fmt::Display for shapes::Circle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "circle of radius {}", self.radius)
+    }
+}
@@ -0,0 +1,22 @@
+//! Public items of kinds that are importable, even though they aren't
+//! structs, enums, traits, or functions: a constant, a static, a union,
+//! an exported macro, a trait alias, and a module.
+#![feature(trait_alias)]
+
+pub const CONSTANT: u32 = 1;
+
+pub static STATIC: u32 = 2;
+
+pub union Union {
+    pub int: u32,
+    pub float: f32,
+}
+
+#[macro_export]
+macro_rules! exported_macro {
+    () => {};
+}
+
+pub trait TraitAlias = Clone + Send;
+
+pub mod nested {}
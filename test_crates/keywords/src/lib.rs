@@ -0,0 +1,11 @@
+//! Modules, one of which is a keyword's documentation page:
+//! - `match_keyword`, documenting the `match` keyword with `#[doc(keyword = "match")]`
+//! - `ordinary`, a plain module
+
+#![feature(rustdoc_internals)]
+
+/// The `match` keyword.
+#[doc(keyword = "match")]
+mod match_keyword {}
+
+pub mod ordinary {}
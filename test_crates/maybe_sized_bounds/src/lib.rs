@@ -0,0 +1,20 @@
+//! Generic type parameters and associated types with and without `?Sized` bounds:
+//! - `inline::T` is `?Sized`, declared inline
+//! - `WhereClause::T` is `?Sized`, declared in a `where` clause next to a `Send` bound
+//! - `sized::T` has a `Clone` bound, but isn't `?Sized`
+//! - `Deref::Target` is `?Sized`
+
+pub fn inline<T: ?Sized>(_value: &T) {}
+
+pub struct WhereClause<T>
+where
+    T: ?Sized + Send,
+{
+    pub value: Box<T>,
+}
+
+pub fn sized<T: Clone>(_value: T) {}
+
+pub trait Deref {
+    type Target: ?Sized;
+}
@@ -0,0 +1,85 @@
+//! Methods available on types through their own impls and through `Deref`:
+//! - `Inner` has the methods `len()`, `shadowed()`, and the associated function `new()`
+//! - `Wrapper` derefs to `Inner`, and has its own methods `shadowed()` and `describe()`
+//!   from the `Describe` trait
+//! - `Outer` derefs to `Wrapper`, and has no methods of its own besides `deref()`
+//! - `Impostor` implements a trait that is named `Deref` but isn't `std::ops::Deref`,
+//!   so it only has its own `deref()` method
+
+use std::ops::Deref;
+
+pub struct Inner {
+    items: Vec<i64>,
+}
+
+impl Inner {
+    pub fn new() -> Self {
+        Self { items: vec![] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn shadowed(&self) -> &'static str {
+        "inner"
+    }
+}
+
+pub trait Describe {
+    fn describe(&self) -> String {
+        "a value".to_string()
+    }
+}
+
+pub struct Wrapper {
+    inner: Inner,
+}
+
+impl Wrapper {
+    pub fn shadowed(&self) -> &'static str {
+        "wrapper"
+    }
+}
+
+impl Describe for Wrapper {}
+
+impl Deref for Wrapper {
+    type Target = Inner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+pub struct Outer {
+    wrapper: Wrapper,
+}
+
+impl Deref for Outer {
+    type Target = Wrapper;
+
+    fn deref(&self) -> &Self::Target {
+        &self.wrapper
+    }
+}
+
+pub mod lookalike {
+    pub trait Deref {
+        type Target;
+
+        fn deref(&self) -> &Self::Target;
+    }
+}
+
+pub struct Impostor {
+    inner: Inner,
+}
+
+impl lookalike::Deref for Impostor {
+    type Target = Inner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
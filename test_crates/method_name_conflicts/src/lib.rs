@@ -0,0 +1,38 @@
+//! Types whose impls provide multiple functions with the same name:
+//! - `Widget` has an inherent `draw()` method and one from the `Paint` trait,
+//!   and `resize()` methods from both the `Layout` and `Scale` traits
+//! - `Label` has a `draw()` method from the `Paint` trait only, so it has no conflicts
+
+pub trait Paint {
+    fn draw(&self) {}
+}
+
+pub trait Layout {
+    fn resize(&mut self, width: u32);
+}
+
+pub trait Scale {
+    fn resize(&mut self, factor: u32) {
+        let _ = factor;
+    }
+}
+
+pub struct Widget;
+
+impl Widget {
+    pub fn draw(&self) {}
+}
+
+impl Paint for Widget {}
+
+impl Layout for Widget {
+    fn resize(&mut self, width: u32) {
+        let _ = width;
+    }
+}
+
+impl Scale for Widget {}
+
+pub struct Label;
+
+impl Paint for Label {}
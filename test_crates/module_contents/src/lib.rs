@@ -0,0 +1,25 @@
+//! Modules whose contents are defined in nested modules or re-exported:
+//! - `internal` defines `Internal`, and its nested module `nested` defines `nested_fn`
+//! - `internal` re-exports `Reexported` by name, and `globbed_fn` through a glob import
+//! - `NotReexported` is defined next to `Reexported`, but isn't re-exported
+
+pub mod internal {
+    pub struct Internal;
+
+    pub mod nested {
+        pub fn nested_fn() {}
+    }
+
+    pub use crate::elsewhere::Reexported;
+    pub use crate::globbed::*;
+}
+
+pub mod elsewhere {
+    pub struct Reexported;
+
+    pub struct NotReexported;
+}
+
+pub mod globbed {
+    pub fn globbed_fn() {}
+}
@@ -0,0 +1,28 @@
+//! `#[macro_export]` macros defined in nested modules, which are importable
+//! at the crate root rather than through the modules defining them:
+//! - `in_public_module`, defined in `pub mod outer::inner`
+//! - `in_private_module`, defined in a private module
+//! - `reexported`, also re-exported with `pub use` from `outer`
+
+pub mod outer {
+    pub mod inner {
+        #[macro_export]
+        macro_rules! in_public_module {
+            () => {};
+        }
+    }
+
+    #[macro_export]
+    macro_rules! reexported {
+        () => {};
+    }
+
+    pub use crate::reexported;
+}
+
+mod private {
+    #[macro_export]
+    macro_rules! in_private_module {
+        () => {};
+    }
+}
@@ -0,0 +1,17 @@
+//! Enums whose variants are `#[non_exhaustive]` independently of the enum:
+//! - `Event` isn't `#[non_exhaustive]`, but its `Click` and `Key` variants are
+//! - `Shape` is `#[non_exhaustive]`, but none of its variants are
+
+pub enum Event {
+    #[non_exhaustive]
+    Click { x: i64, y: i64 },
+    #[non_exhaustive]
+    Key(char),
+    Close,
+}
+
+#[non_exhaustive]
+pub enum Shape {
+    Circle { radius: f64 },
+    Point,
+}
@@ -0,0 +1,14 @@
+//! Traits with and without `#[doc(notable_trait)]`:
+//! - `Notable`, marked with `#[doc(notable_trait)]`
+//! - `AlsoNotable`, marked alongside another `doc` argument
+//! - `Ordinary`, not marked
+
+#![feature(doc_notable_trait)]
+
+#[doc(notable_trait)]
+pub trait Notable {}
+
+#[doc(notable_trait, alias = "also")]
+pub trait AlsoNotable {}
+
+pub trait Ordinary {}
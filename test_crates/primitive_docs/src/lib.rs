@@ -0,0 +1,8 @@
+//! A documentation page for a primitive type, like the ones in `core`:
+//! - `prim_char`, documenting the `char` primitive with `#[doc(primitive = "char")]`
+
+#![feature(rustdoc_internals)]
+
+/// The `char` primitive.
+#[doc(primitive = "char")]
+mod prim_char {}
@@ -0,0 +1,22 @@
+//! One procedural macro of each kind, and a declarative `helper` macro.
+
+use proc_macro::TokenStream;
+
+#[proc_macro]
+pub fn make_answer(_input: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
+
+#[proc_macro_attribute]
+pub fn route(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+#[proc_macro_derive(Builder, attributes(builder, builder_field))]
+pub fn derive_builder(_input: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
+
+macro_rules! helper {
+    () => {};
+}
@@ -0,0 +1,117 @@
+//! A small public API, for testing the rendering of the public API listing:
+//! - `Circle` is importable both from `shapes` and from the crate root
+//! - `Circle::center` and `Circle::private_helper()` are not public
+//! - `Shape` is only importable from `shapes`, and has tuple and struct variants
+//! - `Wrapper`, `largest()`, and `Measure` have generics, bounds, and where clauses
+//! - `Circle` implements `Clone` by derive, and `Draw` by hand
+//! - `ffi_area()` has the C ABI
+//! - `Shaped` is a trait alias
+#![feature(trait_alias)]
+
+pub mod shapes {
+    #[derive(Clone)]
+    pub struct Circle {
+        pub radius: f64,
+        center: (f64, f64),
+    }
+
+    impl Circle {
+        pub fn new(radius: f64) -> Circle {
+            Circle {
+                radius,
+                center: (0.0, 0.0),
+            }
+        }
+
+        pub fn area(&self) -> f64 {
+            self.private_helper() * self.radius * self.radius
+        }
+
+        fn private_helper(&self) -> f64 {
+            let _ = self.center;
+            std::f64::consts::PI
+        }
+    }
+
+    pub enum Shape {
+        Round(Circle),
+        Square { side: f64 },
+        Empty,
+    }
+}
+
+pub use shapes::Circle;
+use shapes::Shape;
+
+pub trait Draw {
+    fn draw(&self, canvas: &mut String);
+}
+
+impl Draw for Circle {
+    fn draw(&self, canvas: &mut String) {
+        canvas.push('o');
+    }
+}
+
+pub trait Measure<Unit = f64>: Draw {
+    type Output: Copy;
+
+    const SCALE: u32;
+
+    fn measure(&self) -> Unit;
+}
+
+pub trait Shaped = Draw + Clone;
+
+pub struct Wrapper<'a, T: Clone + 'a, U>
+where
+    U: Default,
+{
+    pub inner: &'a T,
+    pub fallback: U,
+}
+
+pub union Bits {
+    pub float: f64,
+    pub int: u64,
+}
+
+pub const UNIT: f64 = 1.0;
+
+pub static mut COUNTER: u64 = 0;
+
+#[macro_export]
+macro_rules! circle {
+    ($radius:expr) => {
+        $crate::Circle::new($radius)
+    };
+}
+
+pub type Shapes = Vec<Shape>;
+
+pub fn total_area(shapes: &[Shape]) -> f64 {
+    shapes
+        .iter()
+        .map(|shape| match shape {
+            Shape::Round(circle) => circle.area(),
+            Shape::Square { side } => side * side,
+            Shape::Empty => 0.0,
+        })
+        .sum()
+}
+
+pub fn largest<T>(items: &[T]) -> Option<&T>
+where
+    T: PartialOrd,
+{
+    items
+        .iter()
+        .fold(None, |largest, item| match largest {
+            Some(largest) if largest >= item => Some(largest),
+            _ => Some(item),
+        })
+}
+
+pub extern "C" fn ffi_area(radius: f64) -> f64 {
+    Circle::new(radius).area()
+}
@@ -0,0 +1,31 @@
+//! Imports with varying reachability:
+//! - `pub use inner::Foo;`, a plain re-export
+//! - `pub use inner::Bar as Baz;`, a renaming re-export
+//! - `pub use inner::shapes::*;`, a glob re-export
+//! - `pub(crate) use inner::Hidden;`, which isn't public
+//! - a `pub use` inside a private module, which isn't reachable
+
+mod inner {
+    pub struct Foo;
+
+    pub struct Bar;
+
+    pub struct Hidden;
+
+    pub mod shapes {
+        pub struct Circle;
+    }
+}
+
+mod private {
+    pub use crate::inner::Foo as Unreachable;
+}
+
+pub use inner::Foo;
+
+pub use inner::Bar as Baz;
+
+pub use inner::shapes::*;
+
+#[allow(unused_imports)]
+pub(crate) use inner::Hidden;
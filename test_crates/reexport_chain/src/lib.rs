@@ -0,0 +1,16 @@
+//! The struct `inner::Foo` is reachable through a chain of re-exports:
+//! - `Final` re-exports `middle::Renamed`,
+//! - which re-exports the type alias `Alias`,
+//! - which is equivalent to a re-export of `inner::Foo`.
+
+mod inner {
+    pub struct Foo;
+}
+
+pub type Alias = inner::Foo;
+
+pub mod middle {
+    pub use crate::Alias as Renamed;
+}
+
+pub use middle::Renamed as Final;
@@ -0,0 +1,34 @@
+//! Method signatures written with and without `Self`:
+//! - the inherent impl of `Point<T>` uses `Self` in `new()` and `self` in `swap()`,
+//!   but spells out `Point<T>` everywhere else
+//! - the `Duplicate` trait declares `duplicate(&self) -> Self`,
+//!   which is implemented for `Point<i64>`
+
+pub struct Point<T> {
+    pub x: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T) -> Self {
+        Self { x }
+    }
+
+    pub fn swap(self, other: Point<T>) -> Point<T> {
+        let _ = self;
+        other
+    }
+
+    pub fn get(&self) -> &T {
+        &self.x
+    }
+}
+
+pub trait Duplicate {
+    fn duplicate(&self) -> Self;
+}
+
+impl Duplicate for Point<i64> {
+    fn duplicate(&self) -> Self {
+        Self { x: self.x }
+    }
+}
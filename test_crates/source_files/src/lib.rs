@@ -0,0 +1,7 @@
+//! Items defined across two source files:
+//! - `src/lib.rs`, with the crate root and `Config`
+//! - `src/shapes.rs`, with the `shapes` module and `Circle` and `area` in it
+
+pub mod shapes;
+
+pub struct Config;
@@ -0,0 +1,5 @@
+pub struct Circle;
+
+pub fn area() -> f64 {
+    0.0
+}
@@ -0,0 +1,9 @@
+//! Items whose source code is read through `Span.source`:
+//! - `Unit`, declared on a single line
+//! - `answer`, declared across several lines
+
+pub struct Unit;
+
+pub fn answer() -> i32 {
+    42
+}
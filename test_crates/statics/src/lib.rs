@@ -0,0 +1,11 @@
+//! Statics, mutable and not:
+//! - `LIMIT` is an immutable static
+//! - `COUNTER` is a `static mut`, re-exported from a private module
+
+pub static LIMIT: u32 = 100;
+
+mod private {
+    pub static mut COUNTER: u64 = 0;
+}
+
+pub use private::COUNTER;
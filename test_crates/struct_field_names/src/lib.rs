@@ -0,0 +1,13 @@
+//! Structs and variants with positional and named fields.
+
+pub struct Tuple(pub i64, pub String);
+
+pub struct Named {
+    pub first: i64,
+    pub second: String,
+}
+
+pub enum Either {
+    Tuple(i64),
+    Named { value: i64 },
+}
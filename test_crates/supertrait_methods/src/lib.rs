@@ -0,0 +1,34 @@
+//! Methods available on a type through the supertraits of the traits it implements:
+//! - `Greeter` implements `Greet`, whose supertrait `Named` is implemented through
+//!   a blanket impl, and whose supertrait `Marker` has no methods
+//! - `Named` has a supertrait `Base` of its own, with the method `id()`
+
+pub trait Base {
+    fn id(&self) -> u64 {
+        0
+    }
+}
+
+pub trait Named: Base {
+    fn name(&self) -> String {
+        "named".to_string()
+    }
+}
+
+pub trait Marker {}
+
+pub trait Greet: Named + Marker {
+    fn greet(&self) -> String {
+        format!("hello, {}", self.name())
+    }
+}
+
+impl<T: Marker> Base for T {}
+
+impl<T: Marker> Named for T {}
+
+pub struct Greeter;
+
+impl Marker for Greeter {}
+
+impl Greet for Greeter {}
@@ -0,0 +1,12 @@
+//! Functions with explicit and compiler-synthesized generic type parameters:
+//! - `explicit` has an explicit type parameter `T`
+//! - `argument_position` takes an `impl Display`, which desugars to a synthetic type parameter
+//! - `mixed` has both
+
+use std::fmt::Display;
+
+pub fn explicit<T: Display>(_value: T) {}
+
+pub fn argument_position(_value: impl Display) {}
+
+pub fn mixed<T: Clone>(_first: T, _second: impl Display) {}
@@ -0,0 +1,10 @@
+//! Trait aliases with trait and lifetime bounds:
+//! - `Shareable`, an alias for `Clone + Send + 'static`
+//! - `ConvertibleMarker<T>`, a generic alias for `Into<T> + Marker`
+#![feature(trait_alias)]
+
+pub trait Marker {}
+
+pub trait Shareable = Clone + Send + 'static;
+
+pub trait ConvertibleMarker<T> = Into<T> + Marker;
@@ -0,0 +1,32 @@
+//! Functions and methods with generic bounds on this crate's traits:
+//! - `draw`, bounded on `Shape` through a type parameter
+//! - `label`, bounded on both `Named` and `Shape` in a `where` clause
+//! - `describe`, bounded on `Named` through an `impl Trait` argument
+//! - `Canvas::paint`, a method bounded on `Shape`
+//! - `private_draw`, which isn't public and so isn't listed
+//! - `Unused`, a trait no function is bounded on
+
+pub trait Shape {}
+
+pub trait Named {}
+
+pub trait Unused {}
+
+pub fn draw<T: Shape>(_shape: T) {}
+
+pub fn label<T>(_item: T)
+where
+    T: Named + Shape,
+{
+}
+
+pub fn describe(_item: impl Named) {}
+
+#[allow(dead_code)]
+fn private_draw<T: Shape>(_shape: T) {}
+
+pub struct Canvas;
+
+impl Canvas {
+    pub fn paint<S: Shape>(&self, _shape: S) {}
+}
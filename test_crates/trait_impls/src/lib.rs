@@ -0,0 +1,20 @@
+//! A mix of inherent impls, trait impls, and unsafe trait impls,
+//! for testing queries over all impls in the crate.
+
+pub trait Marker {}
+
+pub struct Foo;
+
+impl Foo {
+    pub fn new() -> Self {
+        Foo
+    }
+}
+
+impl Marker for Foo {}
+
+pub struct Bar(*const u8);
+
+impl Marker for Bar {}
+
+unsafe impl Send for Bar {}
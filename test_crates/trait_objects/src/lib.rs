@@ -0,0 +1,15 @@
+//! Struct fields whose types contain trait objects:
+//! - `Handlers::error` has a trait object with auto traits and a lifetime bound
+//! - `Handlers::observers` has trait objects inside a `Vec`, with the default lifetime bound
+//! - `Handlers::count` has no trait objects at all
+
+use std::error::Error;
+use std::fmt::Debug;
+
+pub struct Handlers<'a> {
+    pub error: Box<dyn Error + Send + Sync + 'a>,
+
+    pub observers: Vec<Box<dyn Debug>>,
+
+    pub count: usize,
+}
@@ -0,0 +1,24 @@
+//! Structs with and without `#[repr(transparent)]`:
+//! - `Wrapper` wraps a single `u64`
+//! - `Tagged` wraps a `u32` alongside zero-sized marker fields
+//! - `Named` wraps a `Vec<u8>` in a named field next to a local fieldless struct
+//! - `NotTransparent` is `#[repr(C)]`, so it has no transparent inner type
+
+use std::marker::PhantomData;
+
+pub struct Marker;
+
+#[repr(transparent)]
+pub struct Wrapper(pub u64);
+
+#[repr(transparent)]
+pub struct Tagged<T>(pub u32, PhantomData<T>, ());
+
+#[repr(transparent)]
+pub struct Named {
+    pub bytes: Vec<u8>,
+    marker: Marker,
+}
+
+#[repr(C)]
+pub struct NotTransparent(pub u64);
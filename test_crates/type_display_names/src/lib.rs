@@ -0,0 +1,15 @@
+//! Struct fields of various types, for testing how types are rendered:
+//! - `Holder` has fields with standard library types, with a local type
+//!   defined in a submodule, and with types that aren't paths at all
+
+pub mod shapes {
+    pub struct Square;
+}
+
+pub struct Holder<'a> {
+    pub names: Vec<&'a str>,
+    pub pair: (u8, Option<shapes::Square>),
+    pub callback: fn(u32) -> bool,
+    pub square: shapes::Square,
+    pub bytes: [u8; 4],
+}
@@ -0,0 +1,22 @@
+//! Unions with fields, impls, and generic parameters:
+//! - `IntOrFloat`, with fields `int` and `float`, an inherent `to_bits` method,
+//!   and derived `Clone` and `Copy` impls
+//! - `MaybeValue<T>`, generic over its field's type
+
+#[derive(Clone, Copy)]
+pub union IntOrFloat {
+    pub int: u32,
+    pub float: f32,
+}
+
+impl IntOrFloat {
+    pub fn to_bits(self) -> u32 {
+        // SAFETY: both fields are 4 bytes wide, and every bit pattern is a valid `u32`.
+        unsafe { self.int }
+    }
+}
+
+pub union MaybeValue<T: Copy> {
+    pub value: T,
+    pub empty: (),
+}